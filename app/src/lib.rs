@@ -1,4 +1,8 @@
 #![no_std]
+
+// The tests run natively with a mock of the chain
+#[cfg(test)]
+extern crate std;
 // necesary crates
 use sails_rs::{
     cell::RefCell, 
@@ -16,8 +20,12 @@ pub mod services;
 
 // import necesary data (CustomStruct state)
 use states::{
-    bet2chess_state::ChessState,
-    signless_accounts_state::ContractSignlessAccounts
+    bet2chess_state::{
+        ChessState,
+        DEFAULT_INVITATION_TTL_BLOCKS
+    },
+    signless_accounts_state::ContractSignlessAccounts,
+    user_registry_state::UserRegistryState
 };
 
 
@@ -25,7 +33,10 @@ use states::{
 use services::{
     bet2chess_service::Bet2ChessService,
     signless_service::SignlessService,
-    query_service::QueryService
+    query_service::QueryService,
+    token_stakes_service::TokenStakesService,
+    tournament_service::TournamentService,
+    user_registry_service::UserRegistryService
 };
 
 
@@ -35,7 +46,8 @@ use services::{
 // message indicating that these services are stateless.
 pub struct Bet2ChessProgram {
     bet2chess_state: RefCell<ChessState>,
-    signless_state: RefCell<ContractSignlessAccounts>
+    signless_state: RefCell<ContractSignlessAccounts>,
+    user_registry_state: RefCell<UserRegistryState>
 }
 
 // Ping program, it host one or more services and it expose them to the 
@@ -48,12 +60,15 @@ impl Bet2ChessProgram {
     pub fn new() -> Self {
         let mut chess_state = ChessState::default();
         chess_state.admins.push(msg::source());
+        chess_state.invitation_ttl_blocks = DEFAULT_INVITATION_TTL_BLOCKS;
         let bet2chess_state = RefCell::new(chess_state);
         let signless_state = RefCell::new(ContractSignlessAccounts::default());
+        let user_registry_state = RefCell::new(UserRegistryState::default());
 
         Self {
             bet2chess_state,
-            signless_state
+            signless_state,
+            user_registry_state
         }
     }
 
@@ -61,7 +76,8 @@ impl Bet2ChessProgram {
     pub fn bet2chess_svc(&self) -> Bet2ChessService<'_> {
         Bet2ChessService::new(
             self.bet2chess_state.borrow_mut(), 
-            self.signless_state.borrow()
+            self.signless_state.borrow(),
+            self.user_registry_state.borrow()
         )
     }
 
@@ -72,6 +88,33 @@ impl Bet2ChessProgram {
         )
     }
 
+    #[route("UserRegistry")]
+    pub fn user_registry_svc(&self) -> UserRegistryService<'_> {
+        UserRegistryService::new(
+            self.user_registry_state.borrow_mut(),
+            self.bet2chess_state.borrow(),
+            self.signless_state.borrow()
+        )
+    }
+
+    #[route("TokenStakes")]
+    pub fn token_stakes_svc(&self) -> TokenStakesService<'_> {
+        TokenStakesService::new(
+            &self.bet2chess_state,
+            &self.signless_state,
+            &self.user_registry_state
+        )
+    }
+
+    #[route("Tournament")]
+    pub fn tournament_svc(&self) -> TournamentService<'_> {
+        TournamentService::new(
+            self.bet2chess_state.borrow_mut(),
+            self.signless_state.borrow(),
+            self.user_registry_state.borrow()
+        )
+    }
+
     #[route("QueryService")]
     pub fn query_svc(&self) -> QueryService<'_> {
         QueryService::new(
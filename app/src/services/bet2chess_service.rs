@@ -2,10 +2,7 @@ use core::error;
 
 use sails_rs::{
     prelude::*,
-    gstd::{
-        service,
-        msg
-    },
+    gstd::service,
     cell::{
         Ref,
         RefMut
@@ -13,16 +10,40 @@ use sails_rs::{
 };
 
 use crate::states::{
+    runtime::{
+        msg,
+        exec
+    },
     bet2chess_state::{
-        Bet2ChessErrors, BetAmout, ChessState, GameData, GameId, InvitationsData
+        Bet2ChessErrors, BetAmout, ChessState, GameData, GameId, GameStatusKind, InvitationParticipant, InvitationsData, Stake, StakeLimits
+    }, chess_board_state::{
+        BoardStatus,
+        ChessBoard,
+        ChessMove
+    }, games_query_state::{
+        GamesFilter,
+        GamesPage
+    }, leaderboard_state::{
+        LeaderboardEntry,
+        LeaderboardKind
+    }, matchmaking_state::{
+        MatchmakingEntry,
+        MatchmakingQueueKey,
+        MatchmakingResult,
+        RatingRange
+    }, player_stats_state::PlayerStats, rating_state::{
+        PlayerRating,
+        RatingChange
     }, signless_accounts_state::{
         ContractSignlessAccounts,
         SignlessError
-    }
+    }, spectator_market_state::{
+        MarketConfig,
+        MarketOutcome,
+        MarketStatus
+    }, treasury_state::FeeConfig, user_registry_state::UserRegistryState
 };
 
-const ONE_VARA: u128 = 1_000_000_000_000;
-
 #[derive(Encode, Decode, TypeInfo, Clone, Default)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
@@ -31,20 +52,47 @@ pub struct InvitationsState {
     sent_invitations_to_users: Vec<u64>,
 }
 
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InvitationLifetime {
+    inviter_web2_id: u64,
+    guest_web2_id: u64,
+    bet_amount: u128,
+    guest_bet_amount: u128,
+    created_at_block: u32,
+    expires_at_block: u32,
+    remaining_blocks: u32,
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SpectatorMarketInfo {
+    status: MarketStatus,
+    closes_after_move: u32,
+    closes_at_block: u32,
+    pools: Vec<(MarketOutcome, u128)>,
+    prize_pool: u128
+}
+
 pub struct Bet2ChessService<'a> {
     pub state: RefMut<'a, ChessState>,
-    pub signless_state_ref: Ref<'a, ContractSignlessAccounts>
+    pub signless_state_ref: Ref<'a, ContractSignlessAccounts>,
+    pub registry_state_ref: Ref<'a, UserRegistryState>
 }
 
 #[service]
 impl<'a> Bet2ChessService<'a> {
     pub fn new(
         state: RefMut<'a, ChessState>,
-        signless_state_ref: Ref<'a, ContractSignlessAccounts>
+        signless_state_ref: Ref<'a, ContractSignlessAccounts>,
+        registry_state_ref: Ref<'a, UserRegistryState>
     ) -> Self {
         Self {
             state,
-            signless_state_ref
+            signless_state_ref,
+            registry_state_ref
         }
     }
 
@@ -53,9 +101,40 @@ impl<'a> Bet2ChessService<'a> {
             .invitations
             .get(&(first_web2_id, second_web2_id));
 
-        temp.copied()
+        temp.map(|invitation| invitation.bet_amount)
     }   
 
+    // Stake the guest has to send to accept the invitation
+    pub fn invitation_guest_bet(&self, first_web2_id: u64, second_web2_id: u64) -> Option<u128> {
+        self.state
+            .invitations
+            .get(&(first_web2_id, second_web2_id))
+            .map(|invitation| invitation.guest_bet_amount)
+    }
+
+    pub fn invitations_lifetime(&self, web2_id: u64) -> Vec<InvitationLifetime> {
+        let current_block = exec::block_height();
+
+        self.state
+            .invitations
+            .iter()
+            .filter(|((inviter, guest), _)| *inviter == web2_id || *guest == web2_id)
+            .map(|((inviter, guest), invitation)| InvitationLifetime {
+                inviter_web2_id: *inviter,
+                guest_web2_id: *guest,
+                bet_amount: invitation.bet_amount,
+                guest_bet_amount: invitation.guest_bet_amount,
+                created_at_block: invitation.created_at_block,
+                expires_at_block: invitation.expires_at_block,
+                remaining_blocks: invitation.remaining_blocks(current_block)
+            })
+            .collect()
+    }
+
+    pub fn invitation_ttl_blocks(&self) -> u32 {
+        self.state.invitation_ttl_blocks
+    }
+
     pub fn invitations_from_web2_id(&self, web2_id: u64) -> Option<InvitationsState>{
         let temp = self.state
             .user_invitations
@@ -80,12 +159,20 @@ impl<'a> Bet2ChessService<'a> {
         Some(state)
     }
 
+    pub fn game_id_by_web2_id(&self, web2_game_id: u64) -> Option<u64> {
+        self.state
+            .games_by_web2_id
+            .get(&web2_game_id)
+            .copied()
+    }
+
     pub fn game_data(&self, game_id: u64) -> Option<GameData> {
         self.state
             .game_by_id(game_id)
             .cloned()
     }
 
+    // Returns every game in one reply, `Games` should be used instead
     pub fn all_games(&self) -> Vec<(u64, GameData)> {
         self.state
             .games_by_id
@@ -94,24 +181,175 @@ impl<'a> Bet2ChessService<'a> {
             .collect()
     }
 
+    pub fn games(&self, offset: u32, limit: u32, filter: GamesFilter) -> GamesPage {
+        self.state
+            .games_page(None, offset, limit, &filter)
+    }
+
+    // Next page of games after the cursor returned by `Games` or `GamesAfter`
+    pub fn games_after(&self, cursor: u64, limit: u32, filter: GamesFilter) -> GamesPage {
+        self.state
+            .games_page(Some(cursor), 0, limit, &filter)
+    }
+
     pub fn games_id_waiting(&self) -> Vec<u64> {
         self.state
-            .games_waiting
-            .clone()
+            .all_games_id_by_status(GameStatusKind::Waiting)
+    }
+
+    pub fn games_id_waiting_page(&self, offset: u32, limit: u32) -> Vec<u64> {
+        self.state
+            .games_id_by_status(GameStatusKind::Waiting, offset, limit)
     }
 
     pub fn games_id_ended(&self) -> Vec<u64> {
         self.state
-            .finished_games
-            .clone()
+            .all_games_id_by_status(GameStatusKind::Ended)
+    }
+
+    pub fn games_id_ended_page(&self, offset: u32, limit: u32) -> Vec<u64> {
+        self.state
+            .games_id_by_status(GameStatusKind::Ended, offset, limit)
     }
 
     pub fn games_id_started(&self) -> Vec<u64> {
         self.state
-            .games_started
+            .all_games_id_by_status(GameStatusKind::Started)
+    }
+
+    pub fn games_id_started_page(&self, offset: u32, limit: u32) -> Vec<u64> {
+        self.state
+            .games_id_by_status(GameStatusKind::Started, offset, limit)
+    }
+
+    pub fn games_id_cancelled(&self) -> Vec<u64> {
+        self.state
+            .all_games_id_by_status(GameStatusKind::Cancelled)
+    }
+
+    pub fn games_id_cancelled_page(&self, offset: u32, limit: u32) -> Vec<u64> {
+        self.state
+            .games_id_by_status(GameStatusKind::Cancelled, offset, limit)
+    }
+
+    // Prizes and refunds the address can withdraw, in planck
+    pub fn balance(&self, address: ActorId) -> u128 {
+        self.state.balance(address)
+    }
+
+    pub fn stake_limits(&self) -> StakeLimits {
+        self.state.stake_limits
+    }
+
+    pub fn fee_config(&self) -> FeeConfig {
+        self.state.treasury.fee_config
+    }
+
+    // Fees that can still be withdrawn
+    pub fn treasury_balance(&self) -> u128 {
+        self.state.treasury.balance
+    }
+
+    pub fn total_fees_collected(&self) -> u128 {
+        self.state.treasury.total_fees_collected
+    }
+
+    pub fn spectator_market(&self, game_id: u64) -> Option<SpectatorMarketInfo> {
+        self.state
+            .spectator_market(game_id)
+            .map(|market| SpectatorMarketInfo {
+                status: market.status,
+                closes_after_move: market.closes_after_move,
+                closes_at_block: market.closes_at_block,
+                pools: market.pools.iter().map(|(outcome, pool)| (*outcome, *pool)).collect(),
+                prize_pool: market.prize_pool
+            })
+    }
+
+    // Bets of the spectator that have not been claimed yet
+    pub fn spectator_bets(&self, game_id: u64, address: ActorId) -> Vec<(MarketOutcome, u128)> {
+        self.state.spectator_bets(game_id, address)
+    }
+
+    pub fn market_config(&self) -> MarketConfig {
+        self.state.spectator_markets.config
+    }
+
+    pub fn admins(&self) -> Vec<ActorId> {
+        self.state
+            .admins
+            .clone()
+    }
+
+    pub fn result_oracles(&self) -> Vec<ActorId> {
+        self.state
+            .result_oracles
             .clone()
     }
 
+    pub fn matchmaking_queue(&self, bet_amount: u128, time_control: Option<u32>) -> Vec<MatchmakingEntry> {
+        let key = MatchmakingQueueKey {
+            bet_amount,
+            time_control
+        };
+
+        self.state
+            .matchmaking
+            .queues
+            .get(&key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn player_rating(&self, web2_id: u64) -> PlayerRating {
+        self.state
+            .ratings
+            .ratings
+            .get(&web2_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn leaderboard(&self, kind: LeaderboardKind, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+        self.state.leaderboard(kind, offset, limit)
+    }
+
+    pub fn games_id_by_player_address(&self, address: ActorId, offset: u32, limit: u32) -> Vec<u64> {
+        self.state.games_id_by_player_address(address, offset, limit)
+    }
+
+    pub fn games_id_by_player_web2_id(&self, web2_id: u64, offset: u32, limit: u32) -> Vec<u64> {
+        self.state.games_id_by_player_web2_id(web2_id, offset, limit)
+    }
+
+    pub fn player_stats(&self, web2_id: u64) -> PlayerStats {
+        self.state.player_stats(web2_id)
+    }
+
+    pub fn rating_history(&self, web2_id: u64) -> Vec<RatingChange> {
+        self.state
+            .ratings
+            .history
+            .get(&web2_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn game_board(&self, game_id: u64) -> Option<ChessBoard> {
+        self.state
+            .game_boards
+            .get(&game_id)
+            .cloned()
+    }
+
+    pub fn legal_moves(&self, game_id: u64) -> Vec<ChessMove> {
+        self.state
+            .game_boards
+            .get(&game_id)
+            .map(|board| board.legal_moves())
+            .unwrap_or_default()
+    }
+
 
 
 
@@ -123,11 +361,17 @@ impl<'a> Bet2ChessService<'a> {
         first_web2_id: u64,
         second_web2_id: u64
     ) -> Bet2ChessEvents {
-        self.handle_cancel_invitation(first_web2_id, second_web2_id)
+        let caller = msg::source();
+
+        self.handle_cancel_invitation(
+            caller,
+            first_web2_id,
+            second_web2_id
+        )
     }
 
     pub fn cancel_invitation_signless(
-        &mut self, 
+        &mut self,
         user_address: ActorId,
         first_web2_id: u64,
         second_web2_id: u64
@@ -144,7 +388,11 @@ impl<'a> Bet2ChessService<'a> {
             return Bet2ChessEvents::SignlessError(signless_error);
         }
 
-        self.handle_cancel_invitation(first_web2_id, second_web2_id)
+        self.handle_cancel_invitation(
+            user_address,
+            first_web2_id,
+            second_web2_id
+        )
     }
 
     pub fn cancel_invitation_signless_no_wallet(
@@ -165,23 +413,112 @@ impl<'a> Bet2ChessService<'a> {
             return Bet2ChessEvents::SignlessError(signless_error);
         }
 
-        self.handle_cancel_invitation(first_web2_id, second_web2_id)
+        self.handle_cancel_invitation(
+            caller,
+            first_web2_id,
+            second_web2_id
+        )
+    }
+
+    pub fn decline_invitation(
+        &mut self,
+        first_web2_id: u64,
+        second_web2_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_decline_invitation(
+            caller,
+            first_web2_id,
+            second_web2_id
+        )
+    }
+
+    pub fn decline_invitation_signless(
+        &mut self,
+        user_address: ActorId,
+        first_web2_id: u64,
+        second_web2_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_decline_invitation(
+            user_address,
+            first_web2_id,
+            second_web2_id
+        )
     }
 
+    pub fn decline_invitation_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        first_web2_id: u64,
+        second_web2_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
 
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
 
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
 
+        self.handle_decline_invitation(
+            caller,
+            first_web2_id,
+            second_web2_id
+        )
+    }
 
+    pub fn expire_invitation(
+        &mut self,
+        first_web2_id: u64,
+        second_web2_id: u64
+    ) -> Bet2ChessEvents {
+        let temp = self.state
+            .expire_invitation(first_web2_id, second_web2_id);
 
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(_) => Bet2ChessEvents::InvitationExpired
+        }
+    }
 
+    pub fn sweep_expired_invitations(&mut self, limit: u32) -> Bet2ChessEvents {
+        let expired = self.state
+            .sweep_expired_invitations(limit);
 
+        Bet2ChessEvents::InvitationsExpired(expired)
+    }
 
+    pub fn set_invitation_ttl(&mut self, ttl_blocks: u32) -> Bet2ChessEvents {
+        let caller = msg::source();
 
+        match self.state.set_invitation_ttl(caller, ttl_blocks) {
+            Ok(_) => Bet2ChessEvents::InvitationTtlSet(ttl_blocks),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
 
     pub fn send_invitation(
         &mut self,
         web2_user_id: u64,
-        web2_guest_id: u64
+        web2_guest_id: u64,
+        guest_bet_amount: Option<u128>
     ) -> Bet2ChessEvents {
         let caller = msg::source();
         let value = msg::value();
@@ -190,7 +527,8 @@ impl<'a> Bet2ChessService<'a> {
             caller, 
             web2_user_id, 
             web2_guest_id, 
-            value
+            value,
+            guest_bet_amount
         )
     }
 
@@ -198,7 +536,8 @@ impl<'a> Bet2ChessService<'a> {
         &mut self, 
         user_address: ActorId,
         web2_user_id: u64,
-        web2_guest_id: u64
+        web2_guest_id: u64,
+        guest_bet_amount: Option<u128>
     ) -> Bet2ChessEvents {
         let caller = msg::source();
         let value = msg::value();
@@ -210,14 +549,15 @@ impl<'a> Bet2ChessService<'a> {
             );
 
         if let Err(signless_error) = result {
-            return Bet2ChessEvents::SignlessError(signless_error);
+            return self.refund_signless_value(value, signless_error);
         }
 
         self.handle_send_invitation(
             user_address, 
             web2_user_id, 
             web2_guest_id, 
-            value
+            value,
+            guest_bet_amount
         )
     }
 
@@ -225,7 +565,8 @@ impl<'a> Bet2ChessService<'a> {
         &mut self,
         no_wallet_name_encoded: String,
         web2_user_id: u64,
-        web2_guest_id: u64
+        web2_guest_id: u64,
+        guest_bet_amount: Option<u128>
     ) -> Bet2ChessEvents {
         let caller = msg::source();
         let value = msg::value();
@@ -237,14 +578,15 @@ impl<'a> Bet2ChessService<'a> {
             );
 
         if let Err(signless_error) = result {
-            return Bet2ChessEvents::SignlessError(signless_error);
+            return self.refund_signless_value(value, signless_error);
         }
 
         self.handle_send_invitation(
             caller, 
             web2_user_id, 
             web2_guest_id, 
-            value
+            value,
+            guest_bet_amount
         )
     }
 
@@ -256,9 +598,7 @@ impl<'a> Bet2ChessService<'a> {
         &mut self,
         web2_user_id: u64,
         web2_user_id_invitation_owner: u64,
-        web2_match_game_id: u64,
-        username_from_user_who_invite: String,
-        own_username: String
+        web2_match_game_id: u64
     ) -> Bet2ChessEvents {
         let caller = msg::source();
         let value = msg::value();
@@ -268,8 +608,6 @@ impl<'a> Bet2ChessService<'a> {
             web2_user_id, 
             web2_user_id_invitation_owner, 
             web2_match_game_id, 
-            username_from_user_who_invite,
-            own_username,
             value
         )
     }
@@ -279,9 +617,7 @@ impl<'a> Bet2ChessService<'a> {
         user_address: ActorId,
         web2_user_id: u64,
         web2_user_id_invitation_owner: u64,
-        web2_match_game_id: u64,
-        username_from_user_who_invite: String,
-        own_username: String
+        web2_match_game_id: u64
     ) -> Bet2ChessEvents {
         let caller = msg::source();
         let value = msg::value();
@@ -293,7 +629,7 @@ impl<'a> Bet2ChessService<'a> {
             );
 
         if let Err(signless_error) = result {
-            return Bet2ChessEvents::SignlessError(signless_error);
+            return self.refund_signless_value(value, signless_error);
         }
 
         self.handle_accept_invitation(
@@ -301,8 +637,6 @@ impl<'a> Bet2ChessService<'a> {
             web2_user_id, 
             web2_user_id_invitation_owner, 
             web2_match_game_id, 
-            username_from_user_who_invite,
-            own_username,
             value
         )
     }
@@ -312,9 +646,7 @@ impl<'a> Bet2ChessService<'a> {
         no_wallet_name_encoded: String,
         web2_user_id: u64,
         web2_user_id_invitation_owner: u64,
-        web2_match_game_id: u64,
-        username_from_user_who_invite: String,
-        own_username: String
+        web2_match_game_id: u64
     ) -> Bet2ChessEvents {
         let caller = msg::source();
         let value = msg::value();
@@ -326,7 +658,7 @@ impl<'a> Bet2ChessService<'a> {
             );
 
         if let Err(signless_error) = result {
-            return Bet2ChessEvents::SignlessError(signless_error);
+            return self.refund_signless_value(value, signless_error);
         }
 
         self.handle_accept_invitation(
@@ -334,82 +666,880 @@ impl<'a> Bet2ChessService<'a> {
             web2_user_id, 
             web2_user_id_invitation_owner, 
             web2_match_game_id, 
-            username_from_user_who_invite,
-            own_username,
             value
         )
     }
 
-    pub fn end_game_by_id(&mut self, game_id: u64, game_winner: Option<ActorId>) -> Bet2ChessEvents {
-        match self.state.end_match(game_id, game_winner) {
-            Ok(_) => Bet2ChessEvents::GameEnded(game_id),
-            Err(error_messaage) => Bet2ChessEvents::Error(error_messaage)
-        }
+    pub fn create_open_game(
+        &mut self,
+        web2_user_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        self.handle_create_open_game(
+            caller,
+            web2_user_id,
+            value
+        )
     }
 
-    pub fn end_match(
+    pub fn create_open_game_signless(
         &mut self,
-        game_id: u64,
-        game_winner: Option<ActorId>
+        user_address: ActorId,
+        web2_user_id: u64
     ) -> Bet2ChessEvents {
-        let temp = self.state
-            .end_match(game_id, game_winner);
+        let caller = msg::source();
+        let value = msg::value();
 
-        match temp {
-            Ok(ended_game_id) => Bet2ChessEvents::GameEnded(ended_game_id),
-            Err(error) => Bet2ChessEvents::Error(error)
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
         }
 
+        self.handle_create_open_game(
+            user_address,
+            web2_user_id,
+            value
+        )
     }
-}
 
-impl<'a> Bet2ChessService<'a> {
-    fn handle_cancel_invitation(
+    pub fn create_open_game_signless_no_wallet(
         &mut self,
-        first_web2_id: u64,
-        second_web2_id: u64,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64
     ) -> Bet2ChessEvents {
-        let temp = self.state
-            .cancel_invitation(first_web2_id, second_web2_id);
+        let caller = msg::source();
+        let value = msg::value();
 
-        match temp {
-            Err(error) => Bet2ChessEvents::Error(error),
-            Ok(_) => Bet2ChessEvents::InvitationCancelled
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
         }
+
+        self.handle_create_open_game(
+            caller,
+            web2_user_id,
+            value
+        )
     }
 
-    fn handle_send_invitation(
-        &mut self, 
-        user_address: ActorId,
-        user_id: u64, 
-        guest_id: u64,
-        bet_amount: u128
+    pub fn join_open_game(
+        &mut self,
+        web2_user_id: u64,
+        game_id: u64
     ) -> Bet2ChessEvents {
-        let bat_value = match Self::format_bet_amout(user_address, bet_amount) {
-            Err(error_message) => return error_message,
-            Ok(amount) => amount
-        };
-
-        let temp = self.state
-            .create_invitation_with_bet(user_address, user_id, guest_id, bat_value);
+        let caller = msg::source();
+        let value = msg::value();
 
-        match temp {
-            Err(error) => Bet2ChessEvents::Error(error),
-            Ok(_) => Bet2ChessEvents::InvitationSentTo(guest_id)
-        }
+        self.handle_join_open_game(
+            caller,
+            web2_user_id,
+            game_id,
+            value
+        )
     }
 
-    fn handle_accept_invitation(
-        &mut self, 
+    pub fn join_open_game_signless(
+        &mut self,
         user_address: ActorId,
-        user_id: u64, 
-        user_who_invite: u64,
-        web2_game_id: u64,
-        username_from_user_who_invite: String,
-        own_username: String,
+        web2_user_id: u64,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_join_open_game(
+            user_address,
+            web2_user_id,
+            game_id,
+            value
+        )
+    }
+
+    pub fn join_open_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_join_open_game(
+            caller,
+            web2_user_id,
+            game_id,
+            value
+        )
+    }
+
+    pub fn join_any_waiting_game(
+        &mut self,
+        web2_user_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        self.handle_join_any_waiting_game(
+            caller,
+            web2_user_id,
+            value
+        )
+    }
+
+    pub fn join_any_waiting_game_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_join_any_waiting_game(
+            user_address,
+            web2_user_id,
+            value
+        )
+    }
+
+    pub fn join_any_waiting_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_join_any_waiting_game(
+            caller,
+            web2_user_id,
+            value
+        )
+    }
+
+    pub fn cancel_open_game(
+        &mut self,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_cancel_open_game(
+            caller,
+            game_id
+        )
+    }
+
+    pub fn cancel_open_game_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_cancel_open_game(
+            user_address,
+            game_id
+        )
+    }
+
+    pub fn cancel_open_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_cancel_open_game(
+            caller,
+            game_id
+        )
+    }
+
+    pub fn join_matchmaking_queue(
+        &mut self,
+        web2_user_id: u64,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        self.handle_join_matchmaking_queue(
+            caller,
+            web2_user_id,
+            time_control,
+            rating_range,
+            value
+        )
+    }
+
+    pub fn join_matchmaking_queue_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_join_matchmaking_queue(
+            user_address,
+            web2_user_id,
+            time_control,
+            rating_range,
+            value
+        )
+    }
+
+    pub fn join_matchmaking_queue_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_join_matchmaking_queue(
+            caller,
+            web2_user_id,
+            time_control,
+            rating_range,
+            value
+        )
+    }
+
+    pub fn leave_matchmaking_queue(&mut self) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_leave_matchmaking_queue(caller)
+    }
+
+    pub fn leave_matchmaking_queue_signless(
+        &mut self,
+        user_address: ActorId
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_leave_matchmaking_queue(user_address)
+    }
+
+    pub fn leave_matchmaking_queue_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_leave_matchmaking_queue(caller)
+    }
+
+    pub fn make_move(
+        &mut self,
+        game_id: u64,
+        chess_move: ChessMove
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_make_move(caller, game_id, chess_move)
+    }
+
+    pub fn make_move_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64,
+        chess_move: ChessMove
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_make_move(user_address, game_id, chess_move)
+    }
+
+    pub fn make_move_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64,
+        chess_move: ChessMove
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_make_move(caller, game_id, chess_move)
+    }
+
+    pub fn resign(&mut self, game_id: u64) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_resign(caller, game_id)
+    }
+
+    pub fn resign_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_resign(user_address, game_id)
+    }
+
+    pub fn resign_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_resign(caller, game_id)
+    }
+
+    pub fn withdraw(
+        &mut self,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_withdraw(
+            caller,
+            amount
+        )
+    }
+
+    pub fn withdraw_signless(
+        &mut self,
+        user_address: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_withdraw(
+            user_address,
+            amount
+        )
+    }
+
+    pub fn withdraw_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_withdraw(
+            caller,
+            amount
+        )
+    }
+
+    pub fn place_spectator_bet(
+        &mut self,
+        game_id: u64,
+        outcome: MarketOutcome
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        self.handle_place_spectator_bet(
+            caller,
+            game_id,
+            outcome,
+            value
+        )
+    }
+
+    pub fn place_spectator_bet_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64,
+        outcome: MarketOutcome
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_place_spectator_bet(
+            user_address,
+            game_id,
+            outcome,
+            value
+        )
+    }
+
+    pub fn place_spectator_bet_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64,
+        outcome: MarketOutcome
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_place_spectator_bet(
+            caller,
+            game_id,
+            outcome,
+            value
+        )
+    }
+
+    pub fn claim_spectator_winnings(
+        &mut self,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_claim_spectator_winnings(
+            caller,
+            game_id
+        )
+    }
+
+    pub fn claim_spectator_winnings_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_claim_spectator_winnings(
+            user_address,
+            game_id
+        )
+    }
+
+    pub fn claim_spectator_winnings_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_claim_spectator_winnings(
+            caller,
+            game_id
+        )
+    }
+
+    pub fn set_stake_limits(&mut self, stake_limits: StakeLimits) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.set_stake_limits(caller, stake_limits) {
+            Ok(_) => Bet2ChessEvents::StakeLimitsSet(stake_limits),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn set_fee_config(&mut self, fee_config: FeeConfig) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.set_fee_config(caller, fee_config) {
+            Ok(_) => Bet2ChessEvents::FeeConfigSet(fee_config),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn withdraw_treasury(&mut self, to: ActorId, amount: u128) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.withdraw_treasury(caller, to, amount) {
+            Ok(_) => Bet2ChessEvents::TreasuryWithdrawn { to, amount },
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn set_market_config(&mut self, market_config: MarketConfig) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.set_market_config(caller, market_config) {
+            Ok(_) => Bet2ChessEvents::MarketConfigSet(market_config),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn abort_game(&mut self, game_id: u64) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.abort_game(caller, game_id) {
+            Ok(game_id) => Bet2ChessEvents::GameAborted(game_id),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn add_admin(&mut self, new_admin: ActorId) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.add_admin(caller, new_admin) {
+            Ok(_) => Bet2ChessEvents::AdminAdded(new_admin),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn remove_admin(&mut self, admin: ActorId) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.remove_admin(caller, admin) {
+            Ok(_) => Bet2ChessEvents::AdminRemoved(admin),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn add_result_oracle(&mut self, oracle: ActorId) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.add_result_oracle(caller, oracle) {
+            Ok(_) => Bet2ChessEvents::ResultOracleAdded(oracle),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn remove_result_oracle(&mut self, oracle: ActorId) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        match self.state.remove_result_oracle(caller, oracle) {
+            Ok(_) => Bet2ChessEvents::ResultOracleRemoved(oracle),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn end_game_by_id(&mut self, game_id: u64, game_winner: Option<ActorId>) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let is_draw = game_winner.is_none();
+
+        match self.state.end_match(game_id, caller, game_winner) {
+            Ok(_) if is_draw => Bet2ChessEvents::GameDrawn(game_id),
+            Ok(_) => Bet2ChessEvents::GameEnded(game_id),
+            Err(error_messaage) => Bet2ChessEvents::Error(error_messaage)
+        }
+    }
+
+    pub fn end_match(
+        &mut self,
+        game_id: u64,
+        game_winner: Option<ActorId>
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+        let is_draw = game_winner.is_none();
+
+        let temp = self.state
+            .end_match(game_id, caller, game_winner);
+
+        match temp {
+            Ok(ended_game_id) if is_draw => Bet2ChessEvents::GameDrawn(ended_game_id),
+            Ok(ended_game_id) => Bet2ChessEvents::GameEnded(ended_game_id),
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+
+    }
+}
+
+impl<'a> Bet2ChessService<'a> {
+    fn handle_cancel_invitation(
+        &mut self,
+        user_address: ActorId,
+        first_web2_id: u64,
+        second_web2_id: u64,
+    ) -> Bet2ChessEvents {
+        let caller_web2_id = match self.linked_web2_id(user_address) {
+            Err(error_message) => return error_message,
+            Ok(web2_id) => web2_id
+        };
+
+        let temp = self.state
+            .cancel_invitation(caller_web2_id, first_web2_id, second_web2_id);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(InvitationParticipant::Inviter) => Bet2ChessEvents::InvitationCancelled,
+            Ok(InvitationParticipant::Guest) => Bet2ChessEvents::InvitationDeclined
+        }
+    }
+
+    fn handle_decline_invitation(
+        &mut self,
+        user_address: ActorId,
+        first_web2_id: u64,
+        second_web2_id: u64,
+    ) -> Bet2ChessEvents {
+        let caller_web2_id = match self.linked_web2_id(user_address) {
+            Err(error_message) => return error_message,
+            Ok(web2_id) => web2_id
+        };
+
+        let temp = self.state
+            .decline_invitation(caller_web2_id, first_web2_id, second_web2_id);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(_) => Bet2ChessEvents::InvitationDeclined
+        }
+    }
+
+    fn handle_send_invitation(
+        &mut self, 
+        user_address: ActorId,
+        user_id: u64, 
+        guest_id: u64,
+        bet_amount: u128,
+        guest_bet_amount: Option<u128>
+    ) -> Bet2ChessEvents {
+        if let Err(error_message) = self.player_username(user_address, user_id, bet_amount) {
+            return error_message;
+        }
+
+        let bat_value = match self.check_stake(user_address, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(amount) => amount
+        };
+
+        // Without a stake for the guest both players bet the same
+        let guest_bet_amount = guest_bet_amount.unwrap_or(bat_value);
+
+        if let Err(error) = self.state.check_stake(guest_bet_amount) {
+            return self.refund_value(user_address, bat_value, error);
+        }
+
+        let temp = self.state
+            .create_invitation_with_bet(user_address, user_id, guest_id, bat_value, guest_bet_amount, None);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(_) => Bet2ChessEvents::InvitationSentTo(guest_id)
+        }
+    }
+
+    fn handle_accept_invitation(
+        &mut self, 
+        user_address: ActorId,
+        user_id: u64, 
+        user_who_invite: u64,
+        web2_game_id: u64,
         bet_amount: u128
     ) -> Bet2ChessEvents {
-        let bet_value = match Self::format_bet_amout(user_address, bet_amount) {
+        let username = match self.player_username(user_address, user_id, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(username) => username
+        };
+
+        // The username of the inviter is also taken from their profile
+        let Some(inviter_username) = self.registry_state_ref.username(user_who_invite) else {
+            return self.refund_value(
+                user_address,
+                bet_amount,
+                Bet2ChessErrors::PlayerHasNoProfile(user_who_invite)
+            );
+        };
+
+        let bet_value = match self.check_stake(user_address, bet_amount) {
             Err(error_message) => return error_message,
             Ok(amount) => amount
         };
@@ -417,37 +1547,272 @@ impl<'a> Bet2ChessService<'a> {
         let temp = self.state
             .accept_invitation(
                 user_address, 
-                user_id, 
-                user_who_invite, 
+                (user_who_invite, user_id),
                 web2_game_id, 
-                username_from_user_who_invite,
-                own_username,
-                bet_value
+                inviter_username,
+                username,
+                Stake {
+                    amount: bet_value,
+                    token: None
+                }
             );
 
         match temp {
             Err(error) => Bet2ChessEvents::Error(error),
-            Ok(_) => Bet2ChessEvents::GameCreated(web2_game_id)
+            Ok(game_id) => Bet2ChessEvents::GameCreated(game_id)
         }
     }
 
-    fn format_bet_amout(caller: ActorId, value: u128) -> Result<BetAmout, Bet2ChessEvents> {
-        if value == 0 {
-            return Ok(0);
+    fn handle_create_open_game(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        bet_amount: u128
+    ) -> Bet2ChessEvents {
+        let username = match self.player_username(user_address, user_id, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(username) => username
+        };
+
+        let bet_value = match self.check_stake(user_address, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(amount) => amount
+        };
+
+        let temp = self.state
+            .create_open_game(user_address, username, user_id, bet_value, None);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(game_id) => Bet2ChessEvents::GameCreated(game_id)
         }
+    }
 
-        if (value / ONE_VARA) < 1 {
-            let payload = Bet2ChessEvents::Error(
-                Bet2ChessErrors::MinAmoutToBetIsOneToken
-            );
+    fn handle_join_open_game(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        game_id: u64,
+        bet_amount: u128
+    ) -> Bet2ChessEvents {
+        let username = match self.player_username(user_address, user_id, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(username) => username
+        };
+
+        let bet_value = match self.check_stake(user_address, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(amount) => amount
+        };
+
+        let temp = self.state
+            .join_open_game(user_address, username, user_id, bet_value, None, game_id);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(game_id) => Bet2ChessEvents::JoinedInGame(game_id)
+        }
+    }
+
+    fn handle_join_any_waiting_game(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        bet_amount: u128
+    ) -> Bet2ChessEvents {
+        let username = match self.player_username(user_address, user_id, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(username) => username
+        };
+
+        let bet_value = match self.check_stake(user_address, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(amount) => amount
+        };
+
+        let temp = self.state
+            .join_any_waiting_game(user_address, username, user_id, bet_value, None);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(game_id) => Bet2ChessEvents::JoinedInGame(game_id)
+        }
+    }
+
+    fn handle_cancel_open_game(&mut self, user_address: ActorId, game_id: u64) -> Bet2ChessEvents {
+        let temp = self.state
+            .cancel_open_game(user_address, game_id);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(game_id) => Bet2ChessEvents::GameCancelled(game_id)
+        }
+    }
+
+    fn handle_join_matchmaking_queue(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>,
+        bet_amount: u128
+    ) -> Bet2ChessEvents {
+        let username = match self.player_username(user_address, user_id, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(username) => username
+        };
+
+        let bet_value = match self.check_stake(user_address, bet_amount) {
+            Err(error_message) => return error_message,
+            Ok(amount) => amount
+        };
+
+        let temp = self.state
+            .join_matchmaking_queue(user_address, username, user_id, bet_value, time_control, rating_range);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(MatchmakingResult::WaitingForOpponent) => Bet2ChessEvents::JoinedMatchmakingQueue,
+            Ok(MatchmakingResult::GameCreated(game_id)) => Bet2ChessEvents::GameCreated(game_id)
+        }
+    }
+
+    fn handle_leave_matchmaking_queue(&mut self, user_address: ActorId) -> Bet2ChessEvents {
+        let temp = self.state
+            .leave_matchmaking_queue(user_address);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(_) => Bet2ChessEvents::LeftMatchmakingQueue
+        }
+    }
+
+    fn handle_place_spectator_bet(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64,
+        outcome: MarketOutcome,
+        bet_amount: u128
+    ) -> Bet2ChessEvents {
+        let temp = self.state
+            .place_spectator_bet(user_address, game_id, outcome, bet_amount);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(_) => Bet2ChessEvents::SpectatorBetPlaced {
+                game_id,
+                outcome,
+                amount: bet_amount
+            }
+        }
+    }
+
+    fn handle_claim_spectator_winnings(&mut self, user_address: ActorId, game_id: u64) -> Bet2ChessEvents {
+        let temp = self.state
+            .claim_spectator_winnings(user_address, game_id);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(amount) => Bet2ChessEvents::SpectatorWinningsClaimed {
+                game_id,
+                amount
+            }
+        }
+    }
+
+    fn handle_withdraw(&mut self, user_address: ActorId, amount: u128) -> Bet2ChessEvents {
+        let temp = self.state
+            .withdraw(user_address, amount);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(_) => Bet2ChessEvents::Withdrawn(amount)
+        }
+    }
+
+    fn handle_make_move(
+        &mut self,
+        player: ActorId,
+        game_id: u64,
+        chess_move: ChessMove
+    ) -> Bet2ChessEvents {
+        let temp = self.state
+            .make_move(player, game_id, chess_move);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(board_status) => Bet2ChessEvents::MovePlayed {
+                game_id,
+                chess_move,
+                board_status
+            }
+        }
+    }
+
+    fn handle_resign(&mut self, player: ActorId, game_id: u64) -> Bet2ChessEvents {
+        let temp = self.state
+            .resign(player, game_id);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(ended_game_id) => Bet2ChessEvents::GameEnded(ended_game_id)
+        }
+    }
+
+    // Web2 ids can only be used by the address the user registry links them to,
+    // and the username of the player is always the one of their profile. If
+    // any of them is missing the value sent is returned
+    fn player_username(&mut self, user_address: ActorId, web2_id: u64, value: u128) -> Result<String, Bet2ChessEvents> {
+        if !self.registry_state_ref.is_linked(web2_id, user_address) {
+            return Err(self.refund_value(
+                user_address,
+                value,
+                Bet2ChessErrors::UserAddressAndWeb2IdAreNotRelated
+            ));
+        }
 
-            msg::send(caller, payload.clone(), value)
-                .expect("Error while sending message");
+        let Some(username) = self.registry_state_ref.username(web2_id) else {
+            return Err(self.refund_value(
+                user_address,
+                value,
+                Bet2ChessErrors::PlayerHasNoProfile(web2_id)
+            ));
+        };
+
+        Ok(username)
+    }
+
+    // The value sent with a command that fails is credited to the user balance
+    fn refund_value(&mut self, user_address: ActorId, value: u128, error: Bet2ChessErrors) -> Bet2ChessEvents {
+        self.state.credit(user_address, value);
+
+        Bet2ChessEvents::Error(error)
+    }
+
+    // The value sent with a signless command that is rejected is credited to
+    // the account that sent the message
+    fn refund_signless_value(&mut self, value: u128, error: SignlessError) -> Bet2ChessEvents {
+        self.state.credit(msg::source(), value);
+
+        Bet2ChessEvents::SignlessError(error)
+    }
+
+    fn linked_web2_id(&self, user_address: ActorId) -> Result<u64, Bet2ChessEvents> {
+        self.registry_state_ref
+            .web2_id_by_address
+            .get(&user_address)
+            .copied()
+            .ok_or(Bet2ChessEvents::Error(Bet2ChessErrors::UserAddressAndWeb2IdAreNotRelated))
+    }
 
-            return Err(payload);
+    // The stake is the exact value sent in planck, if it is out of the
+    // limits the whole value is credited back
+    fn check_stake(&mut self, caller: ActorId, value: u128) -> Result<BetAmout, Bet2ChessEvents> {
+        if let Err(error) = self.state.check_stake(value) {
+            return Err(self.refund_value(caller, value, error));
         }
 
-        Ok(value / ONE_VARA)
+        Ok(value)
     }
 }
 
@@ -461,7 +1826,335 @@ pub enum Bet2ChessEvents {
     GameCreated(GameId),
     JoinedInGame(GameId),
     GameEnded(GameId),
+    GameDrawn(GameId),
+    GameCancelled(GameId),
+    GameAborted(GameId),
+    JoinedMatchmakingQueue,
+    LeftMatchmakingQueue,
     InvitationSentTo(u64),
     InvitationCancelled,
-    Price
+    InvitationDeclined,
+    InvitationExpired,
+    InvitationsExpired(u32),
+    InvitationTtlSet(u32),
+    Price,
+    AdminAdded(ActorId),
+    AdminRemoved(ActorId),
+    ResultOracleAdded(ActorId),
+    ResultOracleRemoved(ActorId),
+    FeeConfigSet(FeeConfig),
+    StakeLimitsSet(StakeLimits),
+    MarketConfigSet(MarketConfig),
+    SpectatorBetPlaced {
+        game_id: GameId,
+        outcome: MarketOutcome,
+        amount: u128
+    },
+    SpectatorWinningsClaimed {
+        game_id: GameId,
+        amount: u128
+    },
+    Withdrawn(u128),
+    TokenWithdrawn {
+        token: ActorId,
+        amount: u128
+    },
+    TreasuryTokenWithdrawn {
+        token: ActorId,
+        to: ActorId,
+        amount: u128
+    },
+    TreasuryWithdrawn {
+        to: ActorId,
+        amount: u128
+    },
+    MovePlayed {
+        game_id: GameId,
+        chess_move: ChessMove,
+        board_status: BoardStatus
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sails_rs::cell::RefCell;
+    use crate::states::{
+        bet2chess_state::ONE_VARA,
+        runtime::mock,
+        signless_accounts_state::SignlessAccount,
+        test_utils::{
+            address,
+            new_state,
+            start_game,
+            ADMIN,
+            STAKE
+        }
+    };
+
+    fn invitations_lifetime(bet2chess_state: &RefCell<ChessState>, web2_id: u64) -> Vec<InvitationLifetime> {
+        let signless_state = RefCell::new(ContractSignlessAccounts::default());
+        let registry_state = RefCell::new(UserRegistryState::default());
+        let service = Bet2ChessService::new(bet2chess_state.borrow_mut(), signless_state.borrow(), registry_state.borrow());
+
+        service.invitations_lifetime(web2_id)
+    }
+
+    #[test]
+    fn invitations_lifetime_counts_down_with_the_blocks() {
+        let bet2chess_state = RefCell::new(new_state());
+        bet2chess_state.borrow_mut().set_invitation_ttl(address(ADMIN), 100).unwrap();
+
+        mock::set_block_height(10);
+        bet2chess_state.borrow_mut().create_invitation_with_bet(address(20), 20, 21, STAKE, 2 * STAKE, None).unwrap();
+        bet2chess_state.borrow_mut().create_invitation_with_bet(address(22), 22, 23, STAKE, STAKE, None).unwrap();
+
+        mock::set_block_height(40);
+        let lifetimes = invitations_lifetime(&bet2chess_state, 21);
+
+        assert_eq!(lifetimes.len(), 1);
+        let lifetime = &lifetimes[0];
+        assert_eq!((lifetime.inviter_web2_id, lifetime.guest_web2_id), (20, 21));
+        assert_eq!((lifetime.bet_amount, lifetime.guest_bet_amount), (STAKE, 2 * STAKE));
+        assert_eq!((lifetime.created_at_block, lifetime.expires_at_block), (10, 110));
+        assert_eq!(lifetime.remaining_blocks, 70);
+
+        // Invitations that expired but weren't swept yet have no blocks left
+        mock::set_block_height(200);
+        assert_eq!(invitations_lifetime(&bet2chess_state, 20)[0].remaining_blocks, 0);
+        assert!(invitations_lifetime(&bet2chess_state, 30).is_empty());
+    }
+
+    fn with_service<T>(bet2chess_state: &RefCell<ChessState>, caller: u64, command: impl FnOnce(&mut Bet2ChessService) -> T) -> T {
+        let accounts = Accounts::default();
+
+        accounts.call(bet2chess_state, caller, command)
+    }
+
+    // Signless sessions and web2 ids linked to the addresses that call the service
+    #[derive(Default)]
+    struct Accounts {
+        signless_state: RefCell<ContractSignlessAccounts>,
+        registry_state: RefCell<UserRegistryState>
+    }
+
+    impl Accounts {
+        fn link(&self, caller: u64, web2_id: u64) {
+            let mut registry_state = self.registry_state.borrow_mut();
+
+            registry_state.web2_id_by_address.insert(address(caller), web2_id);
+            registry_state.address_by_web2_id.insert(web2_id, address(caller));
+        }
+
+        fn register(&self, caller: u64, web2_id: u64) {
+            self.link(caller, web2_id);
+
+            assert!(self.registry_state
+                .borrow_mut()
+                .set_profile(address(caller), web2_id, format!("player{web2_id}"), None, None)
+                .is_ok());
+        }
+
+        fn call<T>(&self, bet2chess_state: &RefCell<ChessState>, caller: u64, command: impl FnOnce(&mut Bet2ChessService) -> T) -> T {
+            let mut service = Bet2ChessService::new(bet2chess_state.borrow_mut(), self.signless_state.borrow(), self.registry_state.borrow());
+
+            mock::set_source(caller);
+
+            command(&mut service)
+        }
+    }
+
+    #[test]
+    fn only_admins_and_result_oracles_end_games_from_the_service() {
+        let bet2chess_state = RefCell::new(new_state());
+        bet2chess_state.borrow_mut().add_result_oracle(address(ADMIN), address(2)).unwrap();
+        let game_id = start_game(&mut bet2chess_state.borrow_mut(), 10, 11, (STAKE, STAKE));
+
+        for caller in [10, 11, 3] {
+            let by_id = with_service(&bet2chess_state, caller, |service| service.end_game_by_id(game_id, None));
+            let by_match = with_service(&bet2chess_state, caller, |service| service.end_match(game_id, Some(address(10))));
+
+            assert!(matches!(by_id, Bet2ChessEvents::Error(Bet2ChessErrors::OnlyAdminsCanEndGames)));
+            assert!(matches!(by_match, Bet2ChessEvents::Error(Bet2ChessErrors::OnlyAdminsCanEndGames)));
+        }
+
+        let ended = with_service(&bet2chess_state, 2, |service| service.end_game_by_id(game_id, Some(address(11))));
+        assert!(matches!(ended, Bet2ChessEvents::GameEnded(id) if id == game_id));
+        assert_eq!(bet2chess_state.borrow().balance(address(11)), 2 * STAKE);
+
+        let drawn_game = start_game(&mut bet2chess_state.borrow_mut(), 10, 11, (STAKE, STAKE));
+        let drawn = with_service(&bet2chess_state, ADMIN, |service| service.end_match(drawn_game, None));
+        assert!(matches!(drawn, Bet2ChessEvents::GameDrawn(id) if id == drawn_game));
+    }
+
+    const INVITER: u64 = 20;
+    const GUEST: u64 = 21;
+    const SIGNLESS: u64 = 30;
+
+    fn invite(bet2chess_state: &RefCell<ChessState>) {
+        bet2chess_state
+            .borrow_mut()
+            .create_invitation_with_bet(address(INVITER), INVITER, GUEST, STAKE, STAKE, None)
+            .unwrap();
+    }
+
+    #[test]
+    fn invitations_are_cancelled_or_declined_by_their_participants() {
+        let bet2chess_state = RefCell::new(new_state());
+        let accounts = Accounts::default();
+        accounts.link(INVITER, INVITER);
+        accounts.link(GUEST, GUEST);
+        accounts.link(3, 3);
+        invite(&bet2chess_state);
+
+        // Callers without a linked web2 id can't prove who they are
+        let unlinked = accounts.call(&bet2chess_state, 4, |service| service.cancel_invitation(INVITER, GUEST));
+        assert!(matches!(unlinked, Bet2ChessEvents::Error(Bet2ChessErrors::UserAddressAndWeb2IdAreNotRelated)));
+
+        for event in [
+            accounts.call(&bet2chess_state, 3, |service| service.cancel_invitation(INVITER, GUEST)),
+            accounts.call(&bet2chess_state, 3, |service| service.decline_invitation(INVITER, GUEST))
+        ] {
+            assert!(matches!(event, Bet2ChessEvents::Error(Bet2ChessErrors::OnlyInvitationParticipantsCanCancelIt)));
+        }
+
+        let by_inviter = accounts.call(&bet2chess_state, INVITER, |service| service.decline_invitation(INVITER, GUEST));
+        assert!(matches!(by_inviter, Bet2ChessEvents::Error(Bet2ChessErrors::OnlyTheGuestCanDeclineTheInvitation)));
+        assert_eq!(bet2chess_state.borrow().invitations.len(), 1);
+
+        // The guest cancelling the invitation declines it
+        let cancelled_by_guest = accounts.call(&bet2chess_state, GUEST, |service| service.cancel_invitation(INVITER, GUEST));
+        assert!(matches!(cancelled_by_guest, Bet2ChessEvents::InvitationDeclined));
+
+        invite(&bet2chess_state);
+        let declined = accounts.call(&bet2chess_state, GUEST, |service| service.decline_invitation(INVITER, GUEST));
+        assert!(matches!(declined, Bet2ChessEvents::InvitationDeclined));
+
+        invite(&bet2chess_state);
+        let cancelled = accounts.call(&bet2chess_state, INVITER, |service| service.cancel_invitation(INVITER, GUEST));
+        assert!(matches!(cancelled, Bet2ChessEvents::InvitationCancelled));
+
+        assert_eq!(bet2chess_state.borrow().balance(address(INVITER)), 3 * STAKE);
+        assert!(bet2chess_state.borrow().invitations.is_empty());
+    }
+
+    #[test]
+    fn signless_sessions_cancel_and_decline_for_their_user() {
+        let bet2chess_state = RefCell::new(new_state());
+        let accounts = Accounts::default();
+        accounts.link(INVITER, INVITER);
+        accounts.link(GUEST, GUEST);
+        assert!(accounts.signless_state
+            .borrow_mut()
+            .set_signless_account_to_user_address(address(SIGNLESS), address(GUEST), SignlessAccount::default())
+            .is_ok());
+        invite(&bet2chess_state);
+
+        // A session only acts for the user it was created for
+        for event in [
+            accounts.call(&bet2chess_state, SIGNLESS, |service| service.cancel_invitation_signless(address(INVITER), INVITER, GUEST)),
+            accounts.call(&bet2chess_state, 3, |service| service.decline_invitation_signless(address(GUEST), INVITER, GUEST))
+        ] {
+            assert!(matches!(event, Bet2ChessEvents::SignlessError(_)));
+        }
+        assert_eq!(bet2chess_state.borrow().invitations.len(), 1);
+
+        let cancelled = accounts.call(&bet2chess_state, SIGNLESS, |service| service.cancel_invitation_signless(address(GUEST), INVITER, GUEST));
+        assert!(matches!(cancelled, Bet2ChessEvents::InvitationDeclined));
+
+        invite(&bet2chess_state);
+        let declined = accounts.call(&bet2chess_state, SIGNLESS, |service| service.decline_invitation_signless(address(GUEST), INVITER, GUEST));
+        assert!(matches!(declined, Bet2ChessEvents::InvitationDeclined));
+
+        assert_eq!(bet2chess_state.borrow().balance(address(INVITER)), 2 * STAKE);
+    }
+
+    #[test]
+    fn no_wallet_sessions_cancel_and_decline_with_their_own_web2_id() {
+        let bet2chess_state = RefCell::new(new_state());
+        let accounts = Accounts::default();
+        accounts.link(SIGNLESS, GUEST);
+        assert!(accounts.signless_state
+            .borrow_mut()
+            .set_signless_account_to_no_wallet_name(address(SIGNLESS), "guest".to_string(), SignlessAccount::default())
+            .is_ok());
+        invite(&bet2chess_state);
+
+        for event in [
+            accounts.call(&bet2chess_state, SIGNLESS, |service| service.cancel_invitation_signless_no_wallet("inviter".to_string(), INVITER, GUEST)),
+            accounts.call(&bet2chess_state, 3, |service| service.decline_invitation_signless_no_wallet("guest".to_string(), INVITER, GUEST))
+        ] {
+            assert!(matches!(event, Bet2ChessEvents::SignlessError(_)));
+        }
+
+        let cancelled = accounts.call(&bet2chess_state, SIGNLESS, |service| service.cancel_invitation_signless_no_wallet("guest".to_string(), INVITER, GUEST));
+        assert!(matches!(cancelled, Bet2ChessEvents::InvitationDeclined));
+
+        invite(&bet2chess_state);
+        let declined = accounts.call(&bet2chess_state, SIGNLESS, |service| service.decline_invitation_signless_no_wallet("guest".to_string(), INVITER, GUEST));
+        assert!(matches!(declined, Bet2ChessEvents::InvitationDeclined));
+
+        assert_eq!(bet2chess_state.borrow().balance(address(INVITER)), 2 * STAKE);
+        assert!(bet2chess_state.borrow().invitations.is_empty());
+    }
+
+    fn create_open_game(bet2chess_state: &RefCell<ChessState>, accounts: &Accounts, value: u128) -> Bet2ChessEvents {
+        mock::set_value(value);
+
+        accounts.call(bet2chess_state, INVITER, |service| service.create_open_game(INVITER))
+    }
+
+    #[test]
+    fn stakes_out_of_the_limits_are_refunded_in_full() {
+        let bet2chess_state = RefCell::new(new_state());
+        let accounts = Accounts::default();
+        accounts.register(INVITER, INVITER);
+
+        let half_vara = ONE_VARA / 2;
+        bet2chess_state
+            .borrow_mut()
+            .set_stake_limits(address(ADMIN), StakeLimits { min_stake: half_vara, max_stake: STAKE })
+            .unwrap();
+
+        let below = create_open_game(&bet2chess_state, &accounts, half_vara - 1);
+        assert!(matches!(below, Bet2ChessEvents::Error(Bet2ChessErrors::StakeBelowMinimum(min)) if min == half_vara));
+        assert_eq!(bet2chess_state.borrow().balance(address(INVITER)), half_vara - 1);
+
+        let above = create_open_game(&bet2chess_state, &accounts, STAKE + 1);
+        assert!(matches!(above, Bet2ChessEvents::Error(Bet2ChessErrors::StakeAboveMaximum(max)) if max == STAKE));
+        assert_eq!(bet2chess_state.borrow().balance(address(INVITER)), half_vara + STAKE);
+
+        // A stake out of the limits for the guest refunds the inviter
+        mock::set_value(STAKE);
+        let invitation = accounts.call(&bet2chess_state, INVITER, |service| service.send_invitation(INVITER, GUEST, Some(STAKE + 1)));
+        assert!(matches!(invitation, Bet2ChessEvents::Error(Bet2ChessErrors::StakeAboveMaximum(_))));
+        assert_eq!(bet2chess_state.borrow().balance(address(INVITER)), half_vara + 2 * STAKE);
+        assert!(bet2chess_state.borrow().invitations.is_empty());
+        assert!(bet2chess_state.borrow().games_by_id.is_empty());
+    }
+
+    #[test]
+    fn stakes_in_the_limits_are_kept_to_the_planck() {
+        let bet2chess_state = RefCell::new(new_state());
+        let accounts = Accounts::default();
+        accounts.register(INVITER, INVITER);
+
+        let half_vara = ONE_VARA / 2;
+        bet2chess_state
+            .borrow_mut()
+            .set_stake_limits(address(ADMIN), StakeLimits { min_stake: half_vara, max_stake: STAKE })
+            .unwrap();
+
+        // Neither sub VARA nor inexact stakes are rounded
+        for value in [half_vara, half_vara + 1, ONE_VARA + 123, STAKE] {
+            let created = create_open_game(&bet2chess_state, &accounts, value);
+            let Bet2ChessEvents::GameCreated(game_id) = created else {
+                panic!("a stake of {value} planck is in the limits");
+            };
+
+            assert_eq!(bet2chess_state.borrow().game_by_id(game_id).unwrap().game_bet, value);
+        }
+
+        assert_eq!(bet2chess_state.borrow().balance(address(INVITER)), 0);
+    }
 }
@@ -1,3 +1,6 @@
 pub mod bet2chess_service;
 pub mod signless_service;
-pub mod query_service;
\ No newline at end of file
+pub mod query_service;
+pub mod token_stakes_service;
+pub mod tournament_service;
+pub mod user_registry_service;
\ No newline at end of file
@@ -81,7 +81,7 @@ impl<'a> QueryService<'a> {
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub enum QueryEvent {
-    GameData(GameData),
+    GameData(Box<GameData>),
     SignlessAccountAddress(Option<ActorId>),
     SignlessAccountData(Option<SignlessAccount>),
 }
\ No newline at end of file
@@ -0,0 +1,740 @@
+use sails_rs::{
+    prelude::*,
+    gstd::service,
+    cell::RefCell
+};
+
+use crate::states::{
+    runtime::msg,
+    bet2chess_state::{
+        Bet2ChessErrors,
+        ChessState,
+        Stake
+    },
+    signless_accounts_state::ContractSignlessAccounts,
+    user_registry_state::UserRegistryState
+};
+
+use super::bet2chess_service::Bet2ChessEvents;
+
+// Commands with stakes in VFT tokens. They wait for the replies of the token
+// programs, so the states are only borrowed between the awaits and never
+// while a transfer is in progress.
+pub struct TokenStakesService<'a> {
+    pub bet2chess_state: &'a RefCell<ChessState>,
+    pub signless_state: &'a RefCell<ContractSignlessAccounts>,
+    pub registry_state: &'a RefCell<UserRegistryState>
+}
+
+#[service]
+impl<'a> TokenStakesService<'a> {
+    pub fn new(
+        bet2chess_state: &'a RefCell<ChessState>,
+        signless_state: &'a RefCell<ContractSignlessAccounts>,
+        registry_state: &'a RefCell<UserRegistryState>
+    ) -> Self {
+        Self {
+            bet2chess_state,
+            signless_state,
+            registry_state
+        }
+    }
+
+    pub async fn send_token_invitation(
+        &mut self,
+        web2_user_id: u64,
+        guest_web2_id: u64,
+        token: ActorId,
+        amount: u128,
+        guest_amount: Option<u128>
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_send_token_invitation(
+            caller,
+            web2_user_id,
+            guest_web2_id,
+            token,
+            amount,
+            guest_amount
+        ).await
+    }
+
+    pub async fn send_token_invitation_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        guest_web2_id: u64,
+        token: ActorId,
+        amount: u128,
+        guest_amount: Option<u128>
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_send_token_invitation(
+            user_address,
+            web2_user_id,
+            guest_web2_id,
+            token,
+            amount,
+            guest_amount
+        ).await
+    }
+
+    pub async fn send_token_invitation_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        guest_web2_id: u64,
+        token: ActorId,
+        amount: u128,
+        guest_amount: Option<u128>
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_send_token_invitation(
+            caller,
+            web2_user_id,
+            guest_web2_id,
+            token,
+            amount,
+            guest_amount
+        ).await
+    }
+
+    pub async fn accept_token_invitation(
+        &mut self,
+        web2_user_id: u64,
+        web2_user_id_invitation_owner: u64,
+        web2_match_game_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_accept_token_invitation(
+            caller,
+            web2_user_id,
+            web2_user_id_invitation_owner,
+            web2_match_game_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn accept_token_invitation_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        web2_user_id_invitation_owner: u64,
+        web2_match_game_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_accept_token_invitation(
+            user_address,
+            web2_user_id,
+            web2_user_id_invitation_owner,
+            web2_match_game_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn accept_token_invitation_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        web2_user_id_invitation_owner: u64,
+        web2_match_game_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_accept_token_invitation(
+            caller,
+            web2_user_id,
+            web2_user_id_invitation_owner,
+            web2_match_game_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn create_open_token_game(
+        &mut self,
+        web2_user_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_create_open_token_game(
+            caller,
+            web2_user_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn create_open_token_game_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_create_open_token_game(
+            user_address,
+            web2_user_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn create_open_token_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_create_open_token_game(
+            caller,
+            web2_user_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn join_open_token_game(
+        &mut self,
+        web2_user_id: u64,
+        game_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_join_open_token_game(
+            caller,
+            web2_user_id,
+            game_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn join_open_token_game_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        game_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_join_open_token_game(
+            user_address,
+            web2_user_id,
+            game_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn join_open_token_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        game_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_join_open_token_game(
+            caller,
+            web2_user_id,
+            game_id,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn withdraw_token(
+        &mut self,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        self.handle_withdraw_token(
+            caller,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn withdraw_token_signless(
+        &mut self,
+        user_address: ActorId,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_withdraw_token(
+            user_address,
+            token,
+            amount
+        ).await
+    }
+
+    pub async fn withdraw_token_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state
+            .borrow()
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return Bet2ChessEvents::SignlessError(signless_error);
+        }
+
+        self.handle_withdraw_token(
+            caller,
+            token,
+            amount
+        ).await
+    }
+
+    pub fn withdraw_treasury_token(&mut self, token: ActorId, to: ActorId, amount: u128) -> Bet2ChessEvents {
+        let caller = msg::source();
+
+        let temp = self.bet2chess_state
+            .borrow_mut()
+            .withdraw_treasury_token(caller, token, to, amount);
+
+        match temp {
+            Ok(_) => Bet2ChessEvents::TreasuryTokenWithdrawn { token, to, amount },
+            Err(error) => Bet2ChessEvents::Error(error)
+        }
+    }
+
+    pub fn token_balance(&self, token: ActorId, address: ActorId) -> u128 {
+        self.bet2chess_state
+            .borrow()
+            .token_balance(token, address)
+    }
+
+    pub fn treasury_token_balance(&self, token: ActorId) -> u128 {
+        self.bet2chess_state
+            .borrow()
+            .treasury
+            .token_balances
+            .get(&token)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl<'a> TokenStakesService<'a> {
+    async fn handle_send_token_invitation(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        guest_id: u64,
+        token: ActorId,
+        amount: u128,
+        guest_amount: Option<u128>
+    ) -> Bet2ChessEvents {
+        if let Err(error) = self.check_player(user_address, user_id) {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        // Without a stake for the guest both players bet the same
+        let guest_amount = guest_amount.unwrap_or(amount);
+
+        let temp = self.bet2chess_state
+            .borrow()
+            .check_token_invitation(user_id, guest_id, amount, guest_amount);
+
+        if let Err(error) = temp {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        if let Err(error) = ChessState::pull_tokens(token, user_address, amount).await {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        let temp = self.bet2chess_state
+            .borrow_mut()
+            .create_invitation_with_bet(user_address, user_id, guest_id, amount, guest_amount, Some(token));
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(_) => Bet2ChessEvents::InvitationSentTo(guest_id)
+        }
+    }
+
+    async fn handle_accept_token_invitation(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        user_who_invite: u64,
+        web2_game_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let username = match self.check_player(user_address, user_id) {
+            Err(error) => return Bet2ChessEvents::Error(error),
+            Ok(username) => username
+        };
+
+        let Some(inviter_username) = self.registry_state.borrow().username(user_who_invite) else {
+            return Bet2ChessEvents::Error(Bet2ChessErrors::PlayerHasNoProfile(user_who_invite));
+        };
+
+        let temp = self.bet2chess_state
+            .borrow()
+            .check_token_invitation_acceptance(user_id, user_who_invite, web2_game_id, token, amount);
+
+        if let Err(error) = temp {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        if let Err(error) = ChessState::pull_tokens(token, user_address, amount).await {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        let temp = self.bet2chess_state
+            .borrow_mut()
+            .accept_invitation(
+                user_address,
+                (user_who_invite, user_id),
+                web2_game_id,
+                inviter_username,
+                username,
+                Stake {
+                    amount,
+                    token: Some(token)
+                }
+            );
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(game_id) => Bet2ChessEvents::GameCreated(game_id)
+        }
+    }
+
+    async fn handle_create_open_token_game(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let username = match self.check_player(user_address, user_id) {
+            Err(error) => return Bet2ChessEvents::Error(error),
+            Ok(username) => username
+        };
+
+        if amount == 0 {
+            return Bet2ChessEvents::Error(Bet2ChessErrors::TokenStakeCantBeZero);
+        }
+
+        if let Err(error) = ChessState::pull_tokens(token, user_address, amount).await {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        let temp = self.bet2chess_state
+            .borrow_mut()
+            .create_open_game(user_address, username, user_id, amount, Some(token));
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(game_id) => Bet2ChessEvents::GameCreated(game_id)
+        }
+    }
+
+    async fn handle_join_open_token_game(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        game_id: u64,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        let username = match self.check_player(user_address, user_id) {
+            Err(error) => return Bet2ChessEvents::Error(error),
+            Ok(username) => username
+        };
+
+        let temp = self.bet2chess_state
+            .borrow()
+            .check_token_game_join(user_address, user_id, game_id, token, amount);
+
+        if let Err(error) = temp {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        if let Err(error) = ChessState::pull_tokens(token, user_address, amount).await {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        let temp = self.bet2chess_state
+            .borrow_mut()
+            .join_open_game(user_address, username, user_id, amount, Some(token), game_id);
+
+        match temp {
+            Err(error) => Bet2ChessEvents::Error(error),
+            Ok(game_id) => Bet2ChessEvents::JoinedInGame(game_id)
+        }
+    }
+
+    async fn handle_withdraw_token(
+        &mut self,
+        user_address: ActorId,
+        token: ActorId,
+        amount: u128
+    ) -> Bet2ChessEvents {
+        if let Err(error) = self.reject_value(user_address) {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        // The balance is taken before the transfer so it can't be withdrawn
+        // twice, and it is returned if the transfer fails
+        let temp = self.bet2chess_state
+            .borrow_mut()
+            .debit_token(token, user_address, amount);
+
+        if let Err(error) = temp {
+            return Bet2ChessEvents::Error(error);
+        }
+
+        if let Err(error) = ChessState::push_tokens(token, user_address, amount).await {
+            self.bet2chess_state
+                .borrow_mut()
+                .credit_token(token, user_address, amount);
+
+            return Bet2ChessEvents::Error(error);
+        }
+
+        Bet2ChessEvents::TokenWithdrawn { token, amount }
+    }
+
+    // The player has to use a web2 id linked to their address and have a
+    // profile, the value sent is rejected since the stake is in tokens
+    fn check_player(&self, user_address: ActorId, web2_id: u64) -> Result<String, Bet2ChessErrors> {
+        self.reject_value(user_address)?;
+
+        let registry_state = self.registry_state.borrow();
+
+        if !registry_state.is_linked(web2_id, user_address) {
+            return Err(Bet2ChessErrors::UserAddressAndWeb2IdAreNotRelated);
+        }
+
+        registry_state
+            .username(web2_id)
+            .ok_or(Bet2ChessErrors::PlayerHasNoProfile(web2_id))
+    }
+
+    // VARA sent by mistake is credited to the ledger balance of the user
+    fn reject_value(&self, user_address: ActorId) -> Result<(), Bet2ChessErrors> {
+        let value = msg::value();
+
+        if value == 0 {
+            return Ok(());
+        }
+
+        self.bet2chess_state
+            .borrow_mut()
+            .credit(user_address, value);
+
+        Err(Bet2ChessErrors::TokenStakesDontTakeValue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        runtime::mock,
+        test_utils::{
+            address,
+            block_on,
+            new_state,
+            STAKE
+        }
+    };
+
+    const TOKEN: u64 = 500;
+    const PLAYER: u64 = 10;
+
+    fn withdraw(bet2chess_state: &RefCell<ChessState>, amount: u128) -> Bet2ChessEvents {
+        let signless_state = RefCell::new(ContractSignlessAccounts::default());
+        let registry_state = RefCell::new(UserRegistryState::default());
+        let mut service = TokenStakesService::new(bet2chess_state, &signless_state, &registry_state);
+
+        mock::set_source(PLAYER);
+
+        block_on(service.withdraw_token(address(TOKEN), amount))
+    }
+
+    #[test]
+    fn withdrawn_tokens_are_credited_back_if_the_transfer_fails() {
+        let bet2chess_state = RefCell::new(new_state());
+        bet2chess_state.borrow_mut().credit_token(address(TOKEN), address(PLAYER), STAKE);
+
+        mock::set_reply(None);
+
+        let event = withdraw(&bet2chess_state, STAKE);
+
+        assert!(matches!(event, Bet2ChessEvents::Error(Bet2ChessErrors::TokenTransferFailed)));
+        assert_eq!(bet2chess_state.borrow().token_balance(address(TOKEN), address(PLAYER)), STAKE);
+    }
+
+    #[test]
+    fn withdrawn_tokens_leave_the_balance_once_transferred() {
+        let bet2chess_state = RefCell::new(new_state());
+        bet2chess_state.borrow_mut().credit_token(address(TOKEN), address(PLAYER), STAKE);
+
+        mock::set_reply(Some((String::from("Vft"), String::from("Transfer"), true).encode()));
+
+        let event = withdraw(&bet2chess_state, STAKE);
+
+        assert!(matches!(event, Bet2ChessEvents::TokenWithdrawn { amount: STAKE, .. }));
+        assert_eq!(bet2chess_state.borrow().token_balance(address(TOKEN), address(PLAYER)), 0);
+        assert!(matches!(withdraw(&bet2chess_state, STAKE), Bet2ChessEvents::Error(Bet2ChessErrors::InsufficientBalance(0))));
+    }
+
+    #[test]
+    fn value_sent_with_a_token_command_is_credited_to_the_ledger() {
+        let bet2chess_state = RefCell::new(new_state());
+        bet2chess_state.borrow_mut().credit_token(address(TOKEN), address(PLAYER), STAKE);
+
+        mock::set_value(STAKE);
+
+        let event = withdraw(&bet2chess_state, STAKE);
+
+        assert!(matches!(event, Bet2ChessEvents::Error(Bet2ChessErrors::TokenStakesDontTakeValue)));
+        assert_eq!(bet2chess_state.borrow().balance(address(PLAYER)), STAKE);
+        assert_eq!(bet2chess_state.borrow().token_balance(address(TOKEN), address(PLAYER)), STAKE);
+    }
+}
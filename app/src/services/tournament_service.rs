@@ -0,0 +1,331 @@
+use sails_rs::{
+    prelude::*,
+    gstd::{
+        service,
+        msg
+    },
+    cell::{
+        Ref,
+        RefMut
+    }
+};
+
+use crate::states::{
+    bet2chess_state::{
+        Bet2ChessErrors,
+        ChessState,
+        UserWeb2Id
+    },
+    signless_accounts_state::{
+        ContractSignlessAccounts,
+        SignlessError
+    },
+    tournament_state::{
+        Tournament,
+        TournamentConfig,
+        TournamentId,
+        TournamentPairing
+    },
+    user_registry_state::UserRegistryState
+};
+
+pub struct TournamentService<'a> {
+    pub state: RefMut<'a, ChessState>,
+    pub signless_state_ref: Ref<'a, ContractSignlessAccounts>,
+    pub registry_state_ref: Ref<'a, UserRegistryState>
+}
+
+#[service]
+impl<'a> TournamentService<'a> {
+    pub fn new(
+        state: RefMut<'a, ChessState>,
+        signless_state_ref: Ref<'a, ContractSignlessAccounts>,
+        registry_state_ref: Ref<'a, UserRegistryState>
+    ) -> Self {
+        Self {
+            state,
+            signless_state_ref,
+            registry_state_ref
+        }
+    }
+
+    pub fn tournament(&self, tournament_id: TournamentId) -> Option<Tournament> {
+        self.state
+            .tournament(tournament_id)
+            .cloned()
+    }
+
+    // Pairings of a round, the first round is zero
+    pub fn tournament_round(&self, tournament_id: TournamentId, round: u32) -> Vec<TournamentPairing> {
+        self.state
+            .tournament(tournament_id)
+            .and_then(|tournament| tournament.rounds.get(round as usize))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // Positions and scores while the tournament is running
+    pub fn tournament_standings(&self, tournament_id: TournamentId) -> Vec<(UserWeb2Id, u32)> {
+        self.state
+            .tournament(tournament_id)
+            .map(|tournament| tournament.standings_with_scores())
+            .unwrap_or_default()
+    }
+
+    pub fn create_tournament(&mut self, config: TournamentConfig) -> TournamentEvents {
+        let caller = msg::source();
+
+        match self.state.create_tournament(caller, config) {
+            Ok(tournament_id) => TournamentEvents::TournamentCreated(tournament_id),
+            Err(error) => TournamentEvents::Error(error)
+        }
+    }
+
+    pub fn start_tournament(&mut self, tournament_id: TournamentId) -> TournamentEvents {
+        let caller = msg::source();
+
+        match self.state.start_tournament(caller, tournament_id) {
+            Ok(_) => TournamentEvents::TournamentStarted(tournament_id),
+            Err(error) => TournamentEvents::Error(error)
+        }
+    }
+
+    pub fn close_arena(&mut self, tournament_id: TournamentId) -> TournamentEvents {
+        match self.state.close_arena(tournament_id) {
+            Ok(_) => TournamentEvents::ArenaClosed(tournament_id),
+            Err(error) => TournamentEvents::Error(error)
+        }
+    }
+
+    pub fn create_pending_tournament_games(&mut self, tournament_id: TournamentId) -> TournamentEvents {
+        match self.state.create_pending_tournament_games(tournament_id) {
+            Ok(created) => TournamentEvents::PendingTournamentGamesCreated(created),
+            Err(error) => TournamentEvents::Error(error)
+        }
+    }
+
+    pub fn forfeit_tournament_game(&mut self, game_id: u64, absent_player: UserWeb2Id) -> TournamentEvents {
+        let caller = msg::source();
+
+        match self.state.forfeit_tournament_game(caller, game_id, absent_player) {
+            Ok(game_id) => TournamentEvents::TournamentGameForfeited(game_id),
+            Err(error) => TournamentEvents::Error(error)
+        }
+    }
+
+    pub fn cancel_tournament(&mut self, tournament_id: TournamentId) -> TournamentEvents {
+        let caller = msg::source();
+
+        match self.state.cancel_tournament(caller, tournament_id) {
+            Ok(_) => TournamentEvents::TournamentCancelled(tournament_id),
+            Err(error) => TournamentEvents::Error(error)
+        }
+    }
+
+    pub fn register_in_tournament(
+        &mut self,
+        web2_user_id: u64,
+        tournament_id: TournamentId
+    ) -> TournamentEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        self.handle_register_in_tournament(
+            caller,
+            web2_user_id,
+            tournament_id,
+            value
+        )
+    }
+
+    pub fn register_in_tournament_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        tournament_id: TournamentId
+    ) -> TournamentEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_register_in_tournament(
+            user_address,
+            web2_user_id,
+            tournament_id,
+            value
+        )
+    }
+
+    pub fn register_in_tournament_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        tournament_id: TournamentId
+    ) -> TournamentEvents {
+        let caller = msg::source();
+        let value = msg::value();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return self.refund_signless_value(value, signless_error);
+        }
+
+        self.handle_register_in_tournament(
+            caller,
+            web2_user_id,
+            tournament_id,
+            value
+        )
+    }
+
+    pub fn leave_tournament(
+        &mut self,
+        web2_user_id: u64,
+        tournament_id: TournamentId
+    ) -> TournamentEvents {
+        let caller = msg::source();
+
+        self.handle_leave_tournament(
+            caller,
+            web2_user_id,
+            tournament_id
+        )
+    }
+
+    pub fn leave_tournament_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        tournament_id: TournamentId
+    ) -> TournamentEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return TournamentEvents::SignlessError(signless_error);
+        }
+
+        self.handle_leave_tournament(
+            user_address,
+            web2_user_id,
+            tournament_id
+        )
+    }
+
+    pub fn leave_tournament_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        tournament_id: TournamentId
+    ) -> TournamentEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return TournamentEvents::SignlessError(signless_error);
+        }
+
+        self.handle_leave_tournament(
+            caller,
+            web2_user_id,
+            tournament_id
+        )
+    }
+}
+
+impl<'a> TournamentService<'a> {
+    fn handle_register_in_tournament(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        tournament_id: TournamentId,
+        entry_fee: u128
+    ) -> TournamentEvents {
+        if !self.registry_state_ref.is_linked(user_id, user_address) {
+            return self.refund_value(user_address, entry_fee, Bet2ChessErrors::UserAddressAndWeb2IdAreNotRelated);
+        }
+
+        let Some(username) = self.registry_state_ref.username(user_id) else {
+            return self.refund_value(user_address, entry_fee, Bet2ChessErrors::PlayerHasNoProfile(user_id));
+        };
+
+        let temp = self.state
+            .register_in_tournament(user_address, username, user_id, tournament_id, entry_fee);
+
+        match temp {
+            Err(error) => TournamentEvents::Error(error),
+            Ok(_) => TournamentEvents::RegisteredInTournament(tournament_id)
+        }
+    }
+
+    fn handle_leave_tournament(
+        &mut self,
+        user_address: ActorId,
+        user_id: u64,
+        tournament_id: TournamentId
+    ) -> TournamentEvents {
+        if !self.registry_state_ref.is_linked(user_id, user_address) {
+            return TournamentEvents::Error(Bet2ChessErrors::UserAddressAndWeb2IdAreNotRelated);
+        }
+
+        match self.state.leave_tournament(user_id, tournament_id) {
+            Err(error) => TournamentEvents::Error(error),
+            Ok(_) => TournamentEvents::LeftTournament(tournament_id)
+        }
+    }
+
+    // The value sent with a command that fails is credited to the user balance
+    fn refund_value(&mut self, user_address: ActorId, value: u128, error: Bet2ChessErrors) -> TournamentEvents {
+        self.state.credit(user_address, value);
+
+        TournamentEvents::Error(error)
+    }
+
+    // The value sent with a signless command that is rejected is credited to
+    // the account that sent the message
+    fn refund_signless_value(&mut self, value: u128, error: SignlessError) -> TournamentEvents {
+        self.state.credit(msg::source(), value);
+
+        TournamentEvents::SignlessError(error)
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TournamentEvents {
+    TournamentCreated(TournamentId),
+    TournamentStarted(TournamentId),
+    TournamentCancelled(TournamentId),
+    RegisteredInTournament(TournamentId),
+    LeftTournament(TournamentId),
+    ArenaClosed(TournamentId),
+    PendingTournamentGamesCreated(u32),
+    TournamentGameForfeited(u64),
+    SignlessError(SignlessError),
+    Error(Bet2ChessErrors)
+}
@@ -0,0 +1,238 @@
+use sails_rs::{
+    prelude::*,
+    gstd::{
+        service,
+        msg,
+        exec
+    },
+    cell::{
+        Ref,
+        RefMut
+    }
+};
+
+use crate::states::{
+    bet2chess_state::{
+        ChessState,
+        UserWeb2Id
+    },
+    signless_accounts_state::{
+        ContractSignlessAccounts,
+        SignlessError
+    },
+    user_registry_state::{
+        PlayerProfile,
+        UserRegistryErrors,
+        UserRegistryState
+    }
+};
+
+pub struct UserRegistryService<'a> {
+    pub state: RefMut<'a, UserRegistryState>,
+    pub bet2chess_state_ref: Ref<'a, ChessState>,
+    pub signless_state_ref: Ref<'a, ContractSignlessAccounts>
+}
+
+#[service]
+impl<'a> UserRegistryService<'a> {
+    pub fn new(
+        state: RefMut<'a, UserRegistryState>,
+        bet2chess_state_ref: Ref<'a, ChessState>,
+        signless_state_ref: Ref<'a, ContractSignlessAccounts>
+    ) -> Self {
+        Self {
+            state,
+            bet2chess_state_ref,
+            signless_state_ref
+        }
+    }
+
+    pub fn set_attester(&mut self, attester: [u8; 32]) -> UserRegistryEvents {
+        let caller = msg::source();
+
+        if !self.bet2chess_state_ref.is_admin(caller) {
+            return UserRegistryEvents::Error(UserRegistryErrors::OnlyAdminsCanDoThisAction);
+        }
+
+        self.state.attester = Some(attester);
+
+        UserRegistryEvents::AttesterSet
+    }
+
+    // Anyone can submit the link, the signature of the attester over
+    // `(program_id, web2_id, address, nonce)` is what authorizes it.
+    pub fn link_web2_id(
+        &mut self,
+        web2_id: UserWeb2Id,
+        address: ActorId,
+        signature: [u8; 64]
+    ) -> UserRegistryEvents {
+        let temp = self.state
+            .link_web2_id(exec::program_id(), web2_id, address, signature);
+
+        match temp {
+            Err(error) => UserRegistryEvents::Error(error),
+            Ok(_) => UserRegistryEvents::Web2IdLinked {
+                web2_id,
+                address
+            }
+        }
+    }
+
+    pub fn unlink_web2_id(&mut self, web2_id: UserWeb2Id) -> UserRegistryEvents {
+        let caller = msg::source();
+
+        if !self.bet2chess_state_ref.is_admin(caller) {
+            return UserRegistryEvents::Error(UserRegistryErrors::OnlyAdminsCanDoThisAction);
+        }
+
+        match self.state.unlink_web2_id(web2_id) {
+            Err(error) => UserRegistryEvents::Error(error),
+            Ok(_) => UserRegistryEvents::Web2IdUnlinked(web2_id)
+        }
+    }
+
+    pub fn set_profile(
+        &mut self,
+        web2_user_id: u64,
+        username: String,
+        avatar_hash: Option<[u8; 32]>,
+        country: Option<String>
+    ) -> UserRegistryEvents {
+        let caller = msg::source();
+
+        self.handle_set_profile(
+            caller,
+            web2_user_id,
+            username,
+            avatar_hash,
+            country
+        )
+    }
+
+    pub fn set_profile_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        username: String,
+        avatar_hash: Option<[u8; 32]>,
+        country: Option<String>
+    ) -> UserRegistryEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_user_address(
+                caller,
+                user_address
+            );
+
+        if let Err(signless_error) = result {
+            return UserRegistryEvents::SignlessError(signless_error);
+        }
+
+        self.handle_set_profile(
+            user_address,
+            web2_user_id,
+            username,
+            avatar_hash,
+            country
+        )
+    }
+
+    pub fn set_profile_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        username: String,
+        avatar_hash: Option<[u8; 32]>,
+        country: Option<String>
+    ) -> UserRegistryEvents {
+        let caller = msg::source();
+
+        let result = self.signless_state_ref
+            .check_signless_address_by_no_wallet_account(
+                caller,
+                no_wallet_name_encoded
+            );
+
+        if let Err(signless_error) = result {
+            return UserRegistryEvents::SignlessError(signless_error);
+        }
+
+        self.handle_set_profile(
+            caller,
+            web2_user_id,
+            username,
+            avatar_hash,
+            country
+        )
+    }
+
+    pub fn attester(&self) -> Option<[u8; 32]> {
+        self.state.attester
+    }
+
+    pub fn address_by_web2_id(&self, web2_id: UserWeb2Id) -> Option<ActorId> {
+        self.state
+            .address_by_web2_id
+            .get(&web2_id)
+            .copied()
+    }
+
+    pub fn web2_id_by_address(&self, address: ActorId) -> Option<UserWeb2Id> {
+        self.state
+            .web2_id_by_address
+            .get(&address)
+            .copied()
+    }
+
+    // Nonce the attester has to sign for the next link of the web2 id
+    pub fn link_nonce(&self, web2_id: UserWeb2Id) -> u64 {
+        self.state.link_nonce(web2_id)
+    }
+
+    pub fn profile(&self, web2_id: UserWeb2Id) -> Option<PlayerProfile> {
+        self.state
+            .profiles
+            .get(&web2_id)
+            .cloned()
+    }
+
+    pub fn web2_id_by_username(&self, username: String) -> Option<UserWeb2Id> {
+        self.state.web2_id_by_username(&username)
+    }
+}
+
+impl<'a> UserRegistryService<'a> {
+    fn handle_set_profile(
+        &mut self,
+        user_address: ActorId,
+        web2_id: UserWeb2Id,
+        username: String,
+        avatar_hash: Option<[u8; 32]>,
+        country: Option<String>
+    ) -> UserRegistryEvents {
+        let temp = self.state
+            .set_profile(user_address, web2_id, username, avatar_hash, country);
+
+        match temp {
+            Err(error) => UserRegistryEvents::Error(error),
+            Ok(_) => UserRegistryEvents::ProfileSet(web2_id)
+        }
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum UserRegistryEvents {
+    AttesterSet,
+    Web2IdLinked {
+        web2_id: UserWeb2Id,
+        address: ActorId
+    },
+    Web2IdUnlinked(UserWeb2Id),
+    ProfileSet(UserWeb2Id),
+    SignlessError(SignlessError),
+    Error(UserRegistryErrors)
+}
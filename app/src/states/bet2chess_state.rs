@@ -1,19 +1,48 @@
 use sails_rs::{
     prelude::*,
-    gstd::msg,
     collections::{
         BTreeMap,
+        BTreeSet,
         HashSet
     }
 };
 
-use crate::services::bet2chess_service::Bet2ChessEvents;
+use super::runtime::{
+    msg,
+    exec
+};
+
+use super::{
+    chess_board_state::{
+        BoardStatus,
+        ChessBoard,
+        ChessMove,
+        PieceColor
+    },
+    leaderboard_state::Leaderboards,
+    ledger_state::Ledger,
+    matchmaking_state::MatchmakingQueues,
+    player_stats_state::PlayerGames,
+    rating_state::Ratings,
+    spectator_market_state::SpectatorMarkets,
+    tournament_state::{
+        TournamentId,
+        Tournaments
+    },
+    treasury_state::Treasury
+};
 
 pub type UserWeb2Id = u64;
 pub type GameId = u64;
 pub type BetAmout = u128;
 
 pub const ONE_VARA: u128 = 1_000_000_000_000;
+// Las apuestas se guardan en planck, por defecto el minimo es un VARA
+pub const DEFAULT_MIN_STAKE: BetAmout = ONE_VARA;
+// Un dia con bloques de tres segundos
+pub const DEFAULT_INVITATION_TTL_BLOCKS: u32 = 28_800;
+// Gas reservado para los mensajes que el contrato se manda a si mismo
+pub const DELAYED_MESSAGE_GAS: u64 = 10_000_000_000;
 
 // pub struct User {
 //     address: Option<ActorId>,
@@ -24,6 +53,7 @@ pub const ONE_VARA: u128 = 1_000_000_000_000;
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub struct GameData {
+    // Apuesta del player1
     pub game_bet: u128,
     pub player1: ActorId,
     pub player2: ActorId,
@@ -33,6 +63,24 @@ pub struct GameData {
     pub player2_web2_id: u64,
     pub winner: Option<ActorId>,
     pub status: GameStatus,
+    // Segundos por jugador acordados en la cola de matchmaking
+    pub time_control: Option<u32>,
+    pub created_at_block: u32,
+    // Programa del token de la apuesta, `None` si la apuesta es en VARA
+    pub token: Option<ActorId>,
+    // Apuesta del player2, es la misma que `game_bet` a menos que la
+    // invitacion de la partida haya puesto otra
+    pub player2_bet: u128,
+    // Llave de la ultima posicion del tablero, se guarda cuando la partida
+    // termina y su tablero se elimina
+    pub final_position_key: Option<u64>,
+}
+
+impl GameData {
+    // Ambas apuestas, lo que recibe el ganador antes de la comision
+    pub fn pot(&self) -> u128 {
+        self.game_bet + self.player2_bet
+    }
 }
 
 pub struct InvitationsData {
@@ -46,6 +94,44 @@ pub struct InvitationsData {
     pub invitations_received: BTreeMap<UserWeb2Id, ActorId>,
 }
 
+#[derive(Encode, Decode, TypeInfo, Clone, Copy)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Invitation {
+    // Apuesta del jugador que invita
+    pub bet_amount: BetAmout,
+    // Apuesta que el invitado tiene que mandar para aceptar la invitacion
+    pub guest_bet_amount: BetAmout,
+    pub created_at_block: u32,
+    pub expires_at_block: u32,
+    // Programa del token de la apuesta, `None` si la apuesta es en VARA
+    pub token: Option<ActorId>
+}
+
+impl Invitation {
+    pub fn is_expired(&self, current_block: u32) -> bool {
+        current_block >= self.expires_at_block
+    }
+
+    pub fn remaining_blocks(&self, current_block: u32) -> u32 {
+        self.expires_at_block.saturating_sub(current_block)
+    }
+}
+
+// Apuesta que manda un jugador, en VARA si `token` es `None`
+#[derive(Clone, Copy)]
+pub struct Stake {
+    pub amount: BetAmout,
+    pub token: Option<ActorId>
+}
+
+// Lado de la invitacion en el que esta quien llama al comando
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum InvitationParticipant {
+    Inviter,
+    Guest
+}
+
 #[derive(Encode, Decode, TypeInfo, Clone, Default, Eq, PartialEq, Copy)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
@@ -56,24 +142,85 @@ pub enum GameStatus{
     Ended {
         winner: Option<ActorId>
     },
+    // El creador cancelo la partida antes de que alguien se uniera, o un
+    // admin la aborto despues de que empezo
+    Cancelled,
+}
+
+impl GameStatus {
+    pub fn kind(&self) -> GameStatusKind {
+        match self {
+            GameStatus::Waiting => GameStatusKind::Waiting,
+            GameStatus::Started => GameStatusKind::Started,
+            GameStatus::Ended { .. } => GameStatusKind::Ended,
+            GameStatus::Cancelled => GameStatusKind::Cancelled
+        }
+    }
+}
+
+// Estado de una partida sin sus datos, se usa para indexar las partidas
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum GameStatusKind {
+    Waiting,
+    Started,
+    Ended,
+    Cancelled
 }
 
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
 pub enum ResultEnd{
     Win,
     Lose,
     Draw,
 }
 
+// Limites de las apuestas de las partidas que no son gratis, en planck
+#[derive(Encode, Decode, TypeInfo, Clone, Copy)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct StakeLimits {
+    pub min_stake: BetAmout,
+    pub max_stake: BetAmout
+}
+
+impl Default for StakeLimits {
+    fn default() -> Self {
+        Self {
+            min_stake: DEFAULT_MIN_STAKE,
+            max_stake: BetAmout::MAX
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct ChessState {
     pub admins: Vec<ActorId>,
-    pub games_started: Vec<GameId>,
-    pub games_waiting: Vec<GameId>,
-    pub finished_games: Vec<GameId>,
+    // Cuentas del backend que solo pueden reportar resultados
+    pub result_oracles: Vec<ActorId>,
+    // Cada id de partida esta en un solo conjunto, el de su estado actual
+    pub games_by_status: BTreeMap<GameStatusKind, BTreeSet<GameId>>,
+    // Ids de partidas del backend web2 y el id que les asigno el contrato
     pub games_by_web2_id: BTreeMap<u64, GameId>,
     pub games_by_id: BTreeMap<GameId, GameData>,
-    pub invitations: BTreeMap<(UserWeb2Id, UserWeb2Id), BetAmout>,
+    pub invitations: BTreeMap<(UserWeb2Id, UserWeb2Id), Invitation>,
+    // Bloques que vive una invitacion antes de regresar su apuesta
+    pub invitation_ttl_blocks: u32,
     pub user_invitations: BTreeMap<UserWeb2Id, InvitationsData>,
+    // Tablero de cada partida que empezo, el player1 juega con blancas
+    pub game_boards: BTreeMap<GameId, ChessBoard>,
+    pub matchmaking: MatchmakingQueues,
+    pub ratings: Ratings,
+    pub player_games: PlayerGames,
+    pub leaderboards: Leaderboards,
+    pub treasury: Treasury,
+    pub ledger: Ledger,
+    pub stake_limits: StakeLimits,
+    pub spectator_markets: SpectatorMarkets,
+    pub tournaments: Tournaments,
     pub current_game_id: GameId
 }
 
@@ -87,7 +234,210 @@ impl ChessState {
         self.games_by_id.get_mut(&game_id)
     }
 
-    pub fn cancel_invitation(&mut self, first_web2_id: UserWeb2Id, second_web2_id: UserWeb2Id) -> Result<(), Bet2ChessErrors> {
+    pub fn is_admin(&self, address: ActorId) -> bool {
+        self.admins.contains(&address)
+    }
+
+    pub fn add_admin(&mut self, caller: ActorId, new_admin: ActorId) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        if self.is_admin(new_admin) {
+            return Err(Bet2ChessErrors::AdminAlreadyExists(new_admin));
+        }
+
+        self.admins.push(new_admin);
+
+        Ok(())
+    }
+
+    pub fn remove_admin(&mut self, caller: ActorId, admin: ActorId) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        if !self.is_admin(admin) {
+            return Err(Bet2ChessErrors::AddressIsNotAnAdmin(admin));
+        }
+
+        // El contrato no se puede quedar sin alguien que lo administre
+        if self.admins.len() == 1 {
+            return Err(Bet2ChessErrors::CantRemoveTheLastAdmin);
+        }
+
+        self.admins.retain(|address| *address != admin);
+
+        Ok(())
+    }
+
+    pub fn add_result_oracle(&mut self, caller: ActorId, oracle: ActorId) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        if self.result_oracles.contains(&oracle) {
+            return Err(Bet2ChessErrors::ResultOracleAlreadyExists(oracle));
+        }
+
+        self.result_oracles.push(oracle);
+
+        Ok(())
+    }
+
+    pub fn remove_result_oracle(&mut self, caller: ActorId, oracle: ActorId) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        if !self.result_oracles.contains(&oracle) {
+            return Err(Bet2ChessErrors::AddressIsNotAResultOracle(oracle));
+        }
+
+        self.result_oracles.retain(|address| *address != oracle);
+
+        Ok(())
+    }
+
+    pub fn all_games_id_by_status(&self, status: GameStatusKind) -> Vec<GameId> {
+        self.games_by_status
+            .get(&status)
+            .map(|games_id| games_id.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn games_id_by_status(&self, status: GameStatusKind, offset: u32, limit: u32) -> Vec<GameId> {
+        self.games_by_status
+            .get(&status)
+            .map(|games_id| Self::games_id_page(games_id.iter().copied(), offset, limit))
+            .unwrap_or_default()
+    }
+
+    pub fn set_stake_limits(&mut self, caller: ActorId, stake_limits: StakeLimits) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        if stake_limits.min_stake == 0 || stake_limits.min_stake > stake_limits.max_stake {
+            return Err(Bet2ChessErrors::InvalidStakeLimits);
+        }
+
+        self.stake_limits = stake_limits;
+
+        Ok(())
+    }
+
+    // Una apuesta de cero es una partida gratis, cualquier otra tiene que estar en los limites
+    pub fn check_stake(&self, stake: BetAmout) -> Result<(), Bet2ChessErrors> {
+        if stake == 0 {
+            return Ok(());
+        }
+
+        if stake < self.stake_limits.min_stake {
+            return Err(Bet2ChessErrors::StakeBelowMinimum(self.stake_limits.min_stake));
+        }
+
+        if stake > self.stake_limits.max_stake {
+            return Err(Bet2ChessErrors::StakeAboveMaximum(self.stake_limits.max_stake));
+        }
+
+        Ok(())
+    }
+
+    pub fn set_invitation_ttl(&mut self, caller: ActorId, ttl_blocks: u32) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        if ttl_blocks == 0 {
+            return Err(Bet2ChessErrors::InvalidInvitationTtl);
+        }
+
+        self.invitation_ttl_blocks = ttl_blocks;
+
+        Ok(())
+    }
+
+    // El que invita puede cancelar la invitacion y el invitado puede rechazarla,
+    // en ambos casos la apuesta regresa al que invito. `caller_web2_id` tiene
+    // que ser el id web2 ligado a la address que llama al contrato en el
+    // registro de usuarios (`web2_id_by_address`), que solo cambia con una
+    // atestacion del backend. No se usa ninguna address guardada por el
+    // contrato al mandar o aceptar invitaciones, esas se pueden suplantar.
+    pub fn cancel_invitation(
+        &mut self,
+        caller_web2_id: UserWeb2Id,
+        first_web2_id: UserWeb2Id,
+        second_web2_id: UserWeb2Id
+    ) -> Result<InvitationParticipant, Bet2ChessErrors> {
+        let participant = Self::invitation_participant(caller_web2_id, first_web2_id, second_web2_id)?;
+
+        let (first_user_address, invitation) = self.remove_invitation(first_web2_id, second_web2_id)?;
+
+        self.credit_stake(first_user_address, invitation.token, invitation.bet_amount);
+
+        Ok(participant)
+    }
+
+    pub fn decline_invitation(
+        &mut self,
+        caller_web2_id: UserWeb2Id,
+        first_web2_id: UserWeb2Id,
+        second_web2_id: UserWeb2Id
+    ) -> Result<(), Bet2ChessErrors> {
+        let participant = Self::invitation_participant(caller_web2_id, first_web2_id, second_web2_id)?;
+
+        if participant != InvitationParticipant::Guest {
+            return Err(Bet2ChessErrors::OnlyTheGuestCanDeclineTheInvitation);
+        }
+
+        self.cancel_invitation(caller_web2_id, first_web2_id, second_web2_id)?;
+
+        Ok(())
+    }
+
+    fn invitation_participant(
+        caller_web2_id: UserWeb2Id,
+        first_web2_id: UserWeb2Id,
+        second_web2_id: UserWeb2Id
+    ) -> Result<InvitationParticipant, Bet2ChessErrors> {
+        if caller_web2_id == first_web2_id {
+            return Ok(InvitationParticipant::Inviter);
+        }
+
+        if caller_web2_id == second_web2_id {
+            return Ok(InvitationParticipant::Guest);
+        }
+
+        Err(Bet2ChessErrors::OnlyInvitationParticipantsCanCancelIt)
+    }
+
+    // Cualquiera puede expirar una invitacion cuando termina su tiempo de vida,
+    // el contrato tambien se programa un mensaje para hacerlo cuando se crea.
+    pub fn expire_invitation(&mut self, first_web2_id: UserWeb2Id, second_web2_id: UserWeb2Id) -> Result<(), Bet2ChessErrors> {
+        let invitation = self.invitations
+            .get(&(first_web2_id, second_web2_id))
+            .ok_or(Bet2ChessErrors::InvitationDoesNotExists)?;
+
+        if !invitation.is_expired(exec::block_height()) {
+            return Err(Bet2ChessErrors::InvitationHasNotExpired);
+        }
+
+        let (first_user_address, invitation) = self.remove_invitation(first_web2_id, second_web2_id)?;
+
+        self.credit_stake(first_user_address, invitation.token, invitation.bet_amount);
+
+        Ok(())
+    }
+
+    // Expira hasta `limit` invitaciones, regresa cuantas se expiraron
+    pub fn sweep_expired_invitations(&mut self, limit: u32) -> u32 {
+        let current_block = exec::block_height();
+
+        let expired: Vec<(UserWeb2Id, UserWeb2Id)> = self.invitations
+            .iter()
+            .filter(|(_, invitation)| invitation.is_expired(current_block))
+            .map(|(key, _)| *key)
+            .take(limit as usize)
+            .collect();
+
+        expired
+            .into_iter()
+            .filter(|(first_web2_id, second_web2_id)| {
+                self.expire_invitation(*first_web2_id, *second_web2_id).is_ok()
+            })
+            .count() as u32
+    }
+
+    fn remove_invitation(&mut self, first_web2_id: UserWeb2Id, second_web2_id: UserWeb2Id) -> Result<(ActorId, Invitation), Bet2ChessErrors> {
         self.user_invitations
             .get_mut(&first_web2_id)
             .ok_or(Bet2ChessErrors::UserHasNoInvitationfromTheUser(second_web2_id))?
@@ -104,19 +454,33 @@ impl ChessState {
             .remove(&first_web2_id)
             .ok_or(Bet2ChessErrors::UserHasNoInvitationfromTheUser(first_web2_id))?;
 
-        let bet_amount = self.invitations
+        let invitation = self.invitations
             .remove(&(first_web2_id, second_web2_id))
             .ok_or(Bet2ChessErrors::InvitationDoesNotExists)?;
-    
-        if bet_amount != 0 {
-            msg::send(first_user_address, b"Refund", bet_amount * ONE_VARA)
-                .expect("Error while sending a messge");
-        }
 
-        Ok(())
+        Ok((first_user_address, invitation))
     }
 
-    pub fn create_invitation_with_bet(&mut self, user_address: ActorId, user_id: UserWeb2Id, guest: UserWeb2Id, bet_amount: u128) -> Result<(), Bet2ChessErrors> {
+    pub fn create_invitation_with_bet(
+        &mut self,
+        user_address: ActorId,
+        user_id: UserWeb2Id,
+        guest: UserWeb2Id,
+        bet_amount: u128,
+        guest_bet_amount: u128,
+        token: Option<ActorId>
+    ) -> Result<(), Bet2ChessErrors> {
+        // Un usuario no puede invitarse a si mismo, todavia no hay partida
+        // asi que el error no lleva id
+        if guest == user_id {
+            return Err(self.refund_bet(
+                user_address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::UserCantJoinTheirOwnGame(0)
+            ));
+        }
+
         // Se obtiene las invitaciones del usuario, si no existe, se 
         // inserta como nuevo usuario y se retorna sus invitaciones como mutables.
         let invitations = self.user_invitations
@@ -133,15 +497,12 @@ impl ChessState {
 
         // Si es el caso, se retorna error y se retornan los tokens
         if already_sent_invitation {
-            if bet_amount != 0 {
-                msg::send(
-                    user_address, 
-                    Bet2ChessErrors::UserAlreadyInviteThePlayer(guest), 
-                    bet_amount * ONE_VARA
-                ).expect("Error while sending a message");
-            }
-
-            return Err(Bet2ChessErrors::UserAlreadyInviteThePlayer(guest));
+            return Err(self.refund_bet(
+                user_address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::UserAlreadyInviteThePlayer(guest)
+            ));
         }
 
         // Se agrega la nueva invitacion que se mando
@@ -175,24 +536,35 @@ impl ChessState {
                         .remove(&guest);
                 });
 
-            if bet_amount != 0 {
-                msg::send(
-                    user_address, 
-                    Bet2ChessErrors::UserAlreadyInviteThePlayer(guest), 
-                    bet_amount * ONE_VARA
-                ).expect("Error while sending a message");
-            }
-            
-            return Err(Bet2ChessErrors::UserAlreadyInviteThePlayer(guest));
+            return Err(self.refund_bet(
+                user_address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::UserAlreadyInviteThePlayer(guest)
+            ));
         }
 
         // Se agrega la nueva invitacion que el usuario recibio
         guest_invitations.invitations_received
             .insert(user_id, user_address);
 
+
         // Se crea la invitacion "global", para saber el monton de la
-        // apuesta. 
-        self.invitations.insert((user_id, guest), bet_amount);
+        // apuesta y cuando expira.
+        let current_block = exec::block_height();
+        let ttl_blocks = self.invitation_ttl_blocks;
+
+        self.invitations.insert((user_id, guest), Invitation {
+            bet_amount,
+            guest_bet_amount,
+            created_at_block: current_block,
+            expires_at_block: current_block.saturating_add(ttl_blocks),
+            token
+        });
+
+        // Si el mensaje no se puede programar, la invitacion se puede
+        // expirar con `ExpireInvitation` o `SweepExpiredInvitations`
+        let _ = Self::send_delayed_call("ExpireInvitation", (user_id, guest), ttl_blocks);
 
         Ok(())
     }
@@ -200,82 +572,240 @@ impl ChessState {
     pub fn accept_invitation(
         &mut self, 
         user_address: ActorId,
-        invited_user: UserWeb2Id, 
-        user_who_invite: UserWeb2Id, 
+        invitation_key: (UserWeb2Id, UserWeb2Id),
         web2_game_id: u64, 
         username_from_user_who_invite: String,
         own_username: String,
-        bet_amount: BetAmout
-    ) -> Result<(), Bet2ChessErrors> {
+        stake: Stake
+    ) -> Result<GameId, Bet2ChessErrors> {
+        // La llave de la invitacion es (id web2 del que invita, id web2 del invitado)
+        let (user_who_invite, invited_user) = invitation_key;
+        let Stake { amount: bet_amount, token } = stake;
+
         // Si no encuentra la invitacion, manda error ya que no existe una invitacion como tal,
         // si existe, se retorna la apueta propuesta por el jugador.
-        let bet = self.invitations
-            .get_mut(&(user_who_invite, invited_user))
-            .ok_or(Bet2ChessErrors::UserHasNoInvitationfromTheUser(user_who_invite))?;
+        let Some(invitation) = self.invitations.get(&(user_who_invite, invited_user)).copied() else {
+            return Err(self.refund_bet(
+                user_address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::UserHasNoInvitationfromTheUser(user_who_invite)
+            ));
+        };
+
+        // Si la invitacion ya expiro, se regresa la apuesta a ambos jugadores.
+        // La del invitado se regresa primero, asi no se pierde aunque no se
+        // pueda expirar la invitacion
+        if invitation.is_expired(exec::block_height()) {
+            let error = self.refund_bet(user_address, bet_amount, token, Bet2ChessErrors::InvitationExpired);
+
+            self.expire_invitation(user_who_invite, invited_user)?;
+
+            return Err(error);
+        }
         
-        // Se verifica que el jugador que acepto la partida haya mandado la cantidad
-        // propuesta
-        if *bet != bet_amount {
-            return Err(Bet2ChessErrors::BetIsNotTheSameForMatch { 
-                game_bet: *bet, 
-                bet_by_user: bet_amount 
-            });
+        // La apuesta debe ser en la misma moneda que la invitacion
+        if invitation.token != token {
+            return Err(self.refund_bet(
+                user_address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::StakeCurrencyMismatch
+            ));
         }
 
-        // Se elimina la invitaion del contrato
-        self.invitations
-            .remove(&(user_who_invite, invited_user));
+        // Se verifica que el jugador que acepto la partida haya mandado exactamente
+        // la cantidad que le pide la invitacion, si mando de mas o de menos se le
+        // regresa todo
+        if invitation.guest_bet_amount != bet_amount {
+            return Err(self.refund_bet(
+                user_address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::BetIsNotTheSameForMatch { 
+                    game_bet: invitation.guest_bet_amount, 
+                    bet_by_user: bet_amount 
+                }
+            ));
+        }
 
-        // Se elimina la invitacion por parte del usuario que mando 
-        // la invitacion.
-        self.user_invitations
-            .entry(user_who_invite)
-            .and_modify(|invitations| {
-                invitations.invitations_sent.remove(&invited_user);
-            });
-        
+        // El id web2 de la partida solo puede usarse una vez, si ya existe
+        // se rechaza antes de modificar las invitaciones
+        if self.games_by_web2_id.contains_key(&web2_game_id) {
+            return Err(self.refund_bet(
+                user_address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::Web2GameIdAlreadyExists(web2_game_id)
+            ));
+        }
 
-        // Se elimina la invitacion y se obtiene la address del usuario
-        // que invito al jugador.
-        let temp = self.user_invitations
-            .get_mut(&invited_user)
-            .unwrap()
-            .invitations_received
-            .remove(&user_who_invite);
-
-        // Si este no existia, se retorna error, y en caso de
-        // apuesta, se retorna
-        let Some(first_user_address) = temp else {
-            if bet_amount != 0 {
-                msg::send(
-                    user_address, 
-                    Bet2ChessErrors::UserHasNoInvitationfromTheUser(user_who_invite), 
-                    bet_amount * ONE_VARA
-                ).expect("Error while sending a message");
-            }
+        // Se obtiene la address del usuario que invito al jugador, si no
+        // existe se retorna error y se regresa la apuesta
+        let first_user_address = self.user_invitations
+            .get(&invited_user)
+            .and_then(|invitations| invitations.invitations_received.get(&user_who_invite))
+            .copied();
 
-            return Err(Bet2ChessErrors::UserHasNoInvitationfromTheUser(user_who_invite));
+        let Some(first_user_address) = first_user_address else {
+            return Err(self.refund_bet(
+                user_address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::UserHasNoInvitationfromTheUser(user_who_invite)
+            ));
         };
 
-        // Se crea la partida y se une a ambos jugadores a esta.
-        let _ = Self::create_match(
+        // Se crea la partida y se une a ambos jugadores a esta antes de tocar
+        // la invitacion, si algo falla la invitacion sigue igual y solo se
+        // regresa la apuesta del invitado
+        let game_id = match Self::create_match(
             self, 
             first_user_address, 
             username_from_user_who_invite,
             user_who_invite,
-            bet_amount, 
-            web2_game_id
-        )?;
-        let _ = Self::join_match(
+            invitation.bet_amount,
+            token
+        ) {
+            Ok(game_id) => game_id,
+            Err(error) => return Err(self.refund_bet(user_address, bet_amount, token, error))
+        };
+
+        // El jugador invitado puede apostar una cantidad distinta
+        if let Some(game_data) = self.game_mut_by_id(game_id) {
+            game_data.player2_bet = invitation.guest_bet_amount;
+        }
+
+        let result = Self::join_match(
             self, 
             user_address, 
             own_username,
             invited_user,
             bet_amount, 
-            web2_game_id
-        )?;
+            token,
+            game_id
+        );
+
+        // Una partida a la que no se pudo unir el invitado se cancela
+        if let Err(error) = result {
+            self.set_game_status(game_id, GameStatus::Cancelled);
+            return Err(self.refund_bet(user_address, bet_amount, token, error));
+        }
+
+        // Se elimina la invitaion del contrato
+        self.invitations
+            .remove(&(user_who_invite, invited_user));
+
+        // Se elimina la invitacion por parte del usuario que mando 
+        // la invitacion y por parte del invitado.
+        self.user_invitations
+            .entry(user_who_invite)
+            .and_modify(|invitations| {
+                invitations.invitations_sent.remove(&invited_user);
+            });
+
+        self.user_invitations
+            .entry(invited_user)
+            .and_modify(|invitations| {
+                invitations.invitations_received.remove(&user_who_invite);
+            });
+
+        self.games_by_web2_id.insert(web2_game_id, game_id);
 
-        Ok(())  
+        Ok(game_id)  
+    }
+
+    pub fn create_open_game(
+        &mut self,
+        address: ActorId,
+        username: String,
+        user_web2_id: UserWeb2Id,
+        bet_amount: BetAmout,
+        token: Option<ActorId>
+    ) -> Result<GameId, Bet2ChessErrors> {
+        self.create_match(address, username, user_web2_id, bet_amount, token)
+            .map_err(|error| self.refund_bet(address, bet_amount, token, error))
+    }
+
+    pub fn join_open_game(
+        &mut self,
+        address: ActorId,
+        username: String,
+        user_web2_id: UserWeb2Id,
+        bet_amount: BetAmout,
+        token: Option<ActorId>,
+        game_id: GameId
+    ) -> Result<GameId, Bet2ChessErrors> {
+        let Some(game_data) = self.games_by_id.get(&game_id) else {
+            return Err(self.refund_bet(address, bet_amount, token, Bet2ChessErrors::GameIdDoesNotExists(game_id)));
+        };
+
+        if game_data.player1 == address || game_data.player1_web2_id == user_web2_id {
+            return Err(self.refund_bet(
+                address,
+                bet_amount,
+                token,
+                Bet2ChessErrors::UserCantJoinTheirOwnGame(game_id)
+            ));
+        }
+
+        self.join_match(address, username, user_web2_id, bet_amount, token, game_id)
+            .map_err(|error| self.refund_bet(address, bet_amount, token, error))?;
+
+        Ok(game_id)
+    }
+
+    // Se une a la partida en espera mas antigua con la misma apuesta que no creo el usuario
+    pub fn join_any_waiting_game(
+        &mut self,
+        address: ActorId,
+        username: String,
+        user_web2_id: UserWeb2Id,
+        bet_amount: BetAmout,
+        token: Option<ActorId>
+    ) -> Result<GameId, Bet2ChessErrors> {
+        let game_id = self.games_by_status
+            .get(&GameStatusKind::Waiting)
+            .and_then(|games_id| {
+                games_id
+                    .iter()
+                    .find(|game_id| {
+                        self.games_by_id
+                            .get(game_id)
+                            .is_some_and(|game_data| {
+                                game_data.player2_bet == bet_amount
+                                    && game_data.token == token
+                                    && game_data.player1 != address
+                                    && game_data.player1_web2_id != user_web2_id
+                            })
+                    })
+                    .copied()
+            })
+            .ok_or_else(|| self.refund_bet(address, bet_amount, token, Bet2ChessErrors::ThereAreNoGamesWaiting))?;
+
+        self.join_open_game(address, username, user_web2_id, bet_amount, token, game_id)
+    }
+
+    pub fn cancel_open_game(&mut self, caller: ActorId, game_id: GameId) -> Result<GameId, Bet2ChessErrors> {
+        let game_data = self.games_by_id
+            .get(&game_id)
+            .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
+
+        if game_data.status != GameStatus::Waiting {
+            return Err(Bet2ChessErrors::GameAlreadyStart(game_id));
+        }
+
+        if game_data.player1 != caller {
+            return Err(Bet2ChessErrors::OnlyTheGameCreatorCanCancelIt(game_id));
+        }
+
+        let (bet_amount, token) = (game_data.game_bet, game_data.token);
+
+        self.set_game_status(game_id, GameStatus::Cancelled);
+
+        self.credit_stake(caller, token, bet_amount);
+
+        Ok(game_id)
     }
 
     pub fn join_match(
@@ -284,18 +814,26 @@ impl ChessState {
         username: String,
         user_web2_id: u64,
         bet_amount: BetAmout, 
+        token: Option<ActorId>,
         game_id: u64
     ) -> Result<(), Bet2ChessErrors> {
         let game_data = self.game_mut_by_id(game_id)
             .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
 
-        if game_data.status == GameStatus::Started {
-            return Err(Bet2ChessErrors::GameAlreadyStart(game_id));
+        match game_data.status {
+            GameStatus::Started => return Err(Bet2ChessErrors::GameAlreadyStart(game_id)),
+            GameStatus::Ended { .. } => return Err(Bet2ChessErrors::GameAlreadyEnded(game_id)),
+            GameStatus::Cancelled => return Err(Bet2ChessErrors::GameWasCancelled(game_id)),
+            GameStatus::Waiting => {}
         }
 
-        if game_data.game_bet != bet_amount {
+        if game_data.token != token {
+            return Err(Bet2ChessErrors::StakeCurrencyMismatch);
+        }
+
+        if game_data.player2_bet != bet_amount {
             return Err(Bet2ChessErrors::BetIsNotTheSameForMatch{
-                game_bet: game_data.game_bet,
+                game_bet: game_data.player2_bet,
                 bet_by_user: bet_amount
             });
         }
@@ -303,9 +841,11 @@ impl ChessState {
         game_data.player2 = address;
         game_data.player2_username = username;
         game_data.player2_web2_id = user_web2_id;
-        game_data.status = GameStatus::Started;
 
-        self.games_started.push(game_id);
+        self.set_game_status(game_id, GameStatus::Started);
+        self.game_boards.insert(game_id, ChessBoard::default());
+        self.index_player_game(address, user_web2_id, game_id);
+        self.open_spectator_market(game_id);
 
         Ok(())
     }
@@ -316,16 +856,21 @@ impl ChessState {
         username: String,
         user_web2_id: u64,
         bet_amount: BetAmout,
-        game_id: u64
+        token: Option<ActorId>
     ) -> Result<GameId, Bet2ChessErrors> {
+        // El contrato asigna los ids, asi una partida nunca se puede sobreescribir
+        let game_id = self.next_game_id()?;
+
         let mut game_data = Self::new_game_with_bet(bet_amount);
         game_data.player1 = address;
         game_data.player1_username = username;
         game_data.player1_web2_id = user_web2_id;
+        game_data.created_at_block = exec::block_height();
+        game_data.token = token;
 
         self.games_by_id.insert(game_id, game_data);
-        self.games_waiting.push(game_id);
-        // self.current_game_id = new_current_game_id;
+        self.set_game_status(game_id, GameStatus::Waiting);
+        self.index_player_game(address, user_web2_id, game_id);
 
         Ok(game_id)
     }
@@ -333,53 +878,330 @@ impl ChessState {
     pub fn end_match(
         &mut self,
         game_id: GameId,
-        // caller: ActorId,
+        caller: ActorId,
         game_winner: Option<ActorId>
     ) -> Result<GameId, Bet2ChessErrors> {
-        // Esta comentado por como esta el flujo en el frontend
-        // self.admins
-        //     .iter()
-        //     .find(|&&admin| admin == caller)
-        //     .ok_or(Bet2ChessErrors::OnlyAdminsCanEndGames)?;
+        // Solo los admins y los oraculos de resultados pueden reportar un resultado,
+        // los jugadores terminan sus partidas jugandolas o rindiendose.
+        if !self.is_admin(caller) && !self.result_oracles.contains(&caller) {
+            return Err(Bet2ChessErrors::OnlyAdminsCanEndGames);
+        }
+
+        self.settle_match(game_id, game_winner)
+    }
 
+    pub fn resign(&mut self, player: ActorId, game_id: GameId) -> Result<GameId, Bet2ChessErrors> {
         let game_data = self.games_by_id
-            .get_mut(&game_id)
+            .get(&game_id)
             .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
 
-        let Some(winner) = game_winner else {
-            game_data.status = GameStatus::Ended { 
-                winner: None 
-            };
-
-            return Ok(game_id);
-        };
+        if game_data.status != GameStatus::Started {
+            return Err(Bet2ChessErrors::GameIsNotStarted(game_id));
+        }
 
-        if game_data.player1 == winner {
-            game_data.status = GameStatus::Ended { 
-                winner:Some(game_data.player1) 
-            };    
+        let winner = if player == game_data.player1 {
+            game_data.player2
+        } else if player == game_data.player2 {
+            game_data.player1
         } else {
-            game_data.status = GameStatus::Ended { 
-                winner:Some(game_data.player2) 
-            };
-        }       
-
-        msg::send(winner, Bet2ChessEvents::Price, game_data.game_bet * ONE_VARA * 2)
-            .expect("Error while sending message");
+            return Err(Bet2ChessErrors::UserIsNotAPlayerOfTheGame(game_id));
+        };
 
-        Ok(game_id)
+        self.settle_match(game_id, Some(winner))
     }
 
-    fn new_game_with_bet(bet_amount: BetAmout) -> GameData {
-        let mut game_data = GameData::default();
+    // Termina una partida empezada sin resultado, a ambos jugadores y a los
+    // espectadores se les regresan sus apuestas y no cambian ratings ni estadisticas
+    pub fn abort_game(&mut self, caller: ActorId, game_id: GameId) -> Result<GameId, Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        let game_data = self.games_by_id
+            .get(&game_id)
+            .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
+
+        if game_data.status != GameStatus::Started {
+            return Err(Bet2ChessErrors::GameIsNotStarted(game_id));
+        }
+
+        // Un torneo no puede avanzar sin el resultado de sus partidas
+        if self.is_tournament_game(game_id) {
+            return Err(Bet2ChessErrors::TournamentGamesCantBeAborted(game_id));
+        }
+
+        let refunds = [
+            (game_data.player1, game_data.game_bet),
+            (game_data.player2, game_data.player2_bet)
+        ];
+        let token = game_data.token;
+
+        self.set_game_status(game_id, GameStatus::Cancelled);
+        self.refund_spectator_market(game_id);
+
+        for (player, bet_amount) in refunds {
+            self.credit_stake(player, token, bet_amount);
+        }
+
+        Ok(game_id)
+    }
+
+    fn settle_match(
+        &mut self,
+        game_id: GameId,
+        game_winner: Option<ActorId>
+    ) -> Result<GameId, Bet2ChessErrors> {
+        let game_data = self.games_by_id
+            .get(&game_id)
+            .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
+
+        match game_data.status {
+            GameStatus::Waiting => return Err(Bet2ChessErrors::GameIsNotStarted(game_id)),
+            GameStatus::Ended { .. } => return Err(Bet2ChessErrors::GameAlreadyEnded(game_id)),
+            GameStatus::Cancelled => return Err(Bet2ChessErrors::GameWasCancelled(game_id)),
+            GameStatus::Started => {}
+        }
+
+        let Some(winner) = game_winner else {
+            return self.settle_draw(game_id);
+        };
+
+        // El premio se manda a la address guardada en la partida, nunca
+        // a una address que no sea de uno de los jugadores.
+        let winner = if winner == game_data.player1 {
+            game_data.player1
+        } else if winner == game_data.player2 {
+            game_data.player2
+        } else {
+            return Err(Bet2ChessErrors::InvalidWinner(winner));
+        };
+
+        let pot = game_data.pot();
+        let token = game_data.token;
+
+        self.set_game_status(game_id, GameStatus::Ended { 
+            winner: Some(winner) 
+        });
+        self.record_game_result(game_id);
+
+        let prize = self.collect_fee(pot, token);
+
+        self.credit_stake(winner, token, prize);
+
+        Ok(game_id)
+    }
+
+    pub fn make_move(
+        &mut self,
+        player: ActorId,
+        game_id: GameId,
+        chess_move: ChessMove
+    ) -> Result<BoardStatus, Bet2ChessErrors> {
+        let game_data = self.games_by_id
+            .get(&game_id)
+            .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
+
+        if game_data.status != GameStatus::Started {
+            return Err(Bet2ChessErrors::GameIsNotStarted(game_id));
+        }
+
+        if player != game_data.player1 && player != game_data.player2 {
+            return Err(Bet2ChessErrors::UserIsNotAPlayerOfTheGame(game_id));
+        }
+
+        let board = self.game_boards
+            .get_mut(&game_id)
+            .ok_or(Bet2ChessErrors::GameIsNotStarted(game_id))?;
+
+        // Las blancas son del jugador 1 y las negras del jugador 2
+        let player_in_turn = match board.turn {
+            PieceColor::White => game_data.player1,
+            PieceColor::Black => game_data.player2
+        };
+
+        if player != player_in_turn {
+            return Err(Bet2ChessErrors::NotPlayerTurn(game_id));
+        }
+
+        let (player1, player2) = (game_data.player1, game_data.player2);
+
+        let board_status = board.play(chess_move)
+            .map_err(Bet2ChessErrors::IllegalMove)?;
+
+        // La posicion del tablero decide el resultado, asi que la partida se
+        // liquida aqui sin esperar a que alguien lo reporte.
+        match board_status {
+            BoardStatus::Checkmate { winner } => {
+                let winner_address = match winner {
+                    PieceColor::White => player1,
+                    PieceColor::Black => player2
+                };
+
+                self.settle_match(game_id, Some(winner_address))?;
+            },
+            status if status.is_game_over() => {
+                self.settle_draw(game_id)?;
+            },
+            _ => {}
+        }
+
+        Ok(board_status)
+    }
+
+    fn settle_draw(&mut self, game_id: GameId) -> Result<GameId, Bet2ChessErrors> {
+        let game_data = self.games_by_id
+            .get(&game_id)
+            .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
+
+        let players = [game_data.player1, game_data.player2];
+        let stakes = (game_data.game_bet, game_data.player2_bet);
+        let pot = game_data.pot();
+        let token = game_data.token;
+
+        self.set_game_status(game_id, GameStatus::Ended {
+            winner: None
+        });
+        self.record_game_result(game_id);
+
+        // La comision la pagan ambos jugadores en proporcion a sus apuestas,
+        // los planck que sobran del redondeo van a la tesoreria
+        let left = self.collect_fee(pot, token);
+        let refunds = Self::draw_refunds(left, stakes);
+        self.add_to_treasury(token, left - refunds.0 - refunds.1);
+
+        for (player, refund) in players.into_iter().zip([refunds.0, refunds.1]) {
+            self.credit_stake(player, token, refund);
+        }
+
+        Ok(game_id)
+    }
+
+    // Todo lo que depende del resultado de una partida se actualiza aqui
+    fn record_game_result(&mut self, game_id: GameId) {
+        let Some(game_data) = self.games_by_id.get(&game_id) else {
+            return;
+        };
+
+        let players = [game_data.player1_web2_id, game_data.player2_web2_id];
+
+        for web2_id in players {
+            self.remove_from_leaderboards(web2_id);
+        }
+
+        self.update_ratings(game_id);
+        self.update_player_stats(game_id);
+        self.settle_spectator_market(game_id);
+        self.record_tournament_result(game_id);
+
+        for web2_id in players {
+            self.add_to_leaderboards(web2_id);
+        }
+    }
+
+    // Todos los cambios de estado pasan por aqui para mantener `games_by_status` sincronizado
+    pub(crate) fn set_game_status(&mut self, game_id: GameId, status: GameStatus) {
+        let Some(game_data) = self.games_by_id.get_mut(&game_id) else {
+            return;
+        };
+
+        let previous_status = game_data.status.kind();
+
+        game_data.status = status;
+
+        if let GameStatus::Ended { winner } = status {
+            game_data.winner = winner;
+        }
+
+        // El tablero solo se necesita mientras se juega, de una partida
+        // terminada o cancelada solo se queda la llave de su ultima posicion
+        if matches!(status, GameStatus::Ended { .. } | GameStatus::Cancelled) {
+            if let Some(board) = self.game_boards.remove(&game_id) {
+                game_data.final_position_key = board.position_keys.last().copied();
+            }
+        }
+
+        if let Some(games_id) = self.games_by_status.get_mut(&previous_status) {
+            games_id.remove(&game_id);
+        }
+
+        self.games_by_status
+            .entry(status.kind())
+            .or_default()
+            .insert(game_id);
+    }
+
+    pub(crate) fn check_admin(&self, caller: ActorId) -> Result<(), Bet2ChessErrors> {
+        if !self.is_admin(caller) {
+            return Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction);
+        }
+
+        Ok(())
+    }
+
+    // Regresa la apuesta al usuario cuando un comando falla despues de mandar el valor
+    pub(crate) fn refund_bet(
+        &mut self,
+        address: ActorId,
+        bet_amount: BetAmout,
+        token: Option<ActorId>,
+        error: Bet2ChessErrors
+    ) -> Bet2ChessErrors {
+        self.credit_stake(address, token, bet_amount);
+
+        error
+    }
+
+    // Manda un mensaje a un comando de este servicio despues de `delay` bloques,
+    // codificado como la ruta de sails seguida de los argumentos
+    pub(crate) fn send_delayed_call(method: &str, arguments: impl Encode, delay: u32) -> Result<MessageId, Bet2ChessErrors> {
+        Self::send_delayed_service_call("Bet2Chess", method, arguments, delay)
+    }
+
+    pub(crate) fn send_delayed_service_call(
+        service: &str,
+        method: &str,
+        arguments: impl Encode,
+        delay: u32
+    ) -> Result<MessageId, Bet2ChessErrors> {
+        let payload = (service, method, arguments).encode();
+
+        msg::send_bytes_with_gas_delayed(exec::program_id(), payload, DELAYED_MESSAGE_GAS, 0, delay)
+            .map_err(|_| Bet2ChessErrors::CantScheduleDelayedMessage)
+    }
+
+    fn next_game_id(&mut self) -> Result<GameId, Bet2ChessErrors> {
+        self.current_game_id = self.current_game_id
+            .checked_add(1)
+            .ok_or(Bet2ChessErrors::CantIncrementGamesIdItOverflow)?;
+
+        Ok(self.current_game_id)
+    }
+
+    // Reparte lo que queda del bote de una partida empatada en proporcion a
+    // las apuestas de los jugadores
+    pub(crate) fn draw_refunds(left: u128, stakes: (BetAmout, BetAmout)) -> (u128, u128) {
+        let pot = stakes.0 + stakes.1;
+
+        if pot == 0 {
+            return (0, 0);
+        }
+
+        let share = |stake: BetAmout| {
+            (U256::from(left) * U256::from(stake) / U256::from(pot)).as_u128()
+        };
+
+        (share(stakes.0), share(stakes.1))
+    }
+
+    fn new_game_with_bet(bet_amount: BetAmout) -> GameData {
+        let mut game_data = GameData::default();
         game_data.game_bet = bet_amount;
+        game_data.player2_bet = bet_amount;
 
         game_data
     }
 }
 
 
-#[derive(Encode, Decode, TypeInfo, Clone)]
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, PartialEq)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub enum Bet2ChessErrors {
@@ -397,5 +1219,892 @@ pub enum Bet2ChessErrors {
     InvitationDoesNotExists,
     ThereAreNoGamesWaiting,
     MinAmoutToBetIsOneToken,
-    OnlyAdminsCanEndGames
-}
\ No newline at end of file
+    OnlyAdminsCanEndGames,
+    GameIsNotStarted(GameId),
+    UserIsNotAPlayerOfTheGame(GameId),
+    NotPlayerTurn(GameId),
+    IllegalMove(ChessMove),
+    OnlyAdminsCanDoThisAction,
+    AdminAlreadyExists(ActorId),
+    AddressIsNotAnAdmin(ActorId),
+    CantRemoveTheLastAdmin,
+    ResultOracleAlreadyExists(ActorId),
+    AddressIsNotAResultOracle(ActorId),
+    InvalidWinner(ActorId),
+    GameAlreadyEnded(GameId),
+    Web2GameIdAlreadyExists(u64),
+    GameWasCancelled(GameId),
+    UserCantJoinTheirOwnGame(GameId),
+    OnlyTheGameCreatorCanCancelIt(GameId),
+    PlayerAlreadyInMatchmakingQueue,
+    PlayerIsNotInMatchmakingQueue,
+    InvalidInvitationTtl,
+    InvitationHasNotExpired,
+    InvitationExpired,
+    CantScheduleDelayedMessage,
+    OnlyInvitationParticipantsCanCancelIt,
+    OnlyTheGuestCanDeclineTheInvitation,
+    PlayerHasNoProfile(UserWeb2Id),
+    InvalidFeeConfig,
+    InsufficientTreasuryBalance(u128),
+    InsufficientBalance(u128),
+    CantSendWithdrawal,
+    StakeBelowMinimum(BetAmout),
+    StakeAboveMaximum(BetAmout),
+    InvalidStakeLimits,
+    StakeCurrencyMismatch,
+    TokenStakeCantBeZero,
+    TokenTransferFailed,
+    TokenStakesDontTakeValue,
+    MarketDoesNotExists(GameId),
+    MarketIsClosed(GameId),
+    MarketIsNotSettled(GameId),
+    PlayersCantBetOnTheirGame(GameId),
+    SpectatorBetCantBeZero,
+    NothingToClaim,
+    InvalidMarketConfig,
+    InvalidTournamentConfig,
+    TournamentDoesNotExists(TournamentId),
+    TournamentRegistrationIsClosed(TournamentId),
+    TournamentIsFull(TournamentId),
+    PlayerAlreadyInTournament(TournamentId),
+    PlayerIsNotInTournament(TournamentId),
+    EntryFeeIsNotTheSame {
+        entry_fee: u128,
+        sent: u128
+    },
+    NotEnoughPlayersInTournament(TournamentId),
+    CantIncrementTournamentsIdItOverflow,
+    TournamentGamesCantBeAborted(GameId),
+    TournamentIsNotAnArena(TournamentId),
+    ArenaHasNotEnded(TournamentId),
+    TournamentIsNotRunning(TournamentId),
+    NotATournamentGame(GameId),
+    InvalidRatingRange
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        runtime::mock,
+        chess_board_state::{
+            CastlingRights,
+            Piece,
+            PieceKind
+        },
+        test_utils::{
+            address,
+            assert_status_index,
+            new_state,
+            start_game,
+            ADMIN,
+            STAKE
+        },
+        treasury_state::FeeConfig
+    };
+
+    const PLAYER1: u64 = 10;
+    const PLAYER2: u64 = 11;
+
+    // Comision del 5% sin minimo ni maximo
+    fn state_with_fee() -> ChessState {
+        let mut state = new_state();
+
+        state.set_fee_config(address(ADMIN), FeeConfig {
+            fee_basis_points: 500,
+            min_fee: 0,
+            max_fee: u128::MAX
+        }).unwrap();
+
+        state
+    }
+
+    fn draw(state: &mut ChessState, stakes: (BetAmout, BetAmout)) -> GameId {
+        let game_id = start_game(state, PLAYER1, PLAYER2, stakes);
+        state.end_match(game_id, address(ADMIN), None).unwrap();
+
+        game_id
+    }
+
+    #[test]
+    fn draw_refunds_split_in_proportion_to_the_stakes() {
+        assert_eq!(ChessState::draw_refunds(20, (10, 10)), (10, 10));
+        assert_eq!(ChessState::draw_refunds(40, (10, 30)), (10, 30));
+        assert_eq!(ChessState::draw_refunds(19, (10, 10)), (9, 9));
+        assert_eq!(ChessState::draw_refunds(10, (1, 2)), (3, 6));
+        assert_eq!(ChessState::draw_refunds(10, (0, 0)), (0, 0));
+    }
+
+    #[test]
+    fn draw_with_equal_stakes_and_no_fee_returns_both_stakes() {
+        let mut state = new_state();
+        let game_id = draw(&mut state, (STAKE, STAKE));
+
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+        assert_eq!(state.balance(address(PLAYER2)), STAKE);
+        assert_eq!(state.treasury.balance, 0);
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Ended { winner: None });
+    }
+
+    #[test]
+    fn draw_with_equal_stakes_pays_the_fee_by_halves() {
+        let mut state = state_with_fee();
+        draw(&mut state, (STAKE, STAKE));
+
+        let pot = 2 * STAKE;
+        let fee = pot / 20;
+
+        assert_eq!(state.treasury.balance, fee);
+        assert_eq!(state.balance(address(PLAYER1)), (pot - fee) / 2);
+        assert_eq!(state.balance(address(PLAYER2)), (pot - fee) / 2);
+        assert_eq!(state.balance(address(PLAYER1)) + state.balance(address(PLAYER2)), pot - fee);
+    }
+
+    #[test]
+    fn draw_with_unequal_stakes_pays_the_fee_in_proportion() {
+        let mut state = state_with_fee();
+        draw(&mut state, (STAKE, 3 * STAKE));
+
+        let pot = 4 * STAKE;
+        let fee = pot / 20;
+
+        assert_eq!(state.treasury.balance, fee);
+        assert_eq!(state.balance(address(PLAYER1)), (pot - fee) / 4);
+        assert_eq!(state.balance(address(PLAYER2)), (pot - fee) * 3 / 4);
+        assert_eq!(state.balance(address(PLAYER1)) + state.balance(address(PLAYER2)), pot - fee);
+    }
+
+    #[test]
+    fn draw_fee_respects_the_minimum() {
+        let mut state = new_state();
+
+        state.set_fee_config(address(ADMIN), FeeConfig {
+            fee_basis_points: 1,
+            min_fee: ONE_VARA,
+            max_fee: u128::MAX
+        }).unwrap();
+
+        draw(&mut state, (STAKE, STAKE));
+
+        assert_eq!(state.treasury.balance, ONE_VARA);
+        assert_eq!(state.balance(address(PLAYER1)) + state.balance(address(PLAYER2)), 2 * STAKE - ONE_VARA);
+    }
+
+    #[test]
+    fn draw_rounding_dust_goes_to_the_treasury() {
+        let mut state = state_with_fee();
+        let stakes = (STAKE + 1, 2 * STAKE);
+        draw(&mut state, stakes);
+
+        let pot = stakes.0 + stakes.1;
+        let fee = pot * 500 / 10_000;
+        let credited = state.balance(address(PLAYER1)) + state.balance(address(PLAYER2));
+        let dust = pot - fee - credited;
+
+        assert!(dust > 0);
+        assert_eq!(credited + dust, pot - fee);
+        assert_eq!(state.treasury.balance, fee + dust);
+        assert_eq!(state.ledger.total_balance + state.treasury.balance, pot);
+    }
+
+    fn create_open(state: &mut ChessState, player: u64) -> GameId {
+        let game_id = state
+            .create_open_game(address(player), "creator".into(), player, STAKE, None)
+            .unwrap();
+
+        assert_status_index(state);
+
+        game_id
+    }
+
+    fn join_open(state: &mut ChessState, player: u64, game_id: GameId) {
+        state
+            .join_open_game(address(player), "guest".into(), player, STAKE, None, game_id)
+            .unwrap();
+
+        assert_status_index(state);
+    }
+
+    #[test]
+    fn status_index_follows_games_that_end_with_a_result() {
+        let mut state = new_state();
+
+        let game_id = create_open(&mut state, PLAYER1);
+        assert_eq!(state.all_games_id_by_status(GameStatusKind::Waiting), [game_id]);
+
+        join_open(&mut state, PLAYER2, game_id);
+        assert_eq!(state.all_games_id_by_status(GameStatusKind::Started), [game_id]);
+
+        state.end_match(game_id, address(ADMIN), Some(address(PLAYER1))).unwrap();
+        assert_status_index(&state);
+        assert_eq!(state.all_games_id_by_status(GameStatusKind::Ended), [game_id]);
+    }
+
+    #[test]
+    fn status_index_follows_resigned_games() {
+        let mut state = new_state();
+
+        let game_id = create_open(&mut state, PLAYER1);
+        join_open(&mut state, PLAYER2, game_id);
+
+        state.resign(address(PLAYER2), game_id).unwrap();
+        assert_status_index(&state);
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Ended { winner: Some(address(PLAYER1)) });
+    }
+
+    #[test]
+    fn status_index_follows_cancelled_and_aborted_games() {
+        let mut state = new_state();
+
+        let cancelled = create_open(&mut state, PLAYER1);
+        state.cancel_open_game(address(PLAYER1), cancelled).unwrap();
+        assert_status_index(&state);
+
+        let aborted = create_open(&mut state, PLAYER1);
+        join_open(&mut state, PLAYER2, aborted);
+        state.abort_game(address(ADMIN), aborted).unwrap();
+        assert_status_index(&state);
+
+        assert_eq!(state.all_games_id_by_status(GameStatusKind::Cancelled), [cancelled, aborted]);
+        assert_eq!(state.balance(address(PLAYER1)), 2 * STAKE);
+        assert_eq!(state.balance(address(PLAYER2)), STAKE);
+    }
+
+    #[test]
+    fn status_index_is_not_changed_by_rejected_transitions() {
+        let mut state = new_state();
+
+        let game_id = create_open(&mut state, PLAYER1);
+
+        assert!(state.resign(address(PLAYER1), game_id).is_err());
+        assert!(state.abort_game(address(ADMIN), game_id).is_err());
+        assert!(state.end_match(game_id, address(PLAYER1), None).is_err());
+        assert_status_index(&state);
+
+        join_open(&mut state, PLAYER2, game_id);
+        state.resign(address(PLAYER1), game_id).unwrap();
+
+        assert!(state.cancel_open_game(address(PLAYER1), game_id).is_err());
+        assert!(state.abort_game(address(ADMIN), game_id).is_err());
+        assert!(state.end_match(game_id, address(ADMIN), None).is_err());
+        assert_status_index(&state);
+        assert_eq!(state.all_games_id_by_status(GameStatusKind::Ended), [game_id]);
+    }
+
+    #[test]
+    fn finished_games_keep_only_the_key_of_their_last_position() {
+        let mut state = new_state();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        let last_position = state.game_boards[&game_id].position_keys.last().copied();
+        state.resign(address(PLAYER1), game_id).unwrap();
+
+        assert!(!state.game_boards.contains_key(&game_id));
+        assert_eq!(state.game_by_id(game_id).unwrap().final_position_key, last_position);
+    }
+
+    // El anfitrion apuesta `STAKE` y el invitado el triple
+    fn invite_with_guest_stake(state: &mut ChessState) {
+        state
+            .create_invitation_with_bet(address(PLAYER1), PLAYER1, PLAYER2, STAKE, 3 * STAKE, None)
+            .unwrap();
+    }
+
+    fn accept_with_stake(state: &mut ChessState, amount: BetAmout) -> Result<GameId, Bet2ChessErrors> {
+        state.accept_invitation(
+            address(PLAYER2),
+            (PLAYER1, PLAYER2),
+            1,
+            "player1".into(),
+            "player2".into(),
+            Stake { amount, token: None }
+        )
+    }
+
+    #[test]
+    fn invited_player_must_send_exactly_their_stake() {
+        let mut state = new_state();
+        invite_with_guest_stake(&mut state);
+
+        // El invitado manda la apuesta del anfitrion, se le regresa completa
+        assert_eq!(
+            accept_with_stake(&mut state, STAKE),
+            Err(Bet2ChessErrors::BetIsNotTheSameForMatch { game_bet: 3 * STAKE, bet_by_user: STAKE })
+        );
+        assert_eq!(state.balance(address(PLAYER2)), STAKE);
+
+        let game_id = accept_with_stake(&mut state, 3 * STAKE).unwrap();
+        let game_data = state.game_by_id(game_id).unwrap();
+
+        assert_eq!((game_data.game_bet, game_data.player2_bet), (STAKE, 3 * STAKE));
+        assert_eq!(game_data.pot(), 4 * STAKE);
+        assert!(state.invitations.is_empty());
+    }
+
+    #[test]
+    fn winner_of_unequal_stakes_takes_the_whole_pot_minus_the_fee() {
+        let mut state = state_with_fee();
+        invite_with_guest_stake(&mut state);
+
+        let game_id = accept_with_stake(&mut state, 3 * STAKE).unwrap();
+        state.resign(address(PLAYER2), game_id).unwrap();
+
+        let pot = 4 * STAKE;
+        let fee = pot / 20;
+
+        assert_eq!(state.balance(address(PLAYER1)), pot - fee);
+        assert_eq!(state.balance(address(PLAYER2)), 0);
+        assert_eq!(state.treasury.balance, fee);
+    }
+
+    #[test]
+    fn open_games_are_joined_with_the_stake_of_the_creator() {
+        let mut state = new_state();
+        let game_id = create_open(&mut state, PLAYER1);
+
+        // Una apuesta distinta se regresa al jugador que intento unirse
+        assert_eq!(
+            state.join_open_game(address(PLAYER2), "guest".into(), PLAYER2, 2 * STAKE, None, game_id),
+            Err(Bet2ChessErrors::BetIsNotTheSameForMatch { game_bet: STAKE, bet_by_user: 2 * STAKE })
+        );
+        assert_eq!(state.balance(address(PLAYER2)), 2 * STAKE);
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Waiting);
+    }
+
+    #[test]
+    fn users_cant_invite_themselves() {
+        let mut state = new_state();
+
+        assert_eq!(
+            state.create_invitation_with_bet(address(PLAYER1), PLAYER1, PLAYER1, STAKE, STAKE, None),
+            Err(Bet2ChessErrors::UserCantJoinTheirOwnGame(0))
+        );
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+        assert!(state.invitations.is_empty());
+        assert!(!state.user_invitations.contains_key(&PLAYER1));
+    }
+
+    #[test]
+    fn each_player_only_moves_the_pieces_of_their_color() {
+        let mut state = new_state();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+        let e2_e4 = ChessMove { from: 12, to: 28, promotion: None };
+        let e7_e5 = ChessMove { from: 52, to: 36, promotion: None };
+
+        assert_eq!(state.make_move(address(PLAYER2), game_id, e2_e4), Err(Bet2ChessErrors::NotPlayerTurn(game_id)));
+        state.make_move(address(PLAYER1), game_id, e2_e4).unwrap();
+
+        assert_eq!(state.make_move(address(PLAYER1), game_id, e7_e5), Err(Bet2ChessErrors::NotPlayerTurn(game_id)));
+        state.make_move(address(PLAYER2), game_id, e7_e5).unwrap();
+    }
+
+    fn square(name: &str) -> u8 {
+        let bytes = name.as_bytes();
+
+        (bytes[1] - b'1') * 8 + (bytes[0] - b'a')
+    }
+
+    // Juega las jugadas con el jugador que tiene el turno y regresa el
+    // estado del tablero despues de la ultima
+    fn play_moves(state: &mut ChessState, game_id: GameId, moves: &[(&str, &str)]) -> BoardStatus {
+        let mut status = BoardStatus::InProgress;
+
+        for (from, to) in moves {
+            let player = match state.game_boards[&game_id].turn {
+                PieceColor::White => address(PLAYER1),
+                PieceColor::Black => address(PLAYER2)
+            };
+            let chess_move = ChessMove { from: square(from), to: square(to), promotion: None };
+
+            status = state.make_move(player, game_id, chess_move).unwrap();
+        }
+
+        status
+    }
+
+    fn assert_draw_settled(state: &ChessState, game_id: GameId) {
+        let pot = 2 * STAKE;
+        let fee = pot / 20;
+
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Ended { winner: None });
+        assert!(!state.game_boards.contains_key(&game_id));
+        assert_eq!(state.treasury.balance, fee);
+        assert_eq!(state.balance(address(PLAYER1)), (pot - fee) / 2);
+        assert_eq!(state.balance(address(PLAYER2)), (pot - fee) / 2);
+    }
+
+    #[test]
+    fn checkmate_pays_the_pot_to_the_winner() {
+        let mut state = state_with_fee();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        let status = play_moves(&mut state, game_id, &[("f2", "f3"), ("e7", "e5"), ("g2", "g4"), ("d8", "h4")]);
+
+        let pot = 2 * STAKE;
+        let fee = pot / 20;
+
+        assert_eq!(status, BoardStatus::Checkmate { winner: PieceColor::Black });
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Ended { winner: Some(address(PLAYER2)) });
+        assert_eq!(state.balance(address(PLAYER2)), pot - fee);
+        assert_eq!(state.balance(address(PLAYER1)), 0);
+        assert_eq!(state.treasury.balance, fee);
+        assert_status_index(&state);
+
+        // La partida ya no acepta jugadas
+        let chess_move = ChessMove { from: square("a2"), to: square("a3"), promotion: None };
+        assert_eq!(state.make_move(address(PLAYER1), game_id, chess_move), Err(Bet2ChessErrors::GameIsNotStarted(game_id)));
+    }
+
+    #[test]
+    fn stalemate_is_settled_as_a_draw() {
+        let mut state = state_with_fee();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        // Ahogado mas corto conocido, de Sam Loyd
+        let status = play_moves(&mut state, game_id, &[
+            ("e2", "e3"), ("a7", "a5"), ("d1", "h5"), ("a8", "a6"), ("h5", "a5"), ("h7", "h5"),
+            ("h2", "h4"), ("a6", "h6"), ("a5", "c7"), ("f7", "f6"), ("c7", "d7"), ("e8", "f7"),
+            ("d7", "b7"), ("d8", "d3"), ("b7", "b8"), ("d3", "h7"), ("b8", "c8"), ("f7", "g6"),
+            ("c8", "e6")
+        ]);
+
+        assert_eq!(status, BoardStatus::Stalemate);
+        assert_draw_settled(&state, game_id);
+    }
+
+    #[test]
+    fn threefold_repetition_is_settled_as_a_draw() {
+        let mut state = state_with_fee();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+        let knights_out_and_back = [("g1", "f3"), ("g8", "f6"), ("f3", "g1"), ("f6", "g8")];
+
+        // La posicion inicial se repite por segunda vez
+        assert_eq!(play_moves(&mut state, game_id, &knights_out_and_back), BoardStatus::InProgress);
+
+        assert_eq!(play_moves(&mut state, game_id, &knights_out_and_back), BoardStatus::ThreefoldRepetition);
+        assert_draw_settled(&state, game_id);
+    }
+
+    #[test]
+    fn fifty_moves_without_captures_or_pawn_moves_are_settled_as_a_draw() {
+        let mut state = state_with_fee();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        state.game_boards.get_mut(&game_id).unwrap().halfmove_clock = 98;
+
+        assert_eq!(play_moves(&mut state, game_id, &[("g1", "f3")]), BoardStatus::InProgress);
+        assert_eq!(play_moves(&mut state, game_id, &[("g8", "f6")]), BoardStatus::FiftyMoveRule);
+        assert_draw_settled(&state, game_id);
+    }
+
+    #[test]
+    fn insufficient_material_is_settled_as_a_draw() {
+        let mut state = state_with_fee();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        // Solo quedan los reyes y un peon negro que el rey blanco puede capturar
+        let board = state.game_boards.get_mut(&game_id).unwrap();
+        board.squares = [None; 64];
+        board.squares[square("e1") as usize] = Some(Piece { kind: PieceKind::King, color: PieceColor::White });
+        board.squares[square("e8") as usize] = Some(Piece { kind: PieceKind::King, color: PieceColor::Black });
+        board.squares[square("d2") as usize] = Some(Piece { kind: PieceKind::Pawn, color: PieceColor::Black });
+        board.castling = CastlingRights {
+            white_king_side: false,
+            white_queen_side: false,
+            black_king_side: false,
+            black_queen_side: false
+        };
+
+        assert_eq!(play_moves(&mut state, game_id, &[("e1", "d2")]), BoardStatus::InsufficientMaterial);
+        assert_draw_settled(&state, game_id);
+    }
+
+    #[test]
+    fn illegal_moves_are_rejected_without_settling() {
+        let mut state = new_state();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+        let chess_move = ChessMove { from: square("e2"), to: square("e5"), promotion: None };
+
+        assert_eq!(state.make_move(address(PLAYER1), game_id, chess_move), Err(Bet2ChessErrors::IllegalMove(chess_move)));
+        assert_eq!(state.make_move(address(3), game_id, chess_move), Err(Bet2ChessErrors::UserIsNotAPlayerOfTheGame(game_id)));
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Started);
+        assert_eq!(state.ledger.total_balance, 0);
+    }
+
+    fn invite(state: &mut ChessState) {
+        state
+            .create_invitation_with_bet(address(PLAYER1), PLAYER1, PLAYER2, STAKE, STAKE, None)
+            .unwrap();
+    }
+
+    #[test]
+    fn accepted_invitations_start_a_game_and_are_removed() {
+        let mut state = new_state();
+        invite(&mut state);
+
+        let game_id = accept_with_stake(&mut state, STAKE).unwrap();
+
+        let game_data = state.game_by_id(game_id).unwrap();
+        assert!(game_data.status == GameStatus::Started);
+        assert_eq!((game_data.player1, game_data.player2), (address(PLAYER1), address(PLAYER2)));
+        assert_eq!(state.games_by_web2_id.get(&1), Some(&game_id));
+        assert!(state.invitations.is_empty());
+        assert!(state.user_invitations[&PLAYER1].invitations_sent.is_empty());
+        assert!(state.user_invitations[&PLAYER2].invitations_received.is_empty());
+    }
+
+    #[test]
+    fn invitation_is_kept_when_its_game_cant_be_created() {
+        let mut state = new_state();
+        invite(&mut state);
+        state.current_game_id = GameId::MAX;
+
+        assert_eq!(accept_with_stake(&mut state, STAKE), Err(Bet2ChessErrors::CantIncrementGamesIdItOverflow));
+        assert_eq!(state.balance(address(PLAYER2)), STAKE);
+        assert!(state.invitations.contains_key(&(PLAYER1, PLAYER2)));
+        assert!(state.user_invitations[&PLAYER2].invitations_received.contains_key(&PLAYER1));
+
+        // El anfitrion todavia puede cancelarla y recuperar su apuesta
+        state.cancel_invitation(PLAYER1, PLAYER1, PLAYER2).unwrap();
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+    }
+
+    #[test]
+    fn invitation_without_the_inviter_address_is_refunded() {
+        let mut state = new_state();
+        invite(&mut state);
+        state.user_invitations.remove(&PLAYER2);
+
+        assert_eq!(accept_with_stake(&mut state, STAKE), Err(Bet2ChessErrors::UserHasNoInvitationfromTheUser(PLAYER1)));
+        assert_eq!(state.balance(address(PLAYER2)), STAKE);
+        assert!(state.games_by_id.is_empty());
+    }
+
+    #[test]
+    fn reused_web2_game_ids_are_refunded_before_touching_the_invitation() {
+        let mut state = new_state();
+        state.games_by_web2_id.insert(1, 7);
+        invite(&mut state);
+
+        assert_eq!(accept_with_stake(&mut state, STAKE), Err(Bet2ChessErrors::Web2GameIdAlreadyExists(1)));
+        assert_eq!(state.balance(address(PLAYER2)), STAKE);
+        assert!(state.invitations.contains_key(&(PLAYER1, PLAYER2)));
+    }
+
+    const TTL: u32 = 100;
+
+    fn invite_at_block(state: &mut ChessState, inviter: u64, guest: u64, block: u32) {
+        mock::set_block_height(block);
+        state
+            .create_invitation_with_bet(address(inviter), inviter, guest, STAKE, STAKE, None)
+            .unwrap();
+    }
+
+    #[test]
+    fn invitation_ttl_can_only_be_changed_by_admins() {
+        let mut state = new_state();
+
+        assert_eq!(state.set_invitation_ttl(address(PLAYER1), TTL), Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction));
+        assert_eq!(state.set_invitation_ttl(address(ADMIN), 0), Err(Bet2ChessErrors::InvalidInvitationTtl));
+        assert_eq!(state.invitation_ttl_blocks, DEFAULT_INVITATION_TTL_BLOCKS);
+
+        state.set_invitation_ttl(address(ADMIN), TTL).unwrap();
+        invite_at_block(&mut state, PLAYER1, PLAYER2, 50);
+
+        let invitation = state.invitations[&(PLAYER1, PLAYER2)];
+        assert_eq!((invitation.created_at_block, invitation.expires_at_block), (50, 50 + TTL));
+        assert_eq!(invitation.remaining_blocks(60), TTL - 10);
+        assert_eq!(invitation.remaining_blocks(50 + TTL + 1), 0);
+    }
+
+    #[test]
+    fn invitations_schedule_their_own_expiry() {
+        let mut state = new_state();
+        invite_at_block(&mut state, PLAYER1, PLAYER2, 0);
+
+        assert_eq!(mock::sent_messages(), [(address(mock::PROGRAM_ID), 0)]);
+    }
+
+    #[test]
+    fn invitations_are_kept_if_their_expiry_cant_be_scheduled() {
+        let mut state = new_state();
+        mock::set_messages_fail(true);
+
+        invite_at_block(&mut state, PLAYER1, PLAYER2, 0);
+
+        assert!(state.invitations.contains_key(&(PLAYER1, PLAYER2)));
+        assert_eq!(state.balance(address(PLAYER1)), 0);
+    }
+
+    #[test]
+    fn invitations_only_expire_at_the_end_of_their_lifetime() {
+        let mut state = new_state();
+        state.set_invitation_ttl(address(ADMIN), TTL).unwrap();
+        invite_at_block(&mut state, PLAYER1, PLAYER2, 0);
+
+        mock::set_block_height(TTL - 1);
+        assert_eq!(state.expire_invitation(PLAYER1, PLAYER2), Err(Bet2ChessErrors::InvitationHasNotExpired));
+
+        mock::set_block_height(TTL);
+        state.expire_invitation(PLAYER1, PLAYER2).unwrap();
+
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+        assert!(state.invitations.is_empty());
+        assert_eq!(state.expire_invitation(PLAYER1, PLAYER2), Err(Bet2ChessErrors::InvitationDoesNotExists));
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+    }
+
+    #[test]
+    fn accepting_an_expired_invitation_refunds_both_players() {
+        let mut state = new_state();
+        state.set_invitation_ttl(address(ADMIN), TTL).unwrap();
+        invite(&mut state);
+
+        mock::set_block_height(TTL);
+
+        assert_eq!(accept_with_stake(&mut state, STAKE), Err(Bet2ChessErrors::InvitationExpired));
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+        assert_eq!(state.balance(address(PLAYER2)), STAKE);
+        assert!(state.invitations.is_empty());
+        assert!(state.games_by_id.is_empty());
+    }
+
+    #[test]
+    fn guest_is_refunded_even_if_the_expired_invitation_cant_be_removed() {
+        let mut state = new_state();
+        state.set_invitation_ttl(address(ADMIN), TTL).unwrap();
+        invite(&mut state);
+        state.user_invitations.remove(&PLAYER1);
+
+        mock::set_block_height(TTL);
+
+        assert_eq!(accept_with_stake(&mut state, STAKE), Err(Bet2ChessErrors::UserHasNoInvitationfromTheUser(PLAYER2)));
+        assert_eq!(state.balance(address(PLAYER2)), STAKE);
+    }
+
+    #[test]
+    fn sweep_expires_up_to_the_limit() {
+        let mut state = new_state();
+        state.set_invitation_ttl(address(ADMIN), TTL).unwrap();
+        invite_at_block(&mut state, 20, 21, 0);
+        invite_at_block(&mut state, 22, 23, 10);
+        invite_at_block(&mut state, 24, 25, 20);
+        invite_at_block(&mut state, 26, 27, 50);
+
+        mock::set_block_height(TTL + 20);
+
+        assert_eq!(state.sweep_expired_invitations(2), 2);
+        assert_eq!(state.sweep_expired_invitations(10), 1);
+        assert_eq!(state.sweep_expired_invitations(10), 0);
+
+        assert_eq!(state.invitations.keys().copied().collect::<Vec<_>>(), [(26, 27)]);
+        assert_eq!(state.ledger.total_balance, 3 * STAKE);
+        assert_eq!(state.balance(address(26)), 0);
+    }
+
+    const ORACLE: u64 = 2;
+
+    #[test]
+    fn only_admins_manage_admins() {
+        let mut state = new_state();
+
+        assert_eq!(state.add_admin(address(PLAYER1), address(PLAYER1)), Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction));
+        assert_eq!(state.add_admin(address(ADMIN), address(ADMIN)), Err(Bet2ChessErrors::AdminAlreadyExists(address(ADMIN))));
+
+        state.add_admin(address(ADMIN), address(PLAYER1)).unwrap();
+        assert!(state.is_admin(address(PLAYER1)));
+
+        assert_eq!(state.remove_admin(address(PLAYER2), address(PLAYER1)), Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction));
+        assert_eq!(state.remove_admin(address(ADMIN), address(PLAYER2)), Err(Bet2ChessErrors::AddressIsNotAnAdmin(address(PLAYER2))));
+
+        // Un admin se puede quitar a si mismo mientras quede otro
+        state.remove_admin(address(PLAYER1), address(PLAYER1)).unwrap();
+        assert_eq!(state.admins, [address(ADMIN)]);
+        assert_eq!(state.remove_admin(address(ADMIN), address(ADMIN)), Err(Bet2ChessErrors::CantRemoveTheLastAdmin));
+    }
+
+    #[test]
+    fn only_admins_manage_result_oracles() {
+        let mut state = new_state();
+
+        assert_eq!(state.add_result_oracle(address(ORACLE), address(ORACLE)), Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction));
+
+        state.add_result_oracle(address(ADMIN), address(ORACLE)).unwrap();
+        assert_eq!(state.add_result_oracle(address(ADMIN), address(ORACLE)), Err(Bet2ChessErrors::ResultOracleAlreadyExists(address(ORACLE))));
+        assert_eq!(state.result_oracles, [address(ORACLE)]);
+
+        // Los oraculos reportan resultados pero no administran el contrato
+        assert_eq!(state.remove_result_oracle(address(ORACLE), address(ORACLE)), Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction));
+        assert!(!state.is_admin(address(ORACLE)));
+
+        state.remove_result_oracle(address(ADMIN), address(ORACLE)).unwrap();
+        assert_eq!(state.remove_result_oracle(address(ADMIN), address(ORACLE)), Err(Bet2ChessErrors::AddressIsNotAResultOracle(address(ORACLE))));
+    }
+
+    #[test]
+    fn only_admins_and_result_oracles_end_games() {
+        let mut state = new_state();
+        state.add_result_oracle(address(ADMIN), address(ORACLE)).unwrap();
+
+        let by_oracle = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+        let by_admin = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        // Ni los jugadores pueden reportar su propio resultado
+        for caller in [PLAYER1, PLAYER2, 3] {
+            assert_eq!(state.end_match(by_oracle, address(caller), Some(address(PLAYER1))), Err(Bet2ChessErrors::OnlyAdminsCanEndGames));
+            assert_eq!(state.end_match(by_oracle, address(caller), None), Err(Bet2ChessErrors::OnlyAdminsCanEndGames));
+        }
+        assert!(state.game_by_id(by_oracle).unwrap().status == GameStatus::Started);
+        assert_eq!(state.ledger.total_balance, 0);
+
+        state.end_match(by_oracle, address(ORACLE), Some(address(PLAYER1))).unwrap();
+        state.end_match(by_admin, address(ADMIN), None).unwrap();
+        assert_eq!(state.balance(address(PLAYER1)), 3 * STAKE);
+
+        // Un oraculo que se quita ya no puede terminar partidas
+        let after_removal = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+        state.remove_result_oracle(address(ADMIN), address(ORACLE)).unwrap();
+        assert_eq!(state.end_match(after_removal, address(ORACLE), None), Err(Bet2ChessErrors::OnlyAdminsCanEndGames));
+    }
+
+    #[test]
+    fn only_admins_abort_games() {
+        let mut state = new_state();
+        state.add_result_oracle(address(ADMIN), address(ORACLE)).unwrap();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        for caller in [PLAYER1, ORACLE] {
+            assert_eq!(state.abort_game(address(caller), game_id), Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction));
+        }
+
+        state.abort_game(address(ADMIN), game_id).unwrap();
+        assert_eq!((state.balance(address(PLAYER1)), state.balance(address(PLAYER2))), (STAKE, STAKE));
+    }
+
+    #[test]
+    fn only_players_of_the_game_can_win_it() {
+        let mut state = new_state();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        for winner in [ADMIN, 3] {
+            assert_eq!(state.end_match(game_id, address(ADMIN), Some(address(winner))), Err(Bet2ChessErrors::InvalidWinner(address(winner))));
+        }
+
+        // El resultado invalido no toca la partida ni los saldos
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Started);
+        assert_eq!(state.ledger.total_balance, 0);
+        assert_status_index(&state);
+    }
+
+    #[test]
+    fn only_started_games_can_be_ended() {
+        let mut state = new_state();
+
+        let waiting = state.create_match(address(PLAYER1), "player10".to_string(), PLAYER1, STAKE, None).unwrap();
+        assert_eq!(state.end_match(waiting, address(ADMIN), Some(address(PLAYER1))), Err(Bet2ChessErrors::GameIsNotStarted(waiting)));
+        assert_eq!(state.end_match(waiting, address(ADMIN), None), Err(Bet2ChessErrors::GameIsNotStarted(waiting)));
+        assert!(state.game_by_id(waiting).unwrap().status == GameStatus::Waiting);
+
+        let aborted = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+        state.abort_game(address(ADMIN), aborted).unwrap();
+        assert_eq!(state.end_match(aborted, address(ADMIN), None), Err(Bet2ChessErrors::GameWasCancelled(aborted)));
+
+        assert_eq!(state.end_match(99, address(ADMIN), None), Err(Bet2ChessErrors::GameIdDoesNotExists(99)));
+        assert_status_index(&state);
+    }
+
+    #[test]
+    fn ended_games_are_paid_only_once() {
+        let mut state = state_with_fee();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        state.end_match(game_id, address(ADMIN), Some(address(PLAYER1))).unwrap();
+
+        let balances = (state.balance(address(PLAYER1)), state.balance(address(PLAYER2)));
+        let treasury = state.treasury.balance;
+        let stats = state.player_stats(PLAYER1);
+
+        // Ni repetir el resultado, ni cambiarlo, ni declarar tablas paga otra vez
+        for winner in [Some(address(PLAYER1)), Some(address(PLAYER2)), None] {
+            assert_eq!(state.end_match(game_id, address(ADMIN), winner), Err(Bet2ChessErrors::GameAlreadyEnded(game_id)));
+        }
+
+        assert_eq!((state.balance(address(PLAYER1)), state.balance(address(PLAYER2))), balances);
+        assert_eq!(state.treasury.balance, treasury);
+        assert_eq!(state.player_stats(PLAYER1).wins, stats.wins);
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Ended { winner: Some(address(PLAYER1)) });
+    }
+
+    #[test]
+    fn only_invitation_participants_can_cancel_it() {
+        let mut state = new_state();
+        invite(&mut state);
+
+        assert!(matches!(state.cancel_invitation(3, PLAYER1, PLAYER2), Err(Bet2ChessErrors::OnlyInvitationParticipantsCanCancelIt)));
+        assert_eq!(state.decline_invitation(3, PLAYER1, PLAYER2), Err(Bet2ChessErrors::OnlyInvitationParticipantsCanCancelIt));
+        assert_eq!(state.invitations.len(), 1);
+        assert_eq!(state.balance(address(PLAYER1)), 0);
+
+        // Quien invita cancela, pero no puede rechazar su propia invitacion
+        assert_eq!(state.decline_invitation(PLAYER1, PLAYER1, PLAYER2), Err(Bet2ChessErrors::OnlyTheGuestCanDeclineTheInvitation));
+        assert!(matches!(state.cancel_invitation(PLAYER1, PLAYER1, PLAYER2), Ok(InvitationParticipant::Inviter)));
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+        assert!(state.invitations.is_empty());
+    }
+
+    #[test]
+    fn guests_decline_invitations_and_the_stake_goes_back_to_the_inviter() {
+        let mut state = new_state();
+
+        invite(&mut state);
+        assert!(matches!(state.cancel_invitation(PLAYER2, PLAYER1, PLAYER2), Ok(InvitationParticipant::Guest)));
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+
+        invite(&mut state);
+        state.decline_invitation(PLAYER2, PLAYER1, PLAYER2).unwrap();
+        assert_eq!((state.balance(address(PLAYER1)), state.balance(address(PLAYER2))), (2 * STAKE, 0));
+        assert!(state.user_invitations[&PLAYER1].invitations_sent.is_empty());
+        assert!(state.user_invitations[&PLAYER2].invitations_received.is_empty());
+
+        // Una invitacion solo se puede rechazar una vez
+        assert!(state.decline_invitation(PLAYER2, PLAYER1, PLAYER2).is_err());
+        assert_eq!(state.balance(address(PLAYER1)), 2 * STAKE);
+    }
+
+    #[test]
+    fn stakes_are_checked_against_the_limits_in_planck() {
+        let mut state = new_state();
+
+        // Por defecto se apuesta desde un VARA y sin maximo
+        assert_eq!(state.check_stake(ONE_VARA - 1), Err(Bet2ChessErrors::StakeBelowMinimum(ONE_VARA)));
+        assert_eq!(state.check_stake(ONE_VARA), Ok(()));
+        assert_eq!(state.check_stake(BetAmout::MAX), Ok(()));
+        assert_eq!(state.check_stake(0), Ok(()));
+
+        let half_vara = ONE_VARA / 2;
+        state.set_stake_limits(address(ADMIN), StakeLimits { min_stake: half_vara, max_stake: STAKE + 1 }).unwrap();
+
+        assert_eq!(state.check_stake(half_vara - 1), Err(Bet2ChessErrors::StakeBelowMinimum(half_vara)));
+        assert_eq!(state.check_stake(half_vara), Ok(()));
+        assert_eq!(state.check_stake(half_vara + 1), Ok(()));
+        assert_eq!(state.check_stake(STAKE + 1), Ok(()));
+        assert_eq!(state.check_stake(STAKE + 2), Err(Bet2ChessErrors::StakeAboveMaximum(STAKE + 1)));
+    }
+
+    #[test]
+    fn only_admins_set_valid_stake_limits() {
+        let mut state = new_state();
+        let limits = StakeLimits { min_stake: ONE_VARA, max_stake: STAKE };
+
+        assert_eq!(state.set_stake_limits(address(PLAYER1), limits), Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction));
+
+        for (min_stake, max_stake) in [(0, STAKE), (STAKE + 1, STAKE)] {
+            assert_eq!(state.set_stake_limits(address(ADMIN), StakeLimits { min_stake, max_stake }), Err(Bet2ChessErrors::InvalidStakeLimits));
+        }
+
+        // Un limite de un solo valor es valido
+        state.set_stake_limits(address(ADMIN), StakeLimits { min_stake: STAKE, max_stake: STAKE }).unwrap();
+        assert_eq!(state.check_stake(STAKE), Ok(()));
+    }
+}
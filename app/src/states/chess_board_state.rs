@@ -0,0 +1,823 @@
+use sails_rs::prelude::*;
+
+// Squares are indexed from 0 to 63, where a1 = 0, h1 = 7, a8 = 56 and h8 = 63,
+// so the file of a square is `square % 8` and its rank is `square / 8`.
+pub type Square = u8;
+
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (1, 2), (2, 1), (2, -1), (1, -2),
+    (-1, -2), (-2, -1), (-2, 1), (-1, 2)
+];
+
+const KING_OFFSETS: [(i8, i8); 8] = [
+    (1, 0), (1, 1), (0, 1), (-1, 1),
+    (-1, 0), (-1, -1), (0, -1), (1, -1)
+];
+
+const ROOK_DIRECTIONS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+const PROMOTION_PIECES: [PieceKind; 4] = [
+    PieceKind::Queen,
+    PieceKind::Rook,
+    PieceKind::Bishop,
+    PieceKind::Knight
+];
+
+const BACK_RANK: [PieceKind; 8] = [
+    PieceKind::Rook,
+    PieceKind::Knight,
+    PieceKind::Bishop,
+    PieceKind::Queen,
+    PieceKind::King,
+    PieceKind::Bishop,
+    PieceKind::Knight,
+    PieceKind::Rook
+];
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PieceColor {
+    White,
+    Black
+}
+
+impl PieceColor {
+    pub fn opposite(self) -> Self {
+        match self {
+            PieceColor::White => PieceColor::Black,
+            PieceColor::Black => PieceColor::White
+        }
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PieceKind {
+    Pawn,
+    Knight,
+    Bishop,
+    Rook,
+    Queen,
+    King
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Piece {
+    pub kind: PieceKind,
+    pub color: PieceColor
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ChessMove {
+    pub from: Square,
+    pub to: Square,
+    // Only set when a pawn reaches the last rank
+    pub promotion: Option<PieceKind>
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CastlingRights {
+    pub white_king_side: bool,
+    pub white_queen_side: bool,
+    pub black_king_side: bool,
+    pub black_queen_side: bool
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum BoardStatus {
+    InProgress,
+    Check,
+    Checkmate {
+        winner: PieceColor
+    },
+    Stalemate,
+    ThreefoldRepetition,
+    FiftyMoveRule,
+    InsufficientMaterial
+}
+
+impl BoardStatus {
+    pub fn is_game_over(&self) -> bool {
+        !matches!(self, BoardStatus::InProgress | BoardStatus::Check)
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ChessBoard {
+    pub squares: [Option<Piece>; 64],
+    pub turn: PieceColor,
+    pub castling: CastlingRights,
+    // Square a pawn can be captured on "en passant" in the next move
+    pub en_passant: Option<Square>,
+    // Half moves since the last capture or pawn move
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+    // Keys of the positions reached since the last capture or pawn move,
+    // older positions can not be repeated anymore.
+    pub position_keys: Vec<u64>
+}
+
+impl Default for ChessBoard {
+    // Standard starting position, white to move
+    fn default() -> Self {
+        let mut squares = [None; 64];
+
+        for (file, kind) in BACK_RANK.iter().enumerate() {
+            squares[file] = Some(Piece { kind: *kind, color: PieceColor::White });
+            squares[8 + file] = Some(Piece { kind: PieceKind::Pawn, color: PieceColor::White });
+            squares[48 + file] = Some(Piece { kind: PieceKind::Pawn, color: PieceColor::Black });
+            squares[56 + file] = Some(Piece { kind: *kind, color: PieceColor::Black });
+        }
+
+        let mut board = Self {
+            squares,
+            turn: PieceColor::White,
+            castling: CastlingRights {
+                white_king_side: true,
+                white_queen_side: true,
+                black_king_side: true,
+                black_queen_side: true
+            },
+            en_passant: None,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            position_keys: Vec::new()
+        };
+
+        board.position_keys.push(board.position_key());
+
+        board
+    }
+}
+
+impl ChessBoard {
+    pub fn piece_at(&self, square: Square) -> Option<Piece> {
+        self.squares
+            .get(square as usize)
+            .copied()
+            .flatten()
+    }
+
+    // Plays the move if it is legal for the side to move, otherwise
+    // the board is left untouched.
+    pub fn play(&mut self, chess_move: ChessMove) -> Result<BoardStatus, ChessMove> {
+        if !self.legal_moves().contains(&chess_move) {
+            return Err(chess_move);
+        }
+
+        self.apply_move(&chess_move);
+
+        if self.halfmove_clock == 0 {
+            self.position_keys.clear();
+        }
+        self.position_keys.push(self.position_key());
+
+        Ok(self.status())
+    }
+
+    pub fn status(&self) -> BoardStatus {
+        let in_check = self.is_in_check(self.turn);
+
+        if self.legal_moves().is_empty() {
+            return if in_check {
+                BoardStatus::Checkmate { winner: self.turn.opposite() }
+            } else {
+                BoardStatus::Stalemate
+            };
+        }
+
+        if self.has_insufficient_material() {
+            return BoardStatus::InsufficientMaterial;
+        }
+
+        let current_key = self.position_key();
+        let repetitions = self.position_keys
+            .iter()
+            .filter(|key| **key == current_key)
+            .count();
+
+        if repetitions >= 3 {
+            return BoardStatus::ThreefoldRepetition;
+        }
+
+        // Fifty moves of each side without captures or pawn moves
+        if self.halfmove_clock >= 100 {
+            return BoardStatus::FiftyMoveRule;
+        }
+
+        if in_check {
+            BoardStatus::Check
+        } else {
+            BoardStatus::InProgress
+        }
+    }
+
+    pub fn is_in_check(&self, color: PieceColor) -> bool {
+        match self.king_square(color) {
+            Some(square) => self.is_square_attacked(square, color.opposite()),
+            None => false
+        }
+    }
+
+    pub fn legal_moves(&self) -> Vec<ChessMove> {
+        let color = self.turn;
+
+        self.pseudo_legal_moves()
+            .into_iter()
+            .filter(|chess_move| {
+                let mut board = self.clone();
+                board.apply_move(chess_move);
+                !board.is_in_check(color)
+            })
+            .collect()
+    }
+
+    pub fn is_square_attacked(&self, square: Square, by: PieceColor) -> bool {
+        // Pawns attack diagonally forward, so we look "backwards" from the square
+        let pawn_rank_step = if by == PieceColor::White { -1 } else { 1 };
+        for file_step in [-1, 1] {
+            if self.piece_from_offset(square, file_step, pawn_rank_step) == Some(Piece { kind: PieceKind::Pawn, color: by }) {
+                return true;
+            }
+        }
+
+        for (file_step, rank_step) in KNIGHT_OFFSETS {
+            if self.piece_from_offset(square, file_step, rank_step) == Some(Piece { kind: PieceKind::Knight, color: by }) {
+                return true;
+            }
+        }
+
+        for (file_step, rank_step) in KING_OFFSETS {
+            if self.piece_from_offset(square, file_step, rank_step) == Some(Piece { kind: PieceKind::King, color: by }) {
+                return true;
+            }
+        }
+
+        let sliders = [
+            (ROOK_DIRECTIONS, PieceKind::Rook),
+            (BISHOP_DIRECTIONS, PieceKind::Bishop)
+        ];
+
+        for (directions, kind) in sliders {
+            for (file_step, rank_step) in directions {
+                let mut current = square;
+
+                while let Some(next) = offset_square(current, file_step, rank_step) {
+                    if let Some(piece) = self.squares[next as usize] {
+                        if piece.color == by && (piece.kind == kind || piece.kind == PieceKind::Queen) {
+                            return true;
+                        }
+                        break;
+                    }
+                    current = next;
+                }
+            }
+        }
+
+        false
+    }
+
+    // Neither side can checkmate: lone kings, a single minor piece, or
+    // only bishops that all move on squares of the same color.
+    pub fn has_insufficient_material(&self) -> bool {
+        let mut minor_pieces = 0;
+        let mut knights = 0;
+        let mut bishop_square_colors = [false, false];
+
+        for (square, piece) in self.squares.iter().enumerate() {
+            let Some(piece) = piece else {
+                continue;
+            };
+
+            match piece.kind {
+                PieceKind::King => {},
+                PieceKind::Knight => {
+                    minor_pieces += 1;
+                    knights += 1;
+                },
+                PieceKind::Bishop => {
+                    minor_pieces += 1;
+                    bishop_square_colors[(square / 8 + square % 8) % 2] = true;
+                },
+                _ => return false
+            }
+        }
+
+        if minor_pieces <= 1 {
+            return true;
+        }
+
+        knights == 0 && !(bishop_square_colors[0] && bishop_square_colors[1])
+    }
+
+    // Identifies a position for the repetition rule: pieces, side to move,
+    // castling rights and the en passant square only when it can be used.
+    fn position_key(&self) -> u64 {
+        let en_passant = self.en_passant
+            .filter(|_| {
+                self.pseudo_legal_moves()
+                    .iter()
+                    .any(|chess_move| {
+                        Some(chess_move.to) == self.en_passant
+                            && self.squares[chess_move.from as usize]
+                                .is_some_and(|piece| piece.kind == PieceKind::Pawn)
+                    })
+            });
+
+        let encoded = (&self.squares, self.turn, self.castling, en_passant).encode();
+
+        // FNV-1a
+        encoded
+            .iter()
+            .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+                (hash ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+            })
+    }
+
+    fn king_square(&self, color: PieceColor) -> Option<Square> {
+        self.squares
+            .iter()
+            .position(|piece| *piece == Some(Piece { kind: PieceKind::King, color }))
+            .map(|square| square as Square)
+    }
+
+    fn piece_from_offset(&self, square: Square, file_step: i8, rank_step: i8) -> Option<Piece> {
+        offset_square(square, file_step, rank_step)
+            .and_then(|target| self.squares[target as usize])
+    }
+
+    // Moves that follow the movement rules of each piece without
+    // checking if the own king is left in check.
+    fn pseudo_legal_moves(&self) -> Vec<ChessMove> {
+        let mut moves = Vec::new();
+
+        for square in 0..64u8 {
+            let Some(piece) = self.squares[square as usize] else {
+                continue;
+            };
+
+            if piece.color != self.turn {
+                continue;
+            }
+
+            match piece.kind {
+                PieceKind::Pawn => self.pawn_moves(square, &mut moves),
+                PieceKind::Knight => self.step_moves(square, &KNIGHT_OFFSETS, &mut moves),
+                PieceKind::Bishop => self.slide_moves(square, &BISHOP_DIRECTIONS, &mut moves),
+                PieceKind::Rook => self.slide_moves(square, &ROOK_DIRECTIONS, &mut moves),
+                PieceKind::Queen => {
+                    self.slide_moves(square, &ROOK_DIRECTIONS, &mut moves);
+                    self.slide_moves(square, &BISHOP_DIRECTIONS, &mut moves);
+                },
+                PieceKind::King => {
+                    self.step_moves(square, &KING_OFFSETS, &mut moves);
+                    self.castling_moves(square, &mut moves);
+                }
+            }
+        }
+
+        moves
+    }
+
+    fn pawn_moves(&self, square: Square, moves: &mut Vec<ChessMove>) {
+        let color = self.turn;
+        let (rank_step, start_rank, last_rank) = match color {
+            PieceColor::White => (1, 1, 7),
+            PieceColor::Black => (-1, 6, 0)
+        };
+
+        let mut push = |to: Square| {
+            if to / 8 == last_rank {
+                for promotion in PROMOTION_PIECES {
+                    moves.push(ChessMove { from: square, to, promotion: Some(promotion) });
+                }
+            } else {
+                moves.push(ChessMove { from: square, to, promotion: None });
+            }
+        };
+
+        if let Some(one_step) = offset_square(square, 0, rank_step) {
+            if self.squares[one_step as usize].is_none() {
+                push(one_step);
+
+                if square / 8 == start_rank {
+                    if let Some(two_steps) = offset_square(one_step, 0, rank_step) {
+                        if self.squares[two_steps as usize].is_none() {
+                            push(two_steps);
+                        }
+                    }
+                }
+            }
+        }
+
+        for file_step in [-1, 1] {
+            let Some(target) = offset_square(square, file_step, rank_step) else {
+                continue;
+            };
+
+            let captures_piece = matches!(
+                self.squares[target as usize],
+                Some(piece) if piece.color != color
+            );
+
+            if captures_piece || self.en_passant == Some(target) {
+                push(target);
+            }
+        }
+    }
+
+    fn step_moves(&self, square: Square, offsets: &[(i8, i8)], moves: &mut Vec<ChessMove>) {
+        for (file_step, rank_step) in offsets {
+            let Some(target) = offset_square(square, *file_step, *rank_step) else {
+                continue;
+            };
+
+            match self.squares[target as usize] {
+                Some(piece) if piece.color == self.turn => {},
+                _ => moves.push(ChessMove { from: square, to: target, promotion: None })
+            }
+        }
+    }
+
+    fn slide_moves(&self, square: Square, directions: &[(i8, i8)], moves: &mut Vec<ChessMove>) {
+        for (file_step, rank_step) in directions {
+            let mut current = square;
+
+            while let Some(target) = offset_square(current, *file_step, *rank_step) {
+                match self.squares[target as usize] {
+                    None => moves.push(ChessMove { from: square, to: target, promotion: None }),
+                    Some(piece) => {
+                        if piece.color != self.turn {
+                            moves.push(ChessMove { from: square, to: target, promotion: None });
+                        }
+                        break;
+                    }
+                }
+                current = target;
+            }
+        }
+    }
+
+    fn castling_moves(&self, square: Square, moves: &mut Vec<ChessMove>) {
+        let color = self.turn;
+        let (king_start, king_side, queen_side) = match color {
+            PieceColor::White => (4, self.castling.white_king_side, self.castling.white_queen_side),
+            PieceColor::Black => (60, self.castling.black_king_side, self.castling.black_queen_side)
+        };
+
+        if square != king_start || self.is_square_attacked(king_start, color.opposite()) {
+            return;
+        }
+
+        let is_empty = |square: Square| self.squares[square as usize].is_none();
+        let is_safe = |square: Square| !self.is_square_attacked(square, color.opposite());
+
+        if king_side
+            && is_empty(king_start + 1)
+            && is_empty(king_start + 2)
+            && is_safe(king_start + 1)
+            && is_safe(king_start + 2)
+        {
+            moves.push(ChessMove { from: king_start, to: king_start + 2, promotion: None });
+        }
+
+        if queen_side
+            && is_empty(king_start - 1)
+            && is_empty(king_start - 2)
+            && is_empty(king_start - 3)
+            && is_safe(king_start - 1)
+            && is_safe(king_start - 2)
+        {
+            moves.push(ChessMove { from: king_start, to: king_start - 2, promotion: None });
+        }
+    }
+
+    // Applies a move that is already known to be pseudo legal
+    fn apply_move(&mut self, chess_move: &ChessMove) {
+        let from = chess_move.from as usize;
+        let to = chess_move.to as usize;
+
+        let Some(mut piece) = self.squares[from].take() else {
+            return;
+        };
+
+        let mut irreversible = piece.kind == PieceKind::Pawn || self.squares[to].is_some();
+
+        if piece.kind == PieceKind::Pawn && self.en_passant == Some(chess_move.to) && self.squares[to].is_none() {
+            // The captured pawn is behind the target square
+            let captured = if piece.color == PieceColor::White { to - 8 } else { to + 8 };
+            self.squares[captured] = None;
+            irreversible = true;
+        }
+
+        if piece.kind == PieceKind::King && from.abs_diff(to) == 2 {
+            let (rook_from, rook_to) = if to > from { (from + 3, from + 1) } else { (from - 4, from - 1) };
+            self.squares[rook_to] = self.squares[rook_from].take();
+        }
+
+        if let Some(promotion) = chess_move.promotion {
+            piece.kind = promotion;
+        }
+
+        self.en_passant = if piece.kind == PieceKind::Pawn && from.abs_diff(to) == 16 {
+            Some(((from + to) / 2) as Square)
+        } else {
+            None
+        };
+
+        self.squares[to] = Some(piece);
+        self.update_castling_rights(from, to);
+
+        self.halfmove_clock = if irreversible { 0 } else { self.halfmove_clock + 1 };
+
+        if self.turn == PieceColor::Black {
+            self.fullmove_number += 1;
+        }
+
+        self.turn = self.turn.opposite();
+    }
+
+    fn update_castling_rights(&mut self, from: usize, to: usize) {
+        for square in [from, to] {
+            match square {
+                0 => self.castling.white_queen_side = false,
+                4 => {
+                    self.castling.white_king_side = false;
+                    self.castling.white_queen_side = false;
+                },
+                7 => self.castling.white_king_side = false,
+                56 => self.castling.black_queen_side = false,
+                60 => {
+                    self.castling.black_king_side = false;
+                    self.castling.black_queen_side = false;
+                },
+                63 => self.castling.black_king_side = false,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn offset_square(square: Square, file_step: i8, rank_step: i8) -> Option<Square> {
+    let file = (square % 8) as i8 + file_step;
+    let rank = (square / 8) as i8 + rank_step;
+
+    if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+        return None;
+    }
+
+    Some((rank * 8 + file) as Square)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+    fn square(name: &str) -> Square {
+        let bytes = name.as_bytes();
+
+        (bytes[1] - b'1') * 8 + (bytes[0] - b'a')
+    }
+
+    fn chess_move(from: &str, to: &str) -> ChessMove {
+        ChessMove { from: square(from), to: square(to), promotion: None }
+    }
+
+    // Board of a position in Forsyth-Edwards notation
+    fn from_fen(fen: &str) -> ChessBoard {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        let mut squares = [None; 64];
+
+        for (row, pieces) in fields[0].split('/').enumerate() {
+            let mut file = 0;
+
+            for symbol in pieces.chars() {
+                if let Some(empty) = symbol.to_digit(10) {
+                    file += empty as usize;
+                    continue;
+                }
+
+                let kind = match symbol.to_ascii_lowercase() {
+                    'p' => PieceKind::Pawn,
+                    'n' => PieceKind::Knight,
+                    'b' => PieceKind::Bishop,
+                    'r' => PieceKind::Rook,
+                    'q' => PieceKind::Queen,
+                    _ => PieceKind::King
+                };
+                let color = if symbol.is_ascii_uppercase() { PieceColor::White } else { PieceColor::Black };
+
+                squares[(7 - row) * 8 + file] = Some(Piece { kind, color });
+                file += 1;
+            }
+        }
+
+        let mut board = ChessBoard {
+            squares,
+            turn: if fields[1] == "w" { PieceColor::White } else { PieceColor::Black },
+            castling: CastlingRights {
+                white_king_side: fields[2].contains('K'),
+                white_queen_side: fields[2].contains('Q'),
+                black_king_side: fields[2].contains('k'),
+                black_queen_side: fields[2].contains('q')
+            },
+            en_passant: (fields[3] != "-").then(|| square(fields[3])),
+            halfmove_clock: fields[4].parse().unwrap(),
+            fullmove_number: fields[5].parse().unwrap(),
+            position_keys: Vec::new()
+        };
+
+        board.position_keys.push(board.position_key());
+
+        board
+    }
+
+    // Number of leaf positions of the tree of legal moves
+    fn perft(board: &ChessBoard, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        board.legal_moves()
+            .iter()
+            .map(|chess_move| {
+                let mut next = board.clone();
+                next.apply_move(chess_move);
+                perft(&next, depth - 1)
+            })
+            .sum()
+    }
+
+    fn play_all(board: &mut ChessBoard, moves: &[(&str, &str)]) -> BoardStatus {
+        let mut status = board.status();
+
+        for (from, to) in moves {
+            status = board.play(chess_move(from, to)).unwrap();
+        }
+
+        status
+    }
+
+    #[test]
+    fn perft_of_the_starting_position() {
+        let board = ChessBoard::default();
+
+        assert_eq!(perft(&board, 1), 20);
+        assert_eq!(perft(&board, 2), 400);
+        assert_eq!(perft(&board, 3), 8_902);
+        assert_eq!(perft(&board, 4), 197_281);
+    }
+
+    #[test]
+    fn perft_of_kiwipete() {
+        let board = from_fen(KIWIPETE);
+
+        assert_eq!(perft(&board, 1), 48);
+        assert_eq!(perft(&board, 2), 2_039);
+        assert_eq!(perft(&board, 3), 97_862);
+    }
+
+    #[test]
+    fn perft_of_endgames_with_en_passant_and_promotions() {
+        assert_eq!(perft(&from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1"), 3), 2_812);
+        assert_eq!(perft(&from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1"), 2), 264);
+    }
+
+    #[test]
+    fn illegal_moves_leave_the_board_untouched() {
+        let mut board = ChessBoard::default();
+
+        assert_eq!(board.play(chess_move("e2", "e5")), Err(chess_move("e2", "e5")));
+        // Black pieces can't move on the turn of white
+        assert!(board.play(chess_move("e7", "e5")).is_err());
+        assert_eq!(board, ChessBoard::default());
+    }
+
+    #[test]
+    fn castling_moves_the_rook_and_drops_the_rights() {
+        let mut board = from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+
+        board.play(chess_move("e1", "g1")).unwrap();
+        assert_eq!(board.piece_at(square("f1")), Some(Piece { kind: PieceKind::Rook, color: PieceColor::White }));
+        assert_eq!(board.piece_at(square("h1")), None);
+        assert!(!board.castling.white_king_side && !board.castling.white_queen_side);
+
+        board.play(chess_move("e8", "c8")).unwrap();
+        assert_eq!(board.piece_at(square("d8")), Some(Piece { kind: PieceKind::Rook, color: PieceColor::Black }));
+        assert_eq!(board.piece_at(square("a8")), None);
+    }
+
+    #[test]
+    fn castling_through_or_out_of_check_is_illegal() {
+        // The rook of f8 attacks f1, the square the king passes through
+        let through_check = from_fen("5r1k/8/8/8/8/8/8/R3K2R w KQ - 0 1");
+        assert!(!through_check.legal_moves().contains(&chess_move("e1", "g1")));
+        assert!(through_check.legal_moves().contains(&chess_move("e1", "c1")));
+
+        // The rook of e8 gives check
+        let out_of_check = from_fen("4r2k/8/8/8/8/8/8/R3K2R w KQ - 0 1");
+        assert!(!out_of_check.legal_moves().contains(&chess_move("e1", "g1")));
+        assert!(!out_of_check.legal_moves().contains(&chess_move("e1", "c1")));
+
+        // Only the squares the king crosses must be safe, b1 can be attacked
+        let rook_square_attacked = from_fen("1r5k/8/8/8/8/8/8/R3K2R w KQ - 0 1");
+        assert!(rook_square_attacked.legal_moves().contains(&chess_move("e1", "c1")));
+    }
+
+    #[test]
+    fn en_passant_captures_the_pawn_behind_the_target_square() {
+        let mut board = ChessBoard::default();
+        play_all(&mut board, &[("e2", "e4"), ("a7", "a6"), ("e4", "e5"), ("d7", "d5")]);
+
+        assert_eq!(board.en_passant, Some(square("d6")));
+
+        board.play(chess_move("e5", "d6")).unwrap();
+        assert_eq!(board.piece_at(square("d5")), None);
+        assert_eq!(board.piece_at(square("d6")), Some(Piece { kind: PieceKind::Pawn, color: PieceColor::White }));
+        assert_eq!(board.halfmove_clock, 0);
+    }
+
+    #[test]
+    fn en_passant_is_only_possible_right_after_the_double_step() {
+        let mut board = ChessBoard::default();
+        play_all(&mut board, &[("e2", "e4"), ("a7", "a6"), ("e4", "e5"), ("d7", "d5"), ("h2", "h3"), ("h7", "h6")]);
+
+        assert!(board.play(chess_move("e5", "d6")).is_err());
+    }
+
+    #[test]
+    fn pinned_en_passant_capture_is_illegal() {
+        // Capturing on c6 removes both pawns of the fifth rank and leaves
+        // the king of a5 in check of the rook of h5
+        let board = from_fen("8/8/8/KPp4r/8/8/8/7k w - c6 0 1");
+
+        assert!(!board.legal_moves().contains(&chess_move("b5", "c6")));
+        assert!(board.legal_moves().contains(&chess_move("b5", "b6")));
+    }
+
+    #[test]
+    fn pawns_promote_to_the_chosen_piece() {
+        let mut board = from_fen("7k/P7/8/8/8/8/8/K7 w - - 0 1");
+
+        // A pawn on the last rank must choose a piece
+        assert!(board.play(chess_move("a7", "a8")).is_err());
+
+        let under_promotion = ChessMove { promotion: Some(PieceKind::Knight), ..chess_move("a7", "a8") };
+        board.play(under_promotion).unwrap();
+
+        assert_eq!(board.piece_at(square("a8")), Some(Piece { kind: PieceKind::Knight, color: PieceColor::White }));
+    }
+
+    #[test]
+    fn checkmate_is_won_by_the_side_that_gave_it() {
+        let mut board = ChessBoard::default();
+        let status = play_all(&mut board, &[("f2", "f3"), ("e7", "e5"), ("g2", "g4"), ("d8", "h4")]);
+
+        assert_eq!(status, BoardStatus::Checkmate { winner: PieceColor::Black });
+        assert!(board.legal_moves().is_empty());
+    }
+
+    #[test]
+    fn check_with_a_way_out_is_not_checkmate() {
+        let mut board = ChessBoard::default();
+        let status = play_all(&mut board, &[("e2", "e4"), ("f7", "f6"), ("d1", "h5")]);
+
+        assert_eq!(status, BoardStatus::Check);
+        assert!(!status.is_game_over());
+    }
+
+    #[test]
+    fn no_legal_moves_without_check_is_stalemate() {
+        let mut board = from_fen("k7/8/3Q4/8/8/8/8/7K w - - 0 1");
+        let status = board.play(chess_move("d6", "b6")).unwrap();
+
+        assert_eq!(status, BoardStatus::Stalemate);
+        assert!(!board.is_in_check(PieceColor::Black));
+    }
+
+    #[test]
+    fn insufficient_material_is_detected() {
+        assert!(from_fen("k7/8/8/8/8/8/8/7K w - - 0 1").has_insufficient_material());
+        assert!(from_fen("k7/8/8/8/8/8/8/5N1K w - - 0 1").has_insufficient_material());
+        // Bishops on squares of the same color
+        assert!(from_fen("k1b5/8/8/8/8/8/8/5B1K w - - 0 1").has_insufficient_material());
+        assert!(!from_fen("kb6/8/8/8/8/8/8/5B1K w - - 0 1").has_insufficient_material());
+        assert!(!from_fen("kn6/8/8/8/8/8/8/5N1K w - - 0 1").has_insufficient_material());
+        assert!(!from_fen("k7/8/8/8/8/8/P7/7K w - - 0 1").has_insufficient_material());
+    }
+}
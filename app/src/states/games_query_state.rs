@@ -0,0 +1,266 @@
+use core::ops::Bound;
+
+use sails_rs::prelude::*;
+
+use super::bet2chess_state::{
+    BetAmout,
+    ChessState,
+    GameData,
+    GameId,
+    GameStatusKind,
+    UserWeb2Id
+};
+
+// Maximum number of games or game ids returned by each page
+pub const MAX_GAMES_PAGE_SIZE: u32 = 100;
+
+// Every field that is set has to match, an empty filter matches every game
+#[derive(Encode, Decode, TypeInfo, Clone, Default)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GamesFilter {
+    pub status: Option<GameStatusKind>,
+    // Address of any of the players
+    pub player: Option<ActorId>,
+    // Web2 id of any of the players
+    pub player_web2_id: Option<UserWeb2Id>,
+    // Bounds of the stake of player1, the one that created the game
+    pub min_bet: Option<BetAmout>,
+    pub max_bet: Option<BetAmout>,
+    pub created_from_block: Option<u32>,
+    pub created_to_block: Option<u32>
+}
+
+impl GamesFilter {
+    pub fn matches(&self, game_data: &GameData) -> bool {
+        self.status.is_none_or(|status| game_data.status.kind() == status)
+            && self.player.is_none_or(|player| {
+                game_data.player1 == player || game_data.player2 == player
+            })
+            && self.player_web2_id.is_none_or(|web2_id| {
+                game_data.player1_web2_id == web2_id || game_data.player2_web2_id == web2_id
+            })
+            && self.min_bet.is_none_or(|min_bet| game_data.game_bet >= min_bet)
+            && self.max_bet.is_none_or(|max_bet| game_data.game_bet <= max_bet)
+            && self.created_from_block.is_none_or(|block| game_data.created_at_block >= block)
+            && self.created_to_block.is_none_or(|block| game_data.created_at_block <= block)
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GamesPage {
+    pub games: Vec<(GameId, GameData)>,
+    // Cursor to get the next page, `None` if there are no more games
+    pub next_cursor: Option<GameId>
+}
+
+impl ChessState {
+    // Games that match the filter sorted by id, starting after the cursor
+    pub fn games_page(
+        &self,
+        cursor: Option<GameId>,
+        offset: u32,
+        limit: u32,
+        filter: &GamesFilter
+    ) -> GamesPage {
+        let limit = limit.min(MAX_GAMES_PAGE_SIZE) as usize;
+        let start = cursor.map_or(Bound::Unbounded, Bound::Excluded);
+
+        // With a status the search only goes through the games of that status
+        let games_id: Box<dyn Iterator<Item = GameId> + '_> = match filter.status {
+            Some(status) => match self.games_by_status.get(&status) {
+                Some(games_id) => Box::new(games_id.range((start, Bound::Unbounded)).copied()),
+                None => Box::new(core::iter::empty())
+            },
+            None => Box::new(
+                self.games_by_id
+                    .range((start, Bound::Unbounded))
+                    .map(|(game_id, _)| *game_id)
+            )
+        };
+
+        // One more game is taken to know if there is a next page
+        let mut games: Vec<(GameId, GameData)> = games_id
+            .filter_map(|game_id| self.games_by_id.get(&game_id).map(|game_data| (game_id, game_data)))
+            .filter(|(_, game_data)| filter.matches(game_data))
+            .skip(offset as usize)
+            .take(limit + 1)
+            .map(|(game_id, game_data)| (game_id, game_data.clone()))
+            .collect();
+
+        let next_cursor = if games.len() > limit {
+            games.truncate(limit);
+            games.last().map(|(game_id, _)| *game_id)
+        } else {
+            None
+        };
+
+        GamesPage {
+            games,
+            next_cursor
+        }
+    }
+
+    pub fn games_id_page(games_id: impl Iterator<Item = GameId>, offset: u32, limit: u32) -> Vec<GameId> {
+        games_id
+            .skip(offset as usize)
+            .take(limit.min(MAX_GAMES_PAGE_SIZE) as usize)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        runtime::mock,
+        test_utils::{
+            address,
+            new_state,
+            start_game,
+            STAKE
+        }
+    };
+
+    // 1: 10 against 11 that 11 resigns, created at block 10 with `STAKE`
+    // 2: open game of 12 at block 20 with `2 * STAKE`
+    // 3: 10 against 12 at block 30 with `3 * STAKE`
+    // 4: open game of 11 at block 40 with `STAKE`
+    fn state_with_games() -> ChessState {
+        let mut state = new_state();
+
+        mock::set_block_height(10);
+        let ended = start_game(&mut state, 10, 11, (STAKE, STAKE));
+        state.resign(address(11), ended).unwrap();
+
+        mock::set_block_height(20);
+        state.create_open_game(address(12), "player12".into(), 12, 2 * STAKE, None).unwrap();
+
+        mock::set_block_height(30);
+        start_game(&mut state, 10, 12, (3 * STAKE, 3 * STAKE));
+
+        mock::set_block_height(40);
+        state.create_open_game(address(11), "player11".into(), 11, STAKE, None).unwrap();
+
+        state
+    }
+
+    fn matching(state: &ChessState, filter: GamesFilter) -> Vec<GameId> {
+        state
+            .games_page(None, 0, MAX_GAMES_PAGE_SIZE, &filter)
+            .games
+            .into_iter()
+            .map(|(game_id, _)| game_id)
+            .collect()
+    }
+
+    #[test]
+    fn empty_filter_matches_every_game() {
+        let state = state_with_games();
+
+        assert_eq!(matching(&state, GamesFilter::default()), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn games_are_filtered_by_status() {
+        let state = state_with_games();
+        let with_status = |status| GamesFilter { status: Some(status), ..Default::default() };
+
+        assert_eq!(matching(&state, with_status(GameStatusKind::Waiting)), [2, 4]);
+        assert_eq!(matching(&state, with_status(GameStatusKind::Started)), [3]);
+        assert_eq!(matching(&state, with_status(GameStatusKind::Ended)), [1]);
+        assert!(matching(&state, with_status(GameStatusKind::Cancelled)).is_empty());
+    }
+
+    #[test]
+    fn games_are_filtered_by_any_of_the_players() {
+        let state = state_with_games();
+
+        assert_eq!(matching(&state, GamesFilter { player: Some(address(10)), ..Default::default() }), [1, 3]);
+        assert_eq!(matching(&state, GamesFilter { player: Some(address(11)), ..Default::default() }), [1, 4]);
+        assert_eq!(matching(&state, GamesFilter { player_web2_id: Some(12), ..Default::default() }), [2, 3]);
+    }
+
+    #[test]
+    fn games_are_filtered_by_the_stake_of_the_creator() {
+        let state = state_with_games();
+
+        assert_eq!(matching(&state, GamesFilter { min_bet: Some(2 * STAKE), ..Default::default() }), [2, 3]);
+        assert_eq!(matching(&state, GamesFilter { max_bet: Some(2 * STAKE), ..Default::default() }), [1, 2, 4]);
+        assert_eq!(matching(&state, GamesFilter { min_bet: Some(STAKE), max_bet: Some(STAKE), ..Default::default() }), [1, 4]);
+    }
+
+    #[test]
+    fn games_are_filtered_by_the_block_they_were_created() {
+        let state = state_with_games();
+
+        assert_eq!(matching(&state, GamesFilter { created_from_block: Some(20), created_to_block: Some(30), ..Default::default() }), [2, 3]);
+        assert_eq!(matching(&state, GamesFilter { created_from_block: Some(31), ..Default::default() }), [4]);
+        assert_eq!(matching(&state, GamesFilter { created_to_block: Some(10), ..Default::default() }), [1]);
+    }
+
+    #[test]
+    fn every_field_of_the_filter_has_to_match() {
+        let state = state_with_games();
+        let filter = GamesFilter {
+            status: Some(GameStatusKind::Waiting),
+            player: Some(address(11)),
+            ..Default::default()
+        };
+
+        assert_eq!(matching(&state, filter), [4]);
+    }
+
+    #[test]
+    fn cursor_skips_the_games_that_dont_match() {
+        let state = state_with_games();
+        let filter = GamesFilter { player: Some(address(10)), ..Default::default() };
+
+        let first = state.games_page(None, 0, 1, &filter);
+        assert_eq!(first.games.iter().map(|(game_id, _)| *game_id).collect::<Vec<_>>(), [1]);
+        assert_eq!(first.next_cursor, Some(1));
+
+        // Game 2 doesn't match, so the next page starts at game 3
+        let second = state.games_page(first.next_cursor, 0, 1, &filter);
+        assert_eq!(second.games.iter().map(|(game_id, _)| *game_id).collect::<Vec<_>>(), [3]);
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[test]
+    fn cursor_of_a_status_goes_through_the_games_of_that_status() {
+        let state = state_with_games();
+        let filter = GamesFilter { status: Some(GameStatusKind::Waiting), ..Default::default() };
+
+        let first = state.games_page(None, 0, 1, &filter);
+        assert_eq!(first.next_cursor, Some(2));
+
+        let second = state.games_page(first.next_cursor, 0, 1, &filter);
+        assert_eq!(second.games[0].0, 4);
+        assert_eq!(second.next_cursor, None);
+
+        assert_eq!(state.games_page(None, 1, 10, &filter).games[0].0, 4);
+    }
+
+    #[test]
+    fn pages_are_capped_at_the_maximum_size() {
+        let mut state = new_state();
+
+        for player in 0..150 {
+            state.create_open_game(address(player), "player".into(), player, STAKE, None).unwrap();
+        }
+
+        let first = state.games_page(None, 0, 1_000, &GamesFilter::default());
+        assert_eq!(first.games.len(), MAX_GAMES_PAGE_SIZE as usize);
+        assert_eq!(first.next_cursor, Some(100));
+
+        let second = state.games_page(first.next_cursor, 0, 1_000, &GamesFilter::default());
+        assert_eq!(second.games.len(), 50);
+        assert_eq!(second.games[0].0, 101);
+        assert_eq!(second.next_cursor, None);
+
+        assert_eq!(ChessState::games_id_page(1..=150, 0, 1_000).len(), MAX_GAMES_PAGE_SIZE as usize);
+        assert_eq!(ChessState::games_id_page(1..=150, 140, 20), (141..=150).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,213 @@
+use core::cmp::Reverse;
+
+use sails_rs::{
+    prelude::*,
+    collections::{
+        BTreeMap,
+        BTreeSet
+    }
+};
+
+use super::bet2chess_state::{
+    ChessState,
+    UserWeb2Id
+};
+
+// Maximum number of entries returned by each leaderboard page
+pub const MAX_LEADERBOARD_PAGE_SIZE: u32 = 100;
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum LeaderboardKind {
+    Rating,
+    // Prizes received minus bets wagered
+    NetWinnings,
+    GamesWon
+}
+
+impl LeaderboardKind {
+    pub const ALL: [LeaderboardKind; 3] = [
+        LeaderboardKind::Rating,
+        LeaderboardKind::NetWinnings,
+        LeaderboardKind::GamesWon
+    ];
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct LeaderboardEntry {
+    // Position in the leaderboard, starting at one
+    pub position: u32,
+    pub web2_id: UserWeb2Id,
+    pub score: i128
+}
+
+#[derive(Default)]
+pub struct Leaderboards {
+    // Players of each leaderboard from the highest to the lowest score,
+    // players with the same score are sorted by web2 id
+    pub rankings: BTreeMap<LeaderboardKind, BTreeSet<(Reverse<i128>, UserWeb2Id)>>
+}
+
+impl ChessState {
+    pub fn leaderboard(&self, kind: LeaderboardKind, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+        let Some(ranking) = self.leaderboards.rankings.get(&kind) else {
+            return Vec::new();
+        };
+
+        ranking
+            .iter()
+            .enumerate()
+            .skip(offset as usize)
+            .take(limit.min(MAX_LEADERBOARD_PAGE_SIZE) as usize)
+            .map(|(index, (Reverse(score), web2_id))| LeaderboardEntry {
+                position: index as u32 + 1,
+                web2_id: *web2_id,
+                score: *score
+            })
+            .collect()
+    }
+
+    pub fn leaderboard_score(&self, kind: LeaderboardKind, web2_id: UserWeb2Id) -> i128 {
+        let stats = self.player_stats(web2_id);
+
+        match kind {
+            LeaderboardKind::Rating => self.player_rating(web2_id) as i128,
+            LeaderboardKind::NetWinnings => stats.total_won as i128 - stats.total_wagered as i128,
+            LeaderboardKind::GamesWon => stats.wins as i128
+        }
+    }
+
+    // Has to be called before the rating or the stats of the player change,
+    // the entries are found with the current scores
+    pub(crate) fn remove_from_leaderboards(&mut self, web2_id: UserWeb2Id) {
+        for kind in LeaderboardKind::ALL {
+            let score = self.leaderboard_score(kind, web2_id);
+
+            if let Some(ranking) = self.leaderboards.rankings.get_mut(&kind) {
+                ranking.remove(&(Reverse(score), web2_id));
+            }
+        }
+    }
+
+    pub(crate) fn add_to_leaderboards(&mut self, web2_id: UserWeb2Id) {
+        for kind in LeaderboardKind::ALL {
+            let score = self.leaderboard_score(kind, web2_id);
+
+            self.leaderboards
+                .rankings
+                .entry(kind)
+                .or_default()
+                .insert((Reverse(score), web2_id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::test_utils::{
+        address,
+        new_state,
+        start_game,
+        ADMIN,
+        STAKE
+    };
+
+    fn play(state: &mut ChessState, winner: u64, loser: u64, stake: u128) {
+        let game_id = start_game(state, winner, loser, (stake, stake));
+        state.end_match(game_id, address(ADMIN), Some(address(winner))).unwrap();
+    }
+
+    // 10 wins both of its games, 11 loses both and 12 wins once, so 10 and
+    // 12 end with the same net winnings
+    fn state_with_results() -> ChessState {
+        let mut state = new_state();
+
+        play(&mut state, 10, 11, STAKE);
+        play(&mut state, 10, 12, STAKE);
+        play(&mut state, 12, 11, 3 * STAKE);
+
+        state
+    }
+
+    fn ranking(state: &ChessState, kind: LeaderboardKind) -> Vec<(u32, UserWeb2Id, i128)> {
+        state
+            .leaderboard(kind, 0, MAX_LEADERBOARD_PAGE_SIZE)
+            .into_iter()
+            .map(|entry| (entry.position, entry.web2_id, entry.score))
+            .collect()
+    }
+
+    #[test]
+    fn players_keep_a_single_entry_with_their_current_score() {
+        let state = state_with_results();
+
+        for kind in LeaderboardKind::ALL {
+            let entries = ranking(&state, kind);
+
+            assert_eq!(entries.len(), 3);
+
+            for (_, web2_id, score) in entries {
+                assert_eq!(score, state.leaderboard_score(kind, web2_id));
+            }
+        }
+    }
+
+    #[test]
+    fn rating_leaderboard_goes_from_the_highest_rating() {
+        let state = state_with_results();
+        let entries = ranking(&state, LeaderboardKind::Rating);
+
+        assert_eq!(entries.iter().map(|(_, web2_id, _)| *web2_id).collect::<Vec<_>>(), [10, 12, 11]);
+        assert!(entries.windows(2).all(|pair| pair[0].2 >= pair[1].2));
+        assert_eq!(entries[0].2, state.player_rating(10) as i128);
+    }
+
+    #[test]
+    fn net_winnings_leaderboard_breaks_ties_by_web2_id() {
+        let state = state_with_results();
+        let stake = STAKE as i128;
+
+        assert_eq!(ranking(&state, LeaderboardKind::NetWinnings), [
+            (1, 10, 2 * stake),
+            (2, 12, 2 * stake),
+            (3, 11, -4 * stake)
+        ]);
+    }
+
+    #[test]
+    fn games_won_leaderboard_counts_the_wins() {
+        let state = state_with_results();
+
+        assert_eq!(ranking(&state, LeaderboardKind::GamesWon), [(1, 10, 2), (2, 12, 1), (3, 11, 0)]);
+    }
+
+    #[test]
+    fn pages_continue_the_positions_and_are_capped() {
+        let mut state = new_state();
+
+        for web2_id in 0..150 {
+            state.add_to_leaderboards(web2_id);
+        }
+
+        let page = state.leaderboard(LeaderboardKind::GamesWon, 140, MAX_LEADERBOARD_PAGE_SIZE);
+        assert_eq!(page.len(), 10);
+        assert_eq!((page[0].position, page[0].web2_id), (141, 140));
+
+        assert_eq!(state.leaderboard(LeaderboardKind::GamesWon, 0, 1_000).len(), MAX_LEADERBOARD_PAGE_SIZE as usize);
+        assert_eq!(state.leaderboard(LeaderboardKind::GamesWon, 10, 5).len(), 5);
+        assert!(state.leaderboard(LeaderboardKind::GamesWon, 10, 0).is_empty());
+        assert!(state.leaderboard(LeaderboardKind::GamesWon, 150, 10).is_empty());
+        assert!(state.leaderboard(LeaderboardKind::GamesWon, u32::MAX, u32::MAX).is_empty());
+    }
+
+    #[test]
+    fn leaderboards_without_players_are_empty() {
+        let state = new_state();
+
+        assert!(state.leaderboard(LeaderboardKind::Rating, 0, 10).is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+
+use super::runtime::msg;
+
+use crate::services::bet2chess_service::Bet2ChessEvents;
+
+use super::bet2chess_state::{
+    Bet2ChessErrors,
+    ChessState
+};
+
+// Prizes and refunds are credited here and each user withdraws them, so a
+// recipient that can't receive value never blocks a settlement
+#[derive(Default)]
+pub struct Ledger {
+    // Balances in planck
+    pub balances: BTreeMap<ActorId, u128>,
+    // Sum of all the balances, value the contract owes to its users
+    pub total_balance: u128,
+    // Balances of the stakes in tokens, by token program and address
+    pub token_balances: BTreeMap<(ActorId, ActorId), u128>
+}
+
+impl ChessState {
+    pub fn balance(&self, address: ActorId) -> u128 {
+        self.ledger
+            .balances
+            .get(&address)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn withdraw(&mut self, address: ActorId, amount: u128) -> Result<(), Bet2ChessErrors> {
+        let balance = self.balance(address);
+
+        if amount == 0 || amount > balance {
+            return Err(Bet2ChessErrors::InsufficientBalance(balance));
+        }
+
+        self.debit(address, amount);
+
+        // If the message can't be sent the balance is kept
+        let result = msg::send(address, Bet2ChessEvents::Withdrawn(amount), amount);
+
+        if result.is_err() {
+            self.credit(address, amount);
+            return Err(Bet2ChessErrors::CantSendWithdrawal);
+        }
+
+        Ok(())
+    }
+
+    pub fn token_balance(&self, token: ActorId, address: ActorId) -> u128 {
+        self.ledger
+            .token_balances
+            .get(&(token, address))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    // Credits the amount in the currency of a stake
+    pub(crate) fn credit_stake(&mut self, address: ActorId, token: Option<ActorId>, amount: u128) {
+        match token {
+            None => self.credit(address, amount),
+            Some(token) => self.credit_token(token, address, amount)
+        }
+    }
+
+    pub(crate) fn credit_token(&mut self, token: ActorId, address: ActorId, amount: u128) {
+        if amount == 0 {
+            return;
+        }
+
+        *self.ledger.token_balances.entry((token, address)).or_default() += amount;
+    }
+
+    pub(crate) fn debit_token(&mut self, token: ActorId, address: ActorId, amount: u128) -> Result<(), Bet2ChessErrors> {
+        let balance = self.token_balance(token, address);
+
+        if amount == 0 || amount > balance {
+            return Err(Bet2ChessErrors::InsufficientBalance(balance));
+        }
+
+        if balance == amount {
+            self.ledger.token_balances.remove(&(token, address));
+        } else {
+            self.ledger.token_balances.insert((token, address), balance - amount);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn credit(&mut self, address: ActorId, amount: u128) {
+        if amount == 0 {
+            return;
+        }
+
+        *self.ledger.balances.entry(address).or_default() += amount;
+        self.ledger.total_balance += amount;
+    }
+
+    fn debit(&mut self, address: ActorId, amount: u128) {
+        let Some(balance) = self.ledger.balances.get_mut(&address) else {
+            return;
+        };
+
+        *balance -= amount;
+        self.ledger.total_balance -= amount;
+
+        if *balance == 0 {
+            self.ledger.balances.remove(&address);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        runtime::mock,
+        test_utils::{
+            address,
+            new_state,
+            STAKE
+        }
+    };
+
+    const PLAYER: u64 = 10;
+    const TOKEN: u64 = 500;
+
+    fn state_with_balance(amount: u128) -> ChessState {
+        let mut state = new_state();
+        state.credit(address(PLAYER), amount);
+
+        state
+    }
+
+    #[test]
+    fn withdrawals_debit_the_balance_and_send_the_value() {
+        let mut state = state_with_balance(STAKE);
+
+        state.withdraw(address(PLAYER), STAKE / 4).unwrap();
+
+        assert_eq!(mock::sent_messages(), [(address(PLAYER), STAKE / 4)]);
+        assert_eq!(state.balance(address(PLAYER)), STAKE - STAKE / 4);
+        assert_eq!(state.ledger.total_balance, STAKE - STAKE / 4);
+
+        state.withdraw(address(PLAYER), STAKE - STAKE / 4).unwrap();
+
+        assert!(!state.ledger.balances.contains_key(&address(PLAYER)));
+        assert_eq!(state.ledger.total_balance, 0);
+    }
+
+    #[test]
+    fn withdrawals_bigger_than_the_balance_or_empty_are_rejected() {
+        let mut state = state_with_balance(STAKE);
+
+        assert_eq!(state.withdraw(address(PLAYER), STAKE + 1), Err(Bet2ChessErrors::InsufficientBalance(STAKE)));
+        assert_eq!(state.withdraw(address(PLAYER), 0), Err(Bet2ChessErrors::InsufficientBalance(STAKE)));
+        assert_eq!(state.withdraw(address(PLAYER + 1), 1), Err(Bet2ChessErrors::InsufficientBalance(0)));
+
+        assert_eq!(state.balance(address(PLAYER)), STAKE);
+        assert!(mock::sent_messages().is_empty());
+    }
+
+    #[test]
+    fn balance_is_credited_back_when_the_withdrawal_cant_be_sent() {
+        let mut state = state_with_balance(STAKE);
+        mock::set_messages_fail(true);
+
+        assert_eq!(state.withdraw(address(PLAYER), STAKE), Err(Bet2ChessErrors::CantSendWithdrawal));
+        assert_eq!(state.balance(address(PLAYER)), STAKE);
+        assert_eq!(state.ledger.total_balance, STAKE);
+    }
+
+    #[test]
+    fn credits_add_up_and_zero_credits_are_ignored() {
+        let mut state = new_state();
+
+        state.credit(address(PLAYER), STAKE);
+        state.credit(address(PLAYER), STAKE);
+        state.credit(address(PLAYER + 1), 0);
+
+        assert_eq!(state.balance(address(PLAYER)), 2 * STAKE);
+        assert!(!state.ledger.balances.contains_key(&address(PLAYER + 1)));
+        assert_eq!(state.ledger.total_balance, 2 * STAKE);
+    }
+
+    #[test]
+    fn stakes_are_credited_in_their_currency() {
+        let mut state = new_state();
+
+        state.credit_stake(address(PLAYER), None, STAKE);
+        state.credit_stake(address(PLAYER), Some(address(TOKEN)), 2 * STAKE);
+
+        assert_eq!(state.balance(address(PLAYER)), STAKE);
+        assert_eq!(state.token_balance(address(TOKEN), address(PLAYER)), 2 * STAKE);
+
+        assert_eq!(state.debit_token(address(TOKEN), address(PLAYER), 3 * STAKE), Err(Bet2ChessErrors::InsufficientBalance(2 * STAKE)));
+        state.debit_token(address(TOKEN), address(PLAYER), 2 * STAKE).unwrap();
+        assert!(state.ledger.token_balances.is_empty());
+    }
+}
@@ -0,0 +1,404 @@
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+
+use super::runtime::{
+    msg,
+    exec
+};
+
+use crate::services::bet2chess_service::Bet2ChessEvents;
+
+use super::bet2chess_state::{
+    Bet2ChessErrors,
+    BetAmout,
+    ChessState,
+    GameId,
+    GameStatus,
+    UserWeb2Id
+};
+
+// Players are only paired with players of the same queue
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MatchmakingQueueKey {
+    pub bet_amount: BetAmout,
+    // Seconds per player, `None` for games without a time control. The
+    // queues are matched exactly, `None` only pairs with `None`
+    pub time_control: Option<u32>
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Eq, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RatingRange {
+    pub min: u32,
+    pub max: u32
+}
+
+impl RatingRange {
+    pub fn contains(&self, rating: u32) -> bool {
+        self.min <= rating && rating <= self.max
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MatchmakingEntry {
+    pub address: ActorId,
+    pub username: String,
+    pub web2_id: UserWeb2Id,
+    pub rating: u32,
+    // Ratings of the opponents the player accepts, any rating if `None`
+    pub rating_range: Option<RatingRange>,
+    pub joined_at_block: u32
+}
+
+impl MatchmakingEntry {
+    fn accepts(&self, other: &MatchmakingEntry) -> bool {
+        self.rating_range
+            .is_none_or(|range| range.contains(other.rating))
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum MatchmakingResult {
+    WaitingForOpponent,
+    GameCreated(GameId)
+}
+
+#[derive(Default)]
+pub struct MatchmakingQueues {
+    // Entries of each queue in the order the players joined
+    pub queues: BTreeMap<MatchmakingQueueKey, Vec<MatchmakingEntry>>,
+    // Queue where each player is waiting, a player can only be in one queue
+    pub queue_by_player: BTreeMap<ActorId, MatchmakingQueueKey>
+}
+
+impl ChessState {
+    pub fn join_matchmaking_queue(
+        &mut self,
+        address: ActorId,
+        username: String,
+        web2_id: UserWeb2Id,
+        bet_amount: BetAmout,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>
+    ) -> Result<MatchmakingResult, Bet2ChessErrors> {
+        if self.matchmaking.queue_by_player.contains_key(&address) {
+            return Err(self.refund_bet(address, bet_amount, None, Bet2ChessErrors::PlayerAlreadyInMatchmakingQueue));
+        }
+
+        if rating_range.is_some_and(|range| range.min > range.max) {
+            return Err(self.refund_bet(address, bet_amount, None, Bet2ChessErrors::InvalidRatingRange));
+        }
+
+        let key = MatchmakingQueueKey {
+            bet_amount,
+            time_control
+        };
+
+        let entry = MatchmakingEntry {
+            address,
+            username,
+            web2_id,
+            rating: self.player_rating(web2_id),
+            rating_range,
+            joined_at_block: exec::block_height()
+        };
+
+        let queue = self.matchmaking.queues
+            .entry(key)
+            .or_default();
+
+        // The first player in the queue that accepts the new player and
+        // is accepted by them is the opponent
+        let opponent = queue
+            .iter()
+            .find(|waiting| {
+                waiting.web2_id != web2_id
+                    && waiting.accepts(&entry)
+                    && entry.accepts(waiting)
+            })
+            .cloned();
+
+        let Some(opponent) = opponent else {
+            queue.push(entry);
+            self.matchmaking.queue_by_player.insert(address, key);
+
+            return Ok(MatchmakingResult::WaitingForOpponent);
+        };
+
+        // The game is created before the opponent leaves the queue, if it
+        // can't be created the opponent keeps waiting and the new player
+        // gets the stake back
+        let game_id = match self.create_matchmaking_game(&opponent, entry, key) {
+            Ok(game_id) => game_id,
+            Err(error) => return Err(self.refund_bet(address, bet_amount, None, error))
+        };
+
+        if let Some(queue) = self.matchmaking.queues.get_mut(&key) {
+            queue.retain(|waiting| waiting.address != opponent.address);
+
+            if queue.is_empty() {
+                self.matchmaking.queues.remove(&key);
+            }
+        }
+
+        self.matchmaking.queue_by_player.remove(&opponent.address);
+
+        // The game exists even if the waiting player can't be notified
+        let _ = msg::send(opponent.address, Bet2ChessEvents::GameCreated(game_id), 0);
+
+        Ok(MatchmakingResult::GameCreated(game_id))
+    }
+
+    pub fn leave_matchmaking_queue(&mut self, address: ActorId) -> Result<(), Bet2ChessErrors> {
+        let key = self.matchmaking.queue_by_player
+            .remove(&address)
+            .ok_or(Bet2ChessErrors::PlayerIsNotInMatchmakingQueue)?;
+
+        if let Some(queue) = self.matchmaking.queues.get_mut(&key) {
+            queue.retain(|entry| entry.address != address);
+
+            if queue.is_empty() {
+                self.matchmaking.queues.remove(&key);
+            }
+        }
+
+        self.credit(address, key.bet_amount);
+
+        Ok(())
+    }
+
+    // The player that was waiting creates the game, so they play with white pieces
+    fn create_matchmaking_game(
+        &mut self,
+        waiting: &MatchmakingEntry,
+        entry: MatchmakingEntry,
+        key: MatchmakingQueueKey
+    ) -> Result<GameId, Bet2ChessErrors> {
+        let game_id = self.create_match(
+            waiting.address,
+            waiting.username.clone(),
+            waiting.web2_id,
+            key.bet_amount,
+            None
+        )?;
+
+        if let Some(game_data) = self.game_mut_by_id(game_id) {
+            game_data.time_control = key.time_control;
+        }
+
+        let result = self.join_match(
+            entry.address,
+            entry.username,
+            entry.web2_id,
+            key.bet_amount,
+            None,
+            game_id
+        );
+
+        // A game that nobody can join is cancelled so it doesn't stay waiting
+        if let Err(error) = result {
+            self.set_game_status(game_id, GameStatus::Cancelled);
+            return Err(error);
+        }
+
+        Ok(game_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        rating_state::PlayerRating,
+        runtime::mock,
+        test_utils::{
+            address,
+            new_state,
+            STAKE
+        }
+    };
+
+    const BLITZ: Option<u32> = Some(300);
+
+    fn join(
+        state: &mut ChessState,
+        player: u64,
+        bet_amount: BetAmout,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>
+    ) -> Result<MatchmakingResult, Bet2ChessErrors> {
+        state.join_matchmaking_queue(address(player), format!("player{player}"), player, bet_amount, time_control, rating_range)
+    }
+
+    fn set_rating(state: &mut ChessState, player: u64, rating: u32) {
+        state.ratings.ratings.insert(player, PlayerRating {
+            rating,
+            rated_games: 0
+        });
+    }
+
+    fn range(min: u32, max: u32) -> Option<RatingRange> {
+        Some(RatingRange { min, max })
+    }
+
+    fn is_queued(state: &ChessState, player: u64) -> bool {
+        state.matchmaking.queue_by_player.contains_key(&address(player))
+    }
+
+    #[test]
+    fn first_player_waits_in_the_queue() {
+        let mut state = new_state();
+
+        let result = join(&mut state, 10, STAKE, BLITZ, None).unwrap();
+
+        assert!(matches!(result, MatchmakingResult::WaitingForOpponent));
+        assert!(is_queued(&state, 10));
+
+        let key = MatchmakingQueueKey { bet_amount: STAKE, time_control: BLITZ };
+        assert_eq!(state.matchmaking.queues[&key].len(), 1);
+        assert!(state.games_by_id.is_empty());
+    }
+
+    #[test]
+    fn players_of_the_same_queue_are_paired_in_a_started_game() {
+        let mut state = new_state();
+        join(&mut state, 10, STAKE, BLITZ, None).unwrap();
+
+        let MatchmakingResult::GameCreated(game_id) = join(&mut state, 11, STAKE, BLITZ, None).unwrap() else {
+            panic!("the players weren't paired");
+        };
+
+        let game_data = state.game_by_id(game_id).unwrap();
+        assert!(game_data.status == GameStatus::Started);
+        // The player that was waiting plays with white pieces
+        assert_eq!((game_data.player1, game_data.player2), (address(10), address(11)));
+        assert_eq!(game_data.time_control, BLITZ);
+
+        assert!(!is_queued(&state, 10) && !is_queued(&state, 11));
+        assert!(state.matchmaking.queues.is_empty());
+        assert_eq!(mock::sent_messages(), [(address(10), 0)]);
+    }
+
+    #[test]
+    fn players_are_paired_in_the_order_they_joined() {
+        let mut state = new_state();
+        join(&mut state, 10, STAKE, None, None).unwrap();
+        join(&mut state, 11, STAKE, None, None).unwrap();
+        join(&mut state, 12, STAKE, None, None).unwrap();
+        join(&mut state, 13, STAKE, None, None).unwrap();
+        join(&mut state, 14, STAKE, None, None).unwrap();
+
+        let players: Vec<(ActorId, ActorId)> = state.games_by_id
+            .values()
+            .map(|game_data| (game_data.player1, game_data.player2))
+            .collect();
+
+        assert_eq!(players, [(address(10), address(11)), (address(12), address(13))]);
+        assert!(is_queued(&state, 14));
+    }
+
+    #[test]
+    fn different_bets_or_time_controls_are_not_paired() {
+        let mut state = new_state();
+
+        join(&mut state, 10, STAKE, BLITZ, None).unwrap();
+        let other_bet = join(&mut state, 11, 2 * STAKE, BLITZ, None).unwrap();
+        let other_time_control = join(&mut state, 12, STAKE, Some(600), None).unwrap();
+        let no_time_control = join(&mut state, 13, STAKE, None, None).unwrap();
+
+        assert!(matches!(other_bet, MatchmakingResult::WaitingForOpponent));
+        assert!(matches!(other_time_control, MatchmakingResult::WaitingForOpponent));
+        assert!(matches!(no_time_control, MatchmakingResult::WaitingForOpponent));
+        assert_eq!(state.matchmaking.queues.len(), 4);
+        assert!(state.games_by_id.is_empty());
+    }
+
+    #[test]
+    fn players_are_only_paired_inside_both_rating_ranges() {
+        let mut state = new_state();
+        set_rating(&mut state, 10, 1500);
+        set_rating(&mut state, 11, 1800);
+        set_rating(&mut state, 12, 1550);
+        set_rating(&mut state, 13, 1520);
+
+        join(&mut state, 10, STAKE, None, range(1400, 1600)).unwrap();
+
+        // The waiting player doesn't accept 1800
+        let out_of_range = join(&mut state, 11, STAKE, None, None).unwrap();
+        assert!(matches!(out_of_range, MatchmakingResult::WaitingForOpponent));
+
+        // 1550 accepts 1800 but 1800 only accepts from 1700
+        state.leave_matchmaking_queue(address(11)).unwrap();
+        join(&mut state, 11, STAKE, None, range(1700, 1900)).unwrap();
+
+        let paired = join(&mut state, 12, STAKE, None, range(1500, 2000)).unwrap();
+        let MatchmakingResult::GameCreated(game_id) = paired else {
+            panic!("1500 and 1550 weren't paired");
+        };
+        assert_eq!(state.game_by_id(game_id).unwrap().player1, address(10));
+
+        // Nobody waiting accepts 1520 and 1520 accepts everybody
+        let rejected = join(&mut state, 13, STAKE, None, None).unwrap();
+        assert!(matches!(rejected, MatchmakingResult::WaitingForOpponent));
+        assert!(is_queued(&state, 11) && is_queued(&state, 13));
+    }
+
+    #[test]
+    fn inverted_rating_ranges_are_rejected_and_refunded() {
+        let mut state = new_state();
+
+        assert_eq!(join(&mut state, 10, STAKE, None, range(1600, 1400)).err(), Some(Bet2ChessErrors::InvalidRatingRange));
+        assert!(!is_queued(&state, 10));
+        assert_eq!(state.balance(address(10)), STAKE);
+
+        assert!(join(&mut state, 11, STAKE, None, range(1500, 1500)).is_ok());
+    }
+
+    #[test]
+    fn joining_twice_is_rejected_and_refunded() {
+        let mut state = new_state();
+        join(&mut state, 10, STAKE, None, None).unwrap();
+
+        assert_eq!(join(&mut state, 10, STAKE, BLITZ, None).err(), Some(Bet2ChessErrors::PlayerAlreadyInMatchmakingQueue));
+        assert_eq!(state.balance(address(10)), STAKE);
+        assert_eq!(state.matchmaking.queues.len(), 1);
+    }
+
+    #[test]
+    fn leaving_the_queue_refunds_the_stake() {
+        let mut state = new_state();
+        join(&mut state, 10, STAKE, BLITZ, None).unwrap();
+
+        state.leave_matchmaking_queue(address(10)).unwrap();
+
+        assert_eq!(state.balance(address(10)), STAKE);
+        assert!(!is_queued(&state, 10));
+        assert!(state.matchmaking.queues.is_empty());
+
+        assert_eq!(state.leave_matchmaking_queue(address(10)), Err(Bet2ChessErrors::PlayerIsNotInMatchmakingQueue));
+        assert_eq!(state.balance(address(10)), STAKE);
+    }
+
+    #[test]
+    fn opponent_keeps_waiting_when_the_game_cant_be_created() {
+        let mut state = new_state();
+        join(&mut state, 10, STAKE, None, None).unwrap();
+        state.current_game_id = GameId::MAX;
+
+        assert_eq!(join(&mut state, 11, STAKE, None, None).err(), Some(Bet2ChessErrors::CantIncrementGamesIdItOverflow));
+
+        assert!(is_queued(&state, 10));
+        assert!(!is_queued(&state, 11));
+        assert_eq!(state.balance(address(10)), 0);
+        assert_eq!(state.balance(address(11)), STAKE);
+    }
+}
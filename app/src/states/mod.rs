@@ -1,2 +1,18 @@
 pub mod bet2chess_state;
-pub mod signless_accounts_state;
\ No newline at end of file
+pub mod chess_board_state;
+pub mod games_query_state;
+pub mod leaderboard_state;
+pub mod ledger_state;
+pub mod matchmaking_state;
+pub mod player_stats_state;
+pub mod rating_state;
+pub mod runtime;
+pub mod signless_accounts_state;
+pub mod spectator_market_state;
+pub mod token_stakes_state;
+pub mod tournament_state;
+pub mod treasury_state;
+pub mod user_registry_state;
+
+#[cfg(test)]
+pub(crate) mod test_utils;
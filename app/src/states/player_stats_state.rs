@@ -0,0 +1,254 @@
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+
+use super::bet2chess_state::{
+    BetAmout,
+    ChessState,
+    GameId,
+    GameStatus,
+    UserWeb2Id
+};
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Default)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PlayerStats {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    // Sum of the bets of the player in settled games
+    pub total_wagered: BetAmout,
+    // Sum of what the player received from settled games after fees,
+    // prizes and the stakes returned in draws
+    pub total_won: BetAmout,
+    // Positive for consecutive wins, negative for consecutive losses
+    // and zero after a draw
+    pub current_streak: i32
+}
+
+#[derive(Default)]
+pub struct PlayerGames {
+    // Games of each player in the order they joined them
+    pub games_by_address: BTreeMap<ActorId, Vec<GameId>>,
+    pub games_by_web2_id: BTreeMap<UserWeb2Id, Vec<GameId>>,
+    pub stats: BTreeMap<UserWeb2Id, PlayerStats>
+}
+
+impl ChessState {
+    pub fn games_id_by_player_address(&self, address: ActorId, offset: u32, limit: u32) -> Vec<GameId> {
+        self.player_games
+            .games_by_address
+            .get(&address)
+            .map(|games_id| Self::games_id_page(games_id.iter().copied(), offset, limit))
+            .unwrap_or_default()
+    }
+
+    pub fn games_id_by_player_web2_id(&self, web2_id: UserWeb2Id, offset: u32, limit: u32) -> Vec<GameId> {
+        self.player_games
+            .games_by_web2_id
+            .get(&web2_id)
+            .map(|games_id| Self::games_id_page(games_id.iter().copied(), offset, limit))
+            .unwrap_or_default()
+    }
+
+    pub fn player_stats(&self, web2_id: UserWeb2Id) -> PlayerStats {
+        self.player_games
+            .stats
+            .get(&web2_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn index_player_game(&mut self, address: ActorId, web2_id: UserWeb2Id, game_id: GameId) {
+        self.player_games
+            .games_by_address
+            .entry(address)
+            .or_default()
+            .push(game_id);
+
+        self.player_games
+            .games_by_web2_id
+            .entry(web2_id)
+            .or_default()
+            .push(game_id);
+    }
+
+    // Updates the stats of both players of an ended game
+    pub(crate) fn update_player_stats(&mut self, game_id: GameId) {
+        let Some(game_data) = self.games_by_id.get(&game_id) else {
+            return;
+        };
+
+        let GameStatus::Ended { winner } = game_data.status else {
+            return;
+        };
+
+        // The amounts of the stats are in VARA, games with stakes
+        // in tokens only count their results
+        let stakes = match game_data.token {
+            None => (game_data.game_bet, game_data.player2_bet),
+            Some(_) => (0, 0)
+        };
+        let pot = stakes.0 + stakes.1;
+        let pot_after_fee = pot - self.treasury.fee_config.fee_for_pot(pot);
+        let draw_refunds = Self::draw_refunds(pot_after_fee, stakes);
+
+        let players = [
+            (game_data.player1, game_data.player1_web2_id, stakes.0, draw_refunds.0),
+            (game_data.player2, game_data.player2_web2_id, stakes.1, draw_refunds.1)
+        ];
+
+        for (address, web2_id, stake, draw_refund) in players {
+            let stats = self.player_games
+                .stats
+                .entry(web2_id)
+                .or_default();
+
+            stats.total_wagered = stats.total_wagered.saturating_add(stake);
+
+            match winner {
+                None => {
+                    stats.draws += 1;
+                    stats.total_won = stats.total_won.saturating_add(draw_refund);
+                    stats.current_streak = 0;
+                },
+                Some(winner) if winner == address => {
+                    stats.wins += 1;
+                    stats.total_won = stats.total_won.saturating_add(pot_after_fee);
+                    stats.current_streak = stats.current_streak.max(0) + 1;
+                },
+                Some(_) => {
+                    stats.losses += 1;
+                    stats.current_streak = stats.current_streak.min(0) - 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        test_utils::{
+            address,
+            new_state,
+            start_game,
+            ADMIN,
+            STAKE
+        },
+        treasury_state::FeeConfig
+    };
+
+    const PLAYER1: u64 = 10;
+    const PLAYER2: u64 = 11;
+
+    // Fee of 5% without a minimum or a maximum
+    fn state_with_fee() -> ChessState {
+        let mut state = new_state();
+
+        state.set_fee_config(address(ADMIN), FeeConfig {
+            fee_basis_points: 500,
+            min_fee: 0,
+            max_fee: u128::MAX
+        }).unwrap();
+
+        state
+    }
+
+    fn end_game(state: &mut ChessState, stakes: (BetAmout, BetAmout), winner: Option<u64>) -> GameId {
+        let game_id = start_game(state, PLAYER1, PLAYER2, stakes);
+        state.end_match(game_id, address(ADMIN), winner.map(address)).unwrap();
+
+        game_id
+    }
+
+    #[test]
+    fn wins_count_the_pot_after_the_fee() {
+        let mut state = state_with_fee();
+        end_game(&mut state, (STAKE, STAKE), Some(PLAYER1));
+
+        let pot = 2 * STAKE;
+        let winner = state.player_stats(PLAYER1);
+        let loser = state.player_stats(PLAYER2);
+
+        assert_eq!((winner.wins, winner.losses, winner.draws), (1, 0, 0));
+        assert_eq!((winner.total_wagered, winner.total_won), (STAKE, pot - pot / 20));
+        assert_eq!(winner.current_streak, 1);
+
+        assert_eq!((loser.wins, loser.losses, loser.draws), (0, 1, 0));
+        assert_eq!((loser.total_wagered, loser.total_won), (STAKE, 0));
+        assert_eq!(loser.current_streak, -1);
+
+        // The stats match what was credited to the ledger
+        assert_eq!(state.balance(address(PLAYER1)), winner.total_won);
+    }
+
+    #[test]
+    fn draws_count_the_stake_returned_to_each_player() {
+        let mut state = state_with_fee();
+        end_game(&mut state, (STAKE, 3 * STAKE), None);
+
+        let left = 4 * STAKE - 4 * STAKE / 20;
+        let player1 = state.player_stats(PLAYER1);
+        let player2 = state.player_stats(PLAYER2);
+
+        assert_eq!((player1.draws, player2.draws), (1, 1));
+        assert_eq!((player1.total_wagered, player1.total_won), (STAKE, left / 4));
+        assert_eq!((player2.total_wagered, player2.total_won), (3 * STAKE, left * 3 / 4));
+        assert_eq!((player1.current_streak, player2.current_streak), (0, 0));
+    }
+
+    #[test]
+    fn streaks_change_sign_with_the_results_and_reset_on_draws() {
+        let mut state = new_state();
+
+        end_game(&mut state, (0, 0), Some(PLAYER1));
+        end_game(&mut state, (0, 0), Some(PLAYER1));
+        end_game(&mut state, (0, 0), Some(PLAYER1));
+        assert_eq!(state.player_stats(PLAYER1).current_streak, 3);
+        assert_eq!(state.player_stats(PLAYER2).current_streak, -3);
+
+        end_game(&mut state, (0, 0), Some(PLAYER2));
+        assert_eq!(state.player_stats(PLAYER1).current_streak, -1);
+        assert_eq!(state.player_stats(PLAYER2).current_streak, 1);
+
+        end_game(&mut state, (0, 0), None);
+        assert_eq!(state.player_stats(PLAYER1).current_streak, 0);
+        assert_eq!(state.player_stats(PLAYER2).current_streak, 0);
+
+        let stats = state.player_stats(PLAYER1);
+        assert_eq!((stats.wins, stats.losses, stats.draws), (3, 1, 1));
+    }
+
+    #[test]
+    fn totals_add_up_over_several_games() {
+        let mut state = new_state();
+
+        end_game(&mut state, (STAKE, STAKE), Some(PLAYER1));
+        end_game(&mut state, (2 * STAKE, 2 * STAKE), Some(PLAYER2));
+
+        let player1 = state.player_stats(PLAYER1);
+        assert_eq!((player1.total_wagered, player1.total_won), (3 * STAKE, 2 * STAKE));
+
+        let player2 = state.player_stats(PLAYER2);
+        assert_eq!((player2.total_wagered, player2.total_won), (3 * STAKE, 4 * STAKE));
+    }
+
+    #[test]
+    fn games_are_indexed_by_both_players() {
+        let mut state = new_state();
+        let first = end_game(&mut state, (STAKE, STAKE), Some(PLAYER1));
+        let second = start_game(&mut state, PLAYER2, 12, (STAKE, STAKE));
+
+        assert_eq!(state.games_id_by_player_address(address(PLAYER1), 0, 10), [first]);
+        assert_eq!(state.games_id_by_player_web2_id(PLAYER2, 0, 10), [first, second]);
+        assert_eq!(state.games_id_by_player_web2_id(PLAYER2, 1, 10), [second]);
+        assert!(state.games_id_by_player_address(address(13), 0, 10).is_empty());
+
+        // Games that haven't ended don't change the stats
+        assert_eq!(state.player_stats(12).wins + state.player_stats(12).losses, 0);
+    }
+}
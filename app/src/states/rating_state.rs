@@ -0,0 +1,256 @@
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+
+use super::runtime::exec;
+
+use super::bet2chess_state::{
+    ChessState,
+    GameId,
+    GameStatus,
+    ResultEnd,
+    UserWeb2Id
+};
+
+// Rating of the players that have not finished any game
+pub const DEFAULT_RATING: u32 = 1500;
+// Maximum points a player can win or lose in one game
+pub const RATING_K_FACTOR: i64 = 32;
+// Ratings never go below this value
+pub const MIN_RATING: u32 = 100;
+
+// Expected score (per mille) of the player with the higher rating, for rating
+// differences in steps of 25 points: 1000 / (1 + 10^(-difference / 400)).
+// Programs can't use floats, so the Elo formula is precomputed.
+const EXPECTED_SCORE_BY_DIFFERENCE: [i64; 33] = [
+    500, 536, 571, 606, 640, 673, 703, 733, 760, 785, 808,
+    830, 849, 867, 882, 896, 909, 920, 930, 939, 947, 954,
+    960, 965, 969, 973, 977, 980, 983, 985, 987, 989, 990
+];
+const RATING_DIFFERENCE_STEP: i64 = 25;
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PlayerRating {
+    pub rating: u32,
+    pub rated_games: u32
+}
+
+impl Default for PlayerRating {
+    fn default() -> Self {
+        Self {
+            rating: DEFAULT_RATING,
+            rated_games: 0
+        }
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RatingChange {
+    pub game_id: GameId,
+    pub opponent_web2_id: UserWeb2Id,
+    pub result: ResultEnd,
+    pub rating_before: u32,
+    pub rating_after: u32,
+    pub block: u32
+}
+
+#[derive(Default)]
+pub struct Ratings {
+    pub ratings: BTreeMap<UserWeb2Id, PlayerRating>,
+    // Rating changes of each player, from the oldest to the newest
+    pub history: BTreeMap<UserWeb2Id, Vec<RatingChange>>
+}
+
+impl ChessState {
+    pub fn player_rating(&self, web2_id: UserWeb2Id) -> u32 {
+        self.ratings
+            .ratings
+            .get(&web2_id)
+            .map_or(DEFAULT_RATING, |player_rating| player_rating.rating)
+    }
+
+    // Updates the rating of both players of an ended game
+    pub(crate) fn update_ratings(&mut self, game_id: GameId) {
+        let Some(game_data) = self.games_by_id.get(&game_id) else {
+            return;
+        };
+
+        let GameStatus::Ended { winner } = game_data.status else {
+            return;
+        };
+
+        let (player1_web2_id, player2_web2_id) = (game_data.player1_web2_id, game_data.player2_web2_id);
+
+        if player1_web2_id == player2_web2_id {
+            return;
+        }
+
+        let player1_result = match winner {
+            None => ResultEnd::Draw,
+            Some(winner) if winner == game_data.player1 => ResultEnd::Win,
+            Some(_) => ResultEnd::Lose
+        };
+
+        let player2_result = player1_result.opposite();
+
+        let player1_rating = self.player_rating(player1_web2_id);
+        let player2_rating = self.player_rating(player2_web2_id);
+
+        // Both ratings are computed with the ratings before the game
+        let player1_new_rating = Self::new_rating(player1_rating, player2_rating, player1_result);
+        let player2_new_rating = Self::new_rating(player2_rating, player1_rating, player2_result);
+
+        let block = exec::block_height();
+
+        self.set_player_rating(player1_web2_id, RatingChange {
+            game_id,
+            opponent_web2_id: player2_web2_id,
+            result: player1_result,
+            rating_before: player1_rating,
+            rating_after: player1_new_rating,
+            block
+        });
+
+        self.set_player_rating(player2_web2_id, RatingChange {
+            game_id,
+            opponent_web2_id: player1_web2_id,
+            result: player2_result,
+            rating_before: player2_rating,
+            rating_after: player2_new_rating,
+            block
+        });
+    }
+
+    fn set_player_rating(&mut self, web2_id: UserWeb2Id, change: RatingChange) {
+        let player_rating = self.ratings
+            .ratings
+            .entry(web2_id)
+            .or_default();
+
+        player_rating.rating = change.rating_after;
+        player_rating.rated_games += 1;
+
+        self.ratings
+            .history
+            .entry(web2_id)
+            .or_default()
+            .push(change);
+    }
+
+    fn new_rating(rating: u32, opponent_rating: u32, result: ResultEnd) -> u32 {
+        let expected_score = Self::expected_score(rating, opponent_rating);
+        let score = match result {
+            ResultEnd::Win => 1000,
+            ResultEnd::Draw => 500,
+            ResultEnd::Lose => 0
+        };
+
+        // Rounded to the nearest point
+        let difference = RATING_K_FACTOR * (score - expected_score);
+        let change = (difference + 500 * difference.signum()) / 1000;
+
+        (rating as i64 + change).max(MIN_RATING as i64) as u32
+    }
+
+    // Expected score per mille of a player against the opponent
+    fn expected_score(rating: u32, opponent_rating: u32) -> i64 {
+        let difference = rating as i64 - opponent_rating as i64;
+        let max_index = EXPECTED_SCORE_BY_DIFFERENCE.len() - 1;
+        let index = ((difference.abs() + RATING_DIFFERENCE_STEP / 2) / RATING_DIFFERENCE_STEP) as usize;
+        let expected_score = EXPECTED_SCORE_BY_DIFFERENCE[index.min(max_index)];
+
+        if difference >= 0 {
+            expected_score
+        } else {
+            1000 - expected_score
+        }
+    }
+}
+
+impl ResultEnd {
+    pub fn opposite(&self) -> ResultEnd {
+        match self {
+            ResultEnd::Win => ResultEnd::Lose,
+            ResultEnd::Lose => ResultEnd::Win,
+            ResultEnd::Draw => ResultEnd::Draw
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::test_utils::{
+        address,
+        new_state,
+        start_game,
+        ADMIN
+    };
+
+    #[test]
+    fn expected_score_table_matches_the_elo_formula() {
+        for (index, expected_score) in EXPECTED_SCORE_BY_DIFFERENCE.iter().enumerate() {
+            let difference = (index as i64 * RATING_DIFFERENCE_STEP) as f64;
+            let formula = 1000.0 / (1.0 + 10f64.powf(-difference / 400.0));
+
+            assert_eq!(*expected_score, formula.round() as i64, "difference of {difference} points");
+        }
+    }
+
+    #[test]
+    fn expected_score_is_symmetric_and_capped() {
+        assert_eq!(ChessState::expected_score(1500, 1500), 500);
+        assert_eq!(ChessState::expected_score(1700, 1500), 760);
+        assert_eq!(ChessState::expected_score(1500, 1700), 240);
+        // Differences are rounded to the nearest step
+        assert_eq!(ChessState::expected_score(1512, 1500), 500);
+        assert_eq!(ChessState::expected_score(1513, 1500), 536);
+        assert_eq!(ChessState::expected_score(3000, 1000), 990);
+        assert_eq!(ChessState::expected_score(1000, 3000), 10);
+    }
+
+    #[test]
+    fn new_rating_moves_half_the_k_factor_between_equal_players() {
+        assert_eq!(ChessState::new_rating(1500, 1500, ResultEnd::Win), 1516);
+        assert_eq!(ChessState::new_rating(1500, 1500, ResultEnd::Lose), 1484);
+        assert_eq!(ChessState::new_rating(1500, 1500, ResultEnd::Draw), 1500);
+    }
+
+    #[test]
+    fn upsets_move_more_points_than_expected_results() {
+        // 1700 is expected to score 760 per mille against 1500
+        assert_eq!(ChessState::new_rating(1700, 1500, ResultEnd::Win), 1708);
+        assert_eq!(ChessState::new_rating(1500, 1700, ResultEnd::Lose), 1492);
+        assert_eq!(ChessState::new_rating(1500, 1700, ResultEnd::Win), 1524);
+        assert_eq!(ChessState::new_rating(1700, 1500, ResultEnd::Lose), 1676);
+        assert_eq!(ChessState::new_rating(1500, 1700, ResultEnd::Draw), 1508);
+    }
+
+    #[test]
+    fn ratings_never_go_below_the_minimum() {
+        assert_eq!(ChessState::new_rating(MIN_RATING, MIN_RATING, ResultEnd::Lose), MIN_RATING);
+    }
+
+    #[test]
+    fn settled_games_update_both_ratings_and_their_history() {
+        let mut state = new_state();
+        let game_id = start_game(&mut state, 10, 11, (0, 0));
+
+        state.end_match(game_id, address(ADMIN), Some(address(10))).unwrap();
+
+        assert_eq!(state.player_rating(10), 1516);
+        assert_eq!(state.player_rating(11), 1484);
+        assert_eq!(state.ratings.ratings[&10].rated_games, 1);
+
+        let change = &state.ratings.history[&11][0];
+        assert_eq!(change.game_id, game_id);
+        assert_eq!(change.opponent_web2_id, 10);
+        assert!(change.result == ResultEnd::Lose);
+        assert_eq!((change.rating_before, change.rating_after), (1500, 1484));
+    }
+}
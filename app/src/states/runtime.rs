@@ -0,0 +1,131 @@
+// Syscalls of the chain used by the states. The contract uses the ones of
+// gstd, the native tests can't call them so they use a mock of the chain
+#[cfg(not(test))]
+pub(crate) use sails_rs::gstd::{
+    exec,
+    msg
+};
+
+#[cfg(test)]
+pub(crate) use mock::{
+    exec,
+    msg
+};
+
+#[cfg(test)]
+pub(crate) mod mock {
+    use core::cell::{
+        Cell,
+        RefCell
+    };
+    use sails_rs::prelude::*;
+
+    pub const PROGRAM_ID: u64 = 1_000;
+
+    std::thread_local! {
+        static BLOCK_HEIGHT: Cell<u32> = const { Cell::new(0) };
+        static SOURCE: Cell<u64> = const { Cell::new(0) };
+        static VALUE: Cell<u128> = const { Cell::new(0) };
+        static MESSAGES_FAIL: Cell<bool> = const { Cell::new(false) };
+        // Reply of the next message sent for a reply, `None` if it fails
+        static REPLY: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+        // Destination and value of every message sent
+        static SENT: RefCell<Vec<(ActorId, u128)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // Every test starts at block zero with messages that are delivered
+    pub fn reset() {
+        BLOCK_HEIGHT.set(0);
+        SOURCE.set(0);
+        VALUE.set(0);
+        MESSAGES_FAIL.set(false);
+        REPLY.replace(None);
+        SENT.replace(Vec::new());
+    }
+
+    pub fn set_block_height(block: u32) {
+        BLOCK_HEIGHT.set(block);
+    }
+
+    pub fn set_source(source: u64) {
+        SOURCE.set(source);
+    }
+
+    pub fn set_value(value: u128) {
+        VALUE.set(value);
+    }
+
+    pub fn set_messages_fail(fail: bool) {
+        MESSAGES_FAIL.set(fail);
+    }
+
+    pub fn set_reply(reply: Option<Vec<u8>>) {
+        REPLY.replace(reply);
+    }
+
+    pub fn sent_messages() -> Vec<(ActorId, u128)> {
+        SENT.with_borrow(|sent| sent.clone())
+    }
+
+    fn send_message(destination: ActorId, value: u128) -> Result<MessageId, ()> {
+        if MESSAGES_FAIL.get() {
+            return Err(());
+        }
+
+        SENT.with_borrow_mut(|sent| sent.push((destination, value)));
+
+        Ok(MessageId::default())
+    }
+
+    pub mod exec {
+        use sails_rs::prelude::*;
+
+        pub fn block_height() -> u32 {
+            super::BLOCK_HEIGHT.get()
+        }
+
+        pub fn program_id() -> ActorId {
+            ActorId::from(super::PROGRAM_ID)
+        }
+    }
+
+    pub mod msg {
+        use core::future::Ready;
+        use sails_rs::prelude::*;
+
+        pub fn source() -> ActorId {
+            ActorId::from(super::SOURCE.get())
+        }
+
+        pub fn value() -> u128 {
+            super::VALUE.get()
+        }
+
+        pub fn send(destination: ActorId, _payload: impl Encode, value: u128) -> Result<MessageId, ()> {
+            super::send_message(destination, value)
+        }
+
+        pub fn send_bytes_with_gas_delayed(
+            destination: ActorId,
+            _payload: impl AsRef<[u8]>,
+            _gas: u64,
+            value: u128,
+            _delay: u32
+        ) -> Result<MessageId, ()> {
+            super::send_message(destination, value)
+        }
+
+        pub fn send_bytes_for_reply(
+            destination: ActorId,
+            _payload: impl AsRef<[u8]>,
+            value: u128,
+            _reply_deposit: u64
+        ) -> Result<Ready<Result<Vec<u8>, ()>>, ()> {
+            super::send_message(destination, value)?;
+
+            let reply = super::REPLY.take().ok_or(());
+
+            Ok(core::future::ready(reply))
+        }
+    }
+}
@@ -0,0 +1,451 @@
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+
+use super::runtime::exec;
+
+use super::bet2chess_state::{
+    Bet2ChessErrors,
+    BetAmout,
+    ChessState,
+    GameId,
+    GameStatus
+};
+
+// Full moves of the game and blocks since it started while spectators
+// can still bet, about half an hour with blocks of three seconds
+pub const DEFAULT_MARKET_CUTOFF_MOVES: u32 = 10;
+pub const DEFAULT_MARKET_CUTOFF_BLOCKS: u32 = 600;
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum MarketOutcome {
+    Player1,
+    Player2,
+    Draw
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum MarketStatus {
+    Open,
+    // The winners share the prize pool in proportion to their bets
+    Settled {
+        outcome: MarketOutcome
+    },
+    // Every spectator gets their bets back
+    Refunded
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MarketConfig {
+    pub cutoff_moves: u32,
+    pub cutoff_blocks: u32
+}
+
+impl Default for MarketConfig {
+    fn default() -> Self {
+        Self {
+            cutoff_moves: DEFAULT_MARKET_CUTOFF_MOVES,
+            cutoff_blocks: DEFAULT_MARKET_CUTOFF_BLOCKS
+        }
+    }
+}
+
+// Spectator bets of a started game, in planck
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SpectatorMarket {
+    pub status: MarketStatus,
+    // The cutoff is taken from the config when the game starts
+    pub closes_after_move: u32,
+    pub closes_at_block: u32,
+    pub pools: BTreeMap<MarketOutcome, BetAmout>,
+    // What is left for the winners after the fee
+    pub prize_pool: BetAmout,
+    // Prizes already claimed, the last winner also takes the rounding dust
+    pub paid_out: BetAmout,
+    // Bets that have not been claimed yet, the losing ones are dropped
+    // when the market is settled
+    pub bets: BTreeMap<ActorId, BTreeMap<MarketOutcome, BetAmout>>
+}
+
+impl SpectatorMarket {
+    pub fn total_pool(&self) -> BetAmout {
+        self.pools.values().sum()
+    }
+
+    fn pool(&self, outcome: MarketOutcome) -> BetAmout {
+        self.pools
+            .get(&outcome)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+pub struct SpectatorMarkets {
+    pub config: MarketConfig,
+    pub markets: BTreeMap<GameId, SpectatorMarket>
+}
+
+impl ChessState {
+    pub fn set_market_config(&mut self, caller: ActorId, config: MarketConfig) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        if config.cutoff_moves == 0 || config.cutoff_blocks == 0 {
+            return Err(Bet2ChessErrors::InvalidMarketConfig);
+        }
+
+        self.spectator_markets.config = config;
+
+        Ok(())
+    }
+
+    pub fn spectator_market(&self, game_id: GameId) -> Option<&SpectatorMarket> {
+        self.spectator_markets
+            .markets
+            .get(&game_id)
+    }
+
+    pub fn spectator_bets(&self, game_id: GameId, address: ActorId) -> Vec<(MarketOutcome, BetAmout)> {
+        self.spectator_market(game_id)
+            .and_then(|market| market.bets.get(&address))
+            .map(|bets| bets.iter().map(|(outcome, amount)| (*outcome, *amount)).collect())
+            .unwrap_or_default()
+    }
+
+    // The value is credited back to the spectator if the bet is rejected
+    pub fn place_spectator_bet(
+        &mut self,
+        address: ActorId,
+        game_id: GameId,
+        outcome: MarketOutcome,
+        amount: BetAmout
+    ) -> Result<(), Bet2ChessErrors> {
+        if let Err(error) = self.check_spectator_bet(address, game_id, amount) {
+            return Err(self.refund_bet(address, amount, None, error));
+        }
+
+        let Some(market) = self.spectator_markets.markets.get_mut(&game_id) else {
+            return Err(self.refund_bet(address, amount, None, Bet2ChessErrors::MarketDoesNotExists(game_id)));
+        };
+
+        *market.pools.entry(outcome).or_default() += amount;
+        *market.bets
+            .entry(address)
+            .or_default()
+            .entry(outcome)
+            .or_default() += amount;
+
+        Ok(())
+    }
+
+    // Credits the prize or the refund of the spectator to their balance,
+    // returns the amount credited. The market is removed once every bet
+    // has been claimed
+    pub fn claim_spectator_winnings(&mut self, address: ActorId, game_id: GameId) -> Result<BetAmout, Bet2ChessErrors> {
+        let market = self.spectator_markets
+            .markets
+            .get_mut(&game_id)
+            .ok_or(Bet2ChessErrors::MarketDoesNotExists(game_id))?;
+
+        let payout = match market.status {
+            MarketStatus::Open => return Err(Bet2ChessErrors::MarketIsNotSettled(game_id)),
+            MarketStatus::Refunded => {
+                market.bets
+                    .get(&address)
+                    .map(|bets| bets.values().sum())
+                    .unwrap_or_default()
+            },
+            MarketStatus::Settled { outcome } => {
+                let stake = market.bets
+                    .get(&address)
+                    .and_then(|bets| bets.get(&outcome))
+                    .copied()
+                    .unwrap_or_default();
+
+                let is_last_winner = stake > 0 && market.bets.len() == 1;
+
+                if is_last_winner {
+                    market.prize_pool - market.paid_out
+                } else {
+                    (U256::from(stake) * U256::from(market.prize_pool) / U256::from(market.pool(outcome).max(1)))
+                        .as_u128()
+                }
+            }
+        };
+
+        if payout == 0 {
+            return Err(Bet2ChessErrors::NothingToClaim);
+        }
+
+        market.bets.remove(&address);
+        market.paid_out += payout;
+
+        if market.bets.is_empty() {
+            self.spectator_markets.markets.remove(&game_id);
+        }
+
+        self.credit(address, payout);
+
+        Ok(payout)
+    }
+
+    pub(crate) fn open_spectator_market(&mut self, game_id: GameId) {
+        let config = self.spectator_markets.config;
+
+        self.spectator_markets.markets.insert(game_id, SpectatorMarket {
+            status: MarketStatus::Open,
+            closes_after_move: config.cutoff_moves,
+            closes_at_block: exec::block_height().saturating_add(config.cutoff_blocks),
+            pools: BTreeMap::new(),
+            prize_pool: 0,
+            paid_out: 0,
+            bets: BTreeMap::new()
+        });
+    }
+
+    // Called when the result of the game is recorded. If nobody bet on the
+    // result the market is refunded instead and pays no fee. Only the bets
+    // on the result are kept, they are the ones that can be claimed
+    pub(crate) fn settle_spectator_market(&mut self, game_id: GameId) {
+        let Some(game_data) = self.games_by_id.get(&game_id) else {
+            return;
+        };
+
+        let GameStatus::Ended { winner } = game_data.status else {
+            return;
+        };
+
+        let outcome = match winner {
+            None => MarketOutcome::Draw,
+            Some(winner) if winner == game_data.player1 => MarketOutcome::Player1,
+            Some(_) => MarketOutcome::Player2
+        };
+
+        let Some(market) = self.spectator_markets.markets.get(&game_id) else {
+            return;
+        };
+
+        if market.pool(outcome) == 0 {
+            self.refund_spectator_market(game_id);
+            return;
+        }
+
+        let prize_pool = self.collect_fee(market.total_pool(), None);
+
+        if let Some(market) = self.spectator_markets.markets.get_mut(&game_id) {
+            market.status = MarketStatus::Settled { outcome };
+            market.prize_pool = prize_pool;
+            market.bets.retain(|_, bets| {
+                bets.retain(|bet_outcome, _| *bet_outcome == outcome);
+                !bets.is_empty()
+            });
+        }
+    }
+
+    // A market without bets is removed, there is nothing to claim from it
+    pub(crate) fn refund_spectator_market(&mut self, game_id: GameId) {
+        let Some(market) = self.spectator_markets.markets.get_mut(&game_id) else {
+            return;
+        };
+
+        if market.bets.is_empty() {
+            self.spectator_markets.markets.remove(&game_id);
+            return;
+        }
+
+        market.status = MarketStatus::Refunded;
+    }
+
+    fn check_spectator_bet(&self, address: ActorId, game_id: GameId, amount: BetAmout) -> Result<(), Bet2ChessErrors> {
+        if amount == 0 {
+            return Err(Bet2ChessErrors::SpectatorBetCantBeZero);
+        }
+
+        let game_data = self.games_by_id
+            .get(&game_id)
+            .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
+
+        if game_data.status != GameStatus::Started {
+            return Err(Bet2ChessErrors::GameIsNotStarted(game_id));
+        }
+
+        if address == game_data.player1 || address == game_data.player2 {
+            return Err(Bet2ChessErrors::PlayersCantBetOnTheirGame(game_id));
+        }
+
+        let market = self.spectator_market(game_id)
+            .ok_or(Bet2ChessErrors::MarketDoesNotExists(game_id))?;
+
+        let move_number = self.game_boards
+            .get(&game_id)
+            .map(|board| board.fullmove_number)
+            .unwrap_or_default();
+
+        if market.status != MarketStatus::Open
+            || move_number > market.closes_after_move
+            || exec::block_height() >= market.closes_at_block
+        {
+            return Err(Bet2ChessErrors::MarketIsClosed(game_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        runtime::mock,
+        test_utils::{
+            address,
+            new_state,
+            start_game,
+            ADMIN,
+            STAKE
+        },
+        treasury_state::FeeConfig
+    };
+
+    const PLAYER1: u64 = 10;
+    const PLAYER2: u64 = 11;
+    const SPECTATORS: [u64; 3] = [20, 21, 22];
+
+    fn started_game() -> (ChessState, GameId) {
+        let mut state = new_state();
+        let game_id = start_game(&mut state, PLAYER1, PLAYER2, (STAKE, STAKE));
+
+        (state, game_id)
+    }
+
+    fn bet(state: &mut ChessState, spectator: u64, game_id: GameId, outcome: MarketOutcome, amount: BetAmout) {
+        state.place_spectator_bet(address(spectator), game_id, outcome, amount).unwrap();
+    }
+
+    fn claim(state: &mut ChessState, spectator: u64, game_id: GameId) -> Result<BetAmout, Bet2ChessErrors> {
+        state.claim_spectator_winnings(address(spectator), game_id)
+    }
+
+    #[test]
+    fn winners_share_the_pool_in_proportion_and_the_last_one_takes_the_dust() {
+        let (mut state, game_id) = started_game();
+
+        bet(&mut state, SPECTATORS[0], game_id, MarketOutcome::Player1, 1);
+        bet(&mut state, SPECTATORS[1], game_id, MarketOutcome::Player1, 2);
+        bet(&mut state, SPECTATORS[2], game_id, MarketOutcome::Player2, 4);
+
+        state.resign(address(PLAYER2), game_id).unwrap();
+
+        // 7 * 1 / 3 rounds down to 2, the last winner takes 7 - 2
+        assert_eq!(claim(&mut state, SPECTATORS[0], game_id), Ok(2));
+        assert_eq!(claim(&mut state, SPECTATORS[2], game_id), Err(Bet2ChessErrors::NothingToClaim));
+        assert_eq!(claim(&mut state, SPECTATORS[1], game_id), Ok(5));
+
+        assert_eq!(state.balance(address(SPECTATORS[0])) + state.balance(address(SPECTATORS[1])), 7);
+    }
+
+    #[test]
+    fn settled_markets_keep_only_the_winning_bets_and_are_removed_once_claimed() {
+        let (mut state, game_id) = started_game();
+
+        bet(&mut state, SPECTATORS[0], game_id, MarketOutcome::Player1, STAKE);
+        bet(&mut state, SPECTATORS[0], game_id, MarketOutcome::Draw, STAKE);
+        bet(&mut state, SPECTATORS[1], game_id, MarketOutcome::Player2, STAKE);
+
+        state.resign(address(PLAYER2), game_id).unwrap();
+
+        let market = state.spectator_market(game_id).unwrap();
+        assert!(market.status == MarketStatus::Settled { outcome: MarketOutcome::Player1 });
+        assert!(state.spectator_bets(game_id, address(SPECTATORS[0])) == [(MarketOutcome::Player1, STAKE)]);
+        assert!(state.spectator_bets(game_id, address(SPECTATORS[1])).is_empty());
+
+        assert_eq!(claim(&mut state, SPECTATORS[0], game_id), Ok(3 * STAKE));
+        assert!(state.spectator_market(game_id).is_none());
+        assert_eq!(claim(&mut state, SPECTATORS[0], game_id), Err(Bet2ChessErrors::MarketDoesNotExists(game_id)));
+    }
+
+    #[test]
+    fn the_fee_is_taken_from_the_pool_of_settled_markets() {
+        let (mut state, game_id) = started_game();
+
+        state.set_fee_config(address(ADMIN), FeeConfig {
+            fee_basis_points: 500,
+            min_fee: 0,
+            max_fee: u128::MAX
+        }).unwrap();
+
+        bet(&mut state, SPECTATORS[0], game_id, MarketOutcome::Player1, STAKE);
+        bet(&mut state, SPECTATORS[1], game_id, MarketOutcome::Player2, STAKE);
+
+        let treasury_before = state.treasury.balance;
+        state.resign(address(PLAYER2), game_id).unwrap();
+
+        let pool = 2 * STAKE;
+        let fee = pool / 20;
+        let game_fee = 2 * STAKE / 20;
+
+        assert_eq!(state.treasury.balance - treasury_before, fee + game_fee);
+        assert_eq!(claim(&mut state, SPECTATORS[0], game_id), Ok(pool - fee));
+    }
+
+    #[test]
+    fn markets_without_bets_on_the_result_are_refunded() {
+        let (mut state, game_id) = started_game();
+
+        bet(&mut state, SPECTATORS[0], game_id, MarketOutcome::Player1, STAKE);
+        bet(&mut state, SPECTATORS[0], game_id, MarketOutcome::Player2, STAKE);
+        bet(&mut state, SPECTATORS[1], game_id, MarketOutcome::Player1, 2 * STAKE);
+
+        state.end_match(game_id, address(ADMIN), None).unwrap();
+
+        assert!(state.spectator_market(game_id).unwrap().status == MarketStatus::Refunded);
+        assert_eq!(claim(&mut state, SPECTATORS[0], game_id), Ok(2 * STAKE));
+        assert_eq!(claim(&mut state, SPECTATORS[1], game_id), Ok(2 * STAKE));
+        assert!(state.spectator_market(game_id).is_none());
+        assert_eq!(state.treasury.balance, 0);
+    }
+
+    #[test]
+    fn open_markets_can_not_be_claimed() {
+        let (mut state, game_id) = started_game();
+
+        bet(&mut state, SPECTATORS[0], game_id, MarketOutcome::Player1, STAKE);
+
+        assert_eq!(claim(&mut state, SPECTATORS[0], game_id), Err(Bet2ChessErrors::MarketIsNotSettled(game_id)));
+    }
+
+    #[test]
+    fn rejected_bets_are_credited_back() {
+        let (mut state, game_id) = started_game();
+
+        assert_eq!(
+            state.place_spectator_bet(address(PLAYER1), game_id, MarketOutcome::Player1, STAKE),
+            Err(Bet2ChessErrors::PlayersCantBetOnTheirGame(game_id))
+        );
+        assert_eq!(
+            state.place_spectator_bet(address(SPECTATORS[0]), game_id, MarketOutcome::Player1, 0),
+            Err(Bet2ChessErrors::SpectatorBetCantBeZero)
+        );
+
+        // The market closes when the cutoff block is reached
+        mock::set_block_height(DEFAULT_MARKET_CUTOFF_BLOCKS);
+
+        assert_eq!(
+            state.place_spectator_bet(address(SPECTATORS[0]), game_id, MarketOutcome::Player1, STAKE),
+            Err(Bet2ChessErrors::MarketIsClosed(game_id))
+        );
+
+        assert_eq!(state.balance(address(PLAYER1)), STAKE);
+        assert_eq!(state.balance(address(SPECTATORS[0])), STAKE);
+        assert_eq!(state.spectator_market(game_id).unwrap().total_pool(), 0);
+    }
+}
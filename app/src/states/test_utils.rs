@@ -0,0 +1,86 @@
+use core::{
+    future::Future,
+    pin::pin,
+    task::{
+        Context,
+        Poll,
+        Waker
+    }
+};
+use sails_rs::prelude::*;
+
+use super::{
+    bet2chess_state::{
+        BetAmout,
+        ChessState,
+        DEFAULT_INVITATION_TTL_BLOCKS,
+        GameId,
+        GameStatusKind,
+        ONE_VARA
+    },
+    runtime::mock
+};
+
+pub const ADMIN: u64 = 1;
+pub const STAKE: BetAmout = 10 * ONE_VARA;
+
+// Players use the same number as address and as web2 id
+pub fn address(id: u64) -> ActorId {
+    ActorId::from(id)
+}
+
+// State of a contract deployed by `ADMIN`, with the mock chain reset
+pub fn new_state() -> ChessState {
+    mock::reset();
+
+    let mut state = ChessState::default();
+    state.admins.push(address(ADMIN));
+    state.invitation_ttl_blocks = DEFAULT_INVITATION_TTL_BLOCKS;
+
+    state
+}
+
+// Starts a game between two players, `player1` plays with white pieces
+pub fn start_game(state: &mut ChessState, player1: u64, player2: u64, stakes: (BetAmout, BetAmout)) -> GameId {
+    let game_id = state
+        .create_match(address(player1), format!("player{player1}"), player1, stakes.0, None)
+        .unwrap();
+
+    state.game_mut_by_id(game_id).unwrap().player2_bet = stakes.1;
+
+    state
+        .join_match(address(player2), format!("player{player2}"), player2, stakes.1, None, game_id)
+        .unwrap();
+
+    game_id
+}
+
+// Every game id is in exactly one bucket of the status index, the one
+// of the status of the game
+pub fn assert_status_index(state: &ChessState) {
+    for (game_id, game_data) in state.games_by_id.iter() {
+        let buckets: Vec<GameStatusKind> = state.games_by_status
+            .iter()
+            .filter(|(_, games_id)| games_id.contains(game_id))
+            .map(|(status, _)| *status)
+            .collect();
+
+        assert!(buckets == [game_data.status.kind()], "game {game_id} is indexed in the wrong buckets");
+    }
+
+    let indexed: usize = state.games_by_status.values().map(|games_id| games_id.len()).sum();
+    assert_eq!(indexed, state.games_by_id.len());
+}
+
+// The mock chain replies right away, so the futures are ready the first
+// time they are polled
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut context = Context::from_waker(Waker::noop());
+    let mut future = pin!(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use sails_rs::prelude::*;
+
+use super::runtime::{
+    msg,
+    exec
+};
+
+use super::bet2chess_state::{
+    Bet2ChessErrors,
+    BetAmout,
+    ChessState,
+    GameId,
+    GameStatus,
+    UserWeb2Id
+};
+
+// Route of the service of the token programs, they implement the VFT standard
+const VFT_SERVICE: &str = "Vft";
+
+// The checks are done before pulling the tokens, so the transfer is only made
+// when the command is expected to succeed. If the state changes while the
+// transfer is in progress, the command fails after it and the tokens are
+// credited back to the ledger balance of the user.
+impl ChessState {
+    pub fn check_token_invitation(
+        &self,
+        user_id: UserWeb2Id,
+        guest: UserWeb2Id,
+        amount: BetAmout,
+        guest_amount: BetAmout
+    ) -> Result<(), Bet2ChessErrors> {
+        if amount == 0 || guest_amount == 0 {
+            return Err(Bet2ChessErrors::TokenStakeCantBeZero);
+        }
+
+        let already_sent_invitation = self.user_invitations
+            .get(&user_id)
+            .is_some_and(|invitations| invitations.invitations_sent.contains(&guest));
+
+        let already_invited = self.user_invitations
+            .get(&guest)
+            .is_some_and(|invitations| invitations.invitations_received.contains_key(&user_id));
+
+        if already_sent_invitation || already_invited {
+            return Err(Bet2ChessErrors::UserAlreadyInviteThePlayer(guest));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_token_invitation_acceptance(
+        &self,
+        invited_user: UserWeb2Id,
+        user_who_invite: UserWeb2Id,
+        web2_game_id: u64,
+        token: ActorId,
+        amount: BetAmout
+    ) -> Result<(), Bet2ChessErrors> {
+        let invitation = self.invitations
+            .get(&(user_who_invite, invited_user))
+            .ok_or(Bet2ChessErrors::UserHasNoInvitationfromTheUser(user_who_invite))?;
+
+        if invitation.is_expired(exec::block_height()) {
+            return Err(Bet2ChessErrors::InvitationExpired);
+        }
+
+        if invitation.token != Some(token) {
+            return Err(Bet2ChessErrors::StakeCurrencyMismatch);
+        }
+
+        if invitation.guest_bet_amount != amount {
+            return Err(Bet2ChessErrors::BetIsNotTheSameForMatch {
+                game_bet: invitation.guest_bet_amount,
+                bet_by_user: amount
+            });
+        }
+
+        if self.games_by_web2_id.contains_key(&web2_game_id) {
+            return Err(Bet2ChessErrors::Web2GameIdAlreadyExists(web2_game_id));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_token_game_join(
+        &self,
+        address: ActorId,
+        user_web2_id: UserWeb2Id,
+        game_id: GameId,
+        token: ActorId,
+        amount: BetAmout
+    ) -> Result<(), Bet2ChessErrors> {
+        let game_data = self.games_by_id
+            .get(&game_id)
+            .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
+
+        match game_data.status {
+            GameStatus::Started => return Err(Bet2ChessErrors::GameAlreadyStart(game_id)),
+            GameStatus::Ended { .. } => return Err(Bet2ChessErrors::GameAlreadyEnded(game_id)),
+            GameStatus::Cancelled => return Err(Bet2ChessErrors::GameWasCancelled(game_id)),
+            GameStatus::Waiting => {}
+        }
+
+        if game_data.player1 == address || game_data.player1_web2_id == user_web2_id {
+            return Err(Bet2ChessErrors::UserCantJoinTheirOwnGame(game_id));
+        }
+
+        if game_data.token != Some(token) {
+            return Err(Bet2ChessErrors::StakeCurrencyMismatch);
+        }
+
+        if game_data.player2_bet != amount {
+            return Err(Bet2ChessErrors::BetIsNotTheSameForMatch {
+                game_bet: game_data.player2_bet,
+                bet_by_user: amount
+            });
+        }
+
+        Ok(())
+    }
+
+    // Moves the tokens of the user to the contract, the user has to approve
+    // the contract in the token program first
+    pub async fn pull_tokens(token: ActorId, from: ActorId, amount: BetAmout) -> Result<(), Bet2ChessErrors> {
+        let payload = (VFT_SERVICE, "TransferFrom", (from, exec::program_id(), U256::from(amount))).encode();
+
+        Self::send_token_message(token, "TransferFrom", payload).await
+    }
+
+    pub async fn push_tokens(token: ActorId, to: ActorId, amount: BetAmout) -> Result<(), Bet2ChessErrors> {
+        let payload = (VFT_SERVICE, "Transfer", (to, U256::from(amount))).encode();
+
+        Self::send_token_message(token, "Transfer", payload).await
+    }
+
+    // The token program replies with the route of the method and `true`
+    // if the transfer was made
+    async fn send_token_message(token: ActorId, method: &str, payload: Vec<u8>) -> Result<(), Bet2ChessErrors> {
+        let reply = msg::send_bytes_for_reply(token, payload, 0, 0)
+            .map_err(|_| Bet2ChessErrors::TokenTransferFailed)?
+            .await
+            .map_err(|_| Bet2ChessErrors::TokenTransferFailed)?;
+
+        let (service, reply_method, transferred) = <(String, String, bool)>::decode(&mut reply.as_slice())
+            .map_err(|_| Bet2ChessErrors::TokenTransferFailed)?;
+
+        if service != VFT_SERVICE || reply_method != method || !transferred {
+            return Err(Bet2ChessErrors::TokenTransferFailed);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        runtime::mock,
+        test_utils::{
+            address,
+            block_on,
+            new_state,
+            STAKE
+        }
+    };
+
+    const TOKEN: u64 = 500;
+    const PLAYER1: u64 = 10;
+    const PLAYER2: u64 = 11;
+
+    fn reply(method: &str, transferred: bool) -> Option<Vec<u8>> {
+        Some((String::from(VFT_SERVICE), String::from(method), transferred).encode())
+    }
+
+    fn pull(amount: BetAmout) -> Result<(), Bet2ChessErrors> {
+        block_on(ChessState::pull_tokens(address(TOKEN), address(PLAYER1), amount))
+    }
+
+    #[test]
+    fn transfers_succeed_only_with_a_matching_reply() {
+        new_state();
+
+        mock::set_reply(reply("TransferFrom", true));
+        assert_eq!(pull(STAKE), Ok(()));
+        assert_eq!(mock::sent_messages(), [(address(TOKEN), 0)]);
+
+        mock::set_reply(reply("Transfer", true));
+        assert_eq!(block_on(ChessState::push_tokens(address(TOKEN), address(PLAYER1), STAKE)), Ok(()));
+    }
+
+    #[test]
+    fn transfers_fail_without_a_matching_reply() {
+        new_state();
+
+        // The token program refuses the transfer
+        mock::set_reply(reply("TransferFrom", false));
+        assert_eq!(pull(STAKE), Err(Bet2ChessErrors::TokenTransferFailed));
+
+        // The reply is of another method
+        mock::set_reply(reply("Transfer", true));
+        assert_eq!(pull(STAKE), Err(Bet2ChessErrors::TokenTransferFailed));
+
+        // The reply can't be decoded
+        mock::set_reply(Some(Vec::from([1, 2, 3])));
+        assert_eq!(pull(STAKE), Err(Bet2ChessErrors::TokenTransferFailed));
+
+        // The message fails
+        mock::set_reply(None);
+        assert_eq!(pull(STAKE), Err(Bet2ChessErrors::TokenTransferFailed));
+
+        // The message can't be sent
+        mock::set_messages_fail(true);
+        mock::set_reply(reply("TransferFrom", true));
+        assert_eq!(pull(STAKE), Err(Bet2ChessErrors::TokenTransferFailed));
+    }
+
+    #[test]
+    fn pulled_tokens_are_credited_back_when_the_game_changed_during_the_transfer() {
+        let mut state = new_state();
+        let token = Some(address(TOKEN));
+
+        let game_id = state
+            .create_open_game(address(PLAYER1), "player1".into(), PLAYER1, STAKE, token)
+            .unwrap();
+
+        // The check passes before the tokens are pulled ...
+        state.check_token_game_join(address(PLAYER2), PLAYER2, game_id, address(TOKEN), STAKE).unwrap();
+
+        // ... but the creator cancels the game before the transfer ends
+        state.cancel_open_game(address(PLAYER1), game_id).unwrap();
+
+        assert_eq!(
+            state.join_open_game(address(PLAYER2), "player2".into(), PLAYER2, STAKE, token, game_id),
+            Err(Bet2ChessErrors::GameWasCancelled(game_id))
+        );
+
+        assert_eq!(state.token_balance(address(TOKEN), address(PLAYER1)), STAKE);
+        assert_eq!(state.token_balance(address(TOKEN), address(PLAYER2)), STAKE);
+    }
+
+    #[test]
+    fn token_stakes_must_match_the_currency_and_amount_of_the_game() {
+        let mut state = new_state();
+
+        let game_id = state
+            .create_open_game(address(PLAYER1), "player1".into(), PLAYER1, STAKE, Some(address(TOKEN)))
+            .unwrap();
+
+        assert_eq!(
+            state.check_token_game_join(address(PLAYER2), PLAYER2, game_id, address(TOKEN + 1), STAKE),
+            Err(Bet2ChessErrors::StakeCurrencyMismatch)
+        );
+        assert_eq!(
+            state.check_token_game_join(address(PLAYER2), PLAYER2, game_id, address(TOKEN), STAKE + 1),
+            Err(Bet2ChessErrors::BetIsNotTheSameForMatch { game_bet: STAKE, bet_by_user: STAKE + 1 })
+        );
+        assert_eq!(
+            state.check_token_game_join(address(PLAYER1), PLAYER1, game_id, address(TOKEN), STAKE),
+            Err(Bet2ChessErrors::UserCantJoinTheirOwnGame(game_id))
+        );
+    }
+}
@@ -0,0 +1,1265 @@
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+
+use super::runtime::exec;
+use core::cmp::Reverse;
+
+use super::{
+    bet2chess_state::{
+        Bet2ChessErrors,
+        BetAmout,
+        ChessState,
+        GameId,
+        GameStatus,
+        UserWeb2Id
+    },
+    treasury_state::MAX_FEE_BASIS_POINTS
+};
+
+pub type TournamentId = u64;
+
+// Half points, so draws don't need fractions
+pub const POINTS_FOR_WIN: u32 = 2;
+pub const POINTS_FOR_DRAW: u32 = 1;
+// Consecutive wins after which an arena player scores double
+pub const ARENA_STREAK_FOR_BONUS: u32 = 2;
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TournamentFormat {
+    Swiss {
+        rounds: u32
+    },
+    // Single elimination, drawn games are replayed with the colors swapped
+    Knockout,
+    // Players join while the window is open and are paired again as soon
+    // as they finish a game
+    Arena {
+        duration_blocks: u32
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TournamentStatus {
+    Registration,
+    Running,
+    Finished,
+    Cancelled
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TournamentConfig {
+    pub name: String,
+    pub format: TournamentFormat,
+    pub entry_fee: BetAmout,
+    pub max_players: u32,
+    // Share of the prize pool of each final position, in basis points
+    pub prize_distribution: Vec<u16>
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PairingResult {
+    Winner(UserWeb2Id),
+    Draw
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TournamentPairing {
+    pub player1: UserWeb2Id,
+    // `None` if player1 has a bye
+    pub player2: Option<UserWeb2Id>,
+    pub game_id: Option<GameId>,
+    pub result: Option<PairingResult>
+}
+
+impl TournamentPairing {
+    fn is_between(&self, first: UserWeb2Id, second: UserWeb2Id) -> bool {
+        (self.player1 == first && self.player2 == Some(second))
+            || (self.player1 == second && self.player2 == Some(first))
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TournamentPlayer {
+    pub address: ActorId,
+    pub username: String,
+    pub score: u32,
+    pub opponents: Vec<UserWeb2Id>,
+    pub had_bye: bool,
+    // Round the player lost in a knockout tournament
+    pub eliminated_in_round: Option<u32>,
+    // Consecutive wins in an arena
+    pub win_streak: u32
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Tournament {
+    pub config: TournamentConfig,
+    pub status: TournamentStatus,
+    pub players: BTreeMap<UserWeb2Id, TournamentPlayer>,
+    // Players by rating when the tournament started, it breaks the ties
+    // of the pairings
+    pub seeds: Vec<UserWeb2Id>,
+    pub rounds: Vec<Vec<TournamentPairing>>,
+    // Entry fees of the players, in planck
+    pub prize_pool: BetAmout,
+    // Final positions, set when the tournament finishes
+    pub standings: Vec<UserWeb2Id>,
+    // Block the window of an arena closes
+    pub ends_at_block: u32,
+    // Arena players waiting for their next game
+    pub arena_queue: Vec<UserWeb2Id>
+}
+
+impl Tournament {
+    fn score(&self, web2_id: &UserWeb2Id) -> u32 {
+        self.players
+            .get(web2_id)
+            .map_or(0, |player| player.score)
+    }
+
+    fn had_bye(&self, web2_id: &UserWeb2Id) -> bool {
+        self.players
+            .get(web2_id)
+            .is_some_and(|player| player.had_bye)
+    }
+
+    // Sum of the scores of the opponents of the player
+    fn buchholz(&self, web2_id: &UserWeb2Id) -> u32 {
+        self.players
+            .get(web2_id)
+            .map_or(0, |player| player.opponents.iter().map(|opponent| self.score(opponent)).sum())
+    }
+
+    fn round_is_over(&self) -> bool {
+        self.rounds
+            .last()
+            .is_some_and(|pairings| pairings.iter().all(|pairing| pairing.result.is_some()))
+    }
+
+    // Arenas end when their window closes
+    fn is_over(&self) -> bool {
+        match self.config.format {
+            TournamentFormat::Swiss { rounds } => self.rounds.len() as u32 >= rounds,
+            TournamentFormat::Knockout => self.remaining_players().len() <= 1,
+            TournamentFormat::Arena { .. } => false
+        }
+    }
+
+    fn is_arena(&self) -> bool {
+        matches!(self.config.format, TournamentFormat::Arena { .. })
+    }
+
+    // Arenas take players until the window closes
+    fn is_open_for_registration(&self, current_block: u32) -> bool {
+        match self.status {
+            TournamentStatus::Registration => true,
+            TournamentStatus::Running => self.is_arena() && current_block < self.ends_at_block,
+            _ => false
+        }
+    }
+
+    // Current positions and scores
+    pub fn standings_with_scores(&self) -> Vec<(UserWeb2Id, u32)> {
+        self.final_standings()
+            .into_iter()
+            .map(|web2_id| (web2_id, self.score(&web2_id)))
+            .collect()
+    }
+
+    fn remaining_players(&self) -> Vec<UserWeb2Id> {
+        self.seeds
+            .iter()
+            .filter(|web2_id| {
+                self.players
+                    .get(web2_id)
+                    .is_some_and(|player| player.eliminated_in_round.is_none())
+            })
+            .copied()
+            .collect()
+    }
+
+    // Players are ordered by score and paired with the next one they have
+    // not played yet, the lowest ranked player without a bye gets it
+    fn swiss_pairings(&self) -> Vec<(UserWeb2Id, Option<UserWeb2Id>)> {
+        let mut players = self.seeds.clone();
+        players.sort_by_key(|web2_id| Reverse(self.score(web2_id)));
+
+        let mut pairings = Vec::new();
+
+        if players.len() % 2 == 1 {
+            let index = players
+                .iter()
+                .rposition(|web2_id| !self.had_bye(web2_id))
+                .unwrap_or(players.len() - 1);
+
+            pairings.push((players.remove(index), None));
+        }
+
+        while !players.is_empty() {
+            let player = players.remove(0);
+            let opponents = self.players
+                .get(&player)
+                .map(|player| player.opponents.clone())
+                .unwrap_or_default();
+
+            let index = players
+                .iter()
+                .position(|web2_id| !opponents.contains(web2_id))
+                .unwrap_or(0);
+
+            pairings.push((player, Some(players.remove(index))));
+        }
+
+        pairings
+    }
+
+    // The best seed plays the worst one every round, the best seed
+    // without a bye gets it
+    fn knockout_pairings(&self) -> Vec<(UserWeb2Id, Option<UserWeb2Id>)> {
+        let mut players = self.remaining_players();
+        let mut pairings = Vec::new();
+
+        if players.len() % 2 == 1 {
+            let index = players
+                .iter()
+                .position(|web2_id| !self.had_bye(web2_id))
+                .unwrap_or(0);
+
+            pairings.push((players.remove(index), None));
+        }
+
+        while players.len() >= 2 {
+            let top = players.remove(0);
+            let bottom = players.pop();
+            pairings.push((top, bottom));
+        }
+
+        pairings
+    }
+
+    // Swiss tournaments are ordered by score and then by the score of the
+    // opponents, knockouts by the round each player lost in and arenas by
+    // score and then by seed: the players registered when the arena started
+    // by rating, followed by the late joiners in the order they joined
+    fn final_standings(&self) -> Vec<UserWeb2Id> {
+        let mut standings = self.seeds.clone();
+
+        match self.config.format {
+            TournamentFormat::Arena { .. } => {
+                standings.sort_by_key(|web2_id| Reverse(self.score(web2_id)));
+            },
+            TournamentFormat::Swiss { .. } => {
+                standings.sort_by_key(|web2_id| Reverse((self.score(web2_id), self.buchholz(web2_id))));
+            },
+            TournamentFormat::Knockout => {
+                standings.sort_by_key(|web2_id| {
+                    Reverse(self.players
+                        .get(web2_id)
+                        .and_then(|player| player.eliminated_in_round)
+                        .unwrap_or(u32::MAX))
+                });
+            }
+        }
+
+        standings
+    }
+}
+
+#[derive(Default)]
+pub struct Tournaments {
+    pub tournaments: BTreeMap<TournamentId, Tournament>,
+    // Tournament of each game created for a pairing
+    pub tournament_by_game: BTreeMap<GameId, TournamentId>,
+    pub current_tournament_id: TournamentId
+}
+
+impl ChessState {
+    pub fn tournament(&self, tournament_id: TournamentId) -> Option<&Tournament> {
+        self.tournaments
+            .tournaments
+            .get(&tournament_id)
+    }
+
+    pub fn create_tournament(&mut self, caller: ActorId, config: TournamentConfig) -> Result<TournamentId, Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        let distribution_total: u32 = config.prize_distribution
+            .iter()
+            .map(|basis_points| *basis_points as u32)
+            .sum();
+
+        let invalid_format = matches!(
+            config.format,
+            TournamentFormat::Swiss { rounds: 0 } | TournamentFormat::Arena { duration_blocks: 0 }
+        );
+
+        if config.name.is_empty()
+            || config.max_players < 2
+            || invalid_format
+            || config.prize_distribution.is_empty()
+            || config.prize_distribution.len() as u32 > config.max_players
+            || distribution_total != MAX_FEE_BASIS_POINTS as u32
+        {
+            return Err(Bet2ChessErrors::InvalidTournamentConfig);
+        }
+
+        let tournament_id = self.tournaments.current_tournament_id
+            .checked_add(1)
+            .ok_or(Bet2ChessErrors::CantIncrementTournamentsIdItOverflow)?;
+
+        self.tournaments.current_tournament_id = tournament_id;
+        self.tournaments.tournaments.insert(tournament_id, Tournament {
+            config,
+            status: TournamentStatus::Registration,
+            players: BTreeMap::new(),
+            seeds: Vec::new(),
+            rounds: Vec::new(),
+            prize_pool: 0,
+            standings: Vec::new(),
+            ends_at_block: 0,
+            arena_queue: Vec::new()
+        });
+
+        Ok(tournament_id)
+    }
+
+    // The entry fee is credited back to the player if the registration fails
+    pub fn register_in_tournament(
+        &mut self,
+        address: ActorId,
+        username: String,
+        user_web2_id: UserWeb2Id,
+        tournament_id: TournamentId,
+        entry_fee: BetAmout
+    ) -> Result<(), Bet2ChessErrors> {
+        if let Err(error) = self.check_tournament_registration(user_web2_id, tournament_id, entry_fee) {
+            return Err(self.refund_bet(address, entry_fee, None, error));
+        }
+
+        let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) else {
+            return Err(self.refund_bet(address, entry_fee, None, Bet2ChessErrors::TournamentDoesNotExists(tournament_id)));
+        };
+
+        tournament.prize_pool += entry_fee;
+        tournament.players.insert(user_web2_id, TournamentPlayer {
+            address,
+            username,
+            score: 0,
+            opponents: Vec::new(),
+            had_bye: false,
+            eliminated_in_round: None,
+            win_streak: 0
+        });
+
+        // Players that join a running arena wait for their first game
+        if tournament.status == TournamentStatus::Running {
+            tournament.seeds.push(user_web2_id);
+            tournament.arena_queue.push(user_web2_id);
+            self.pair_arena_players(tournament_id);
+        }
+
+        Ok(())
+    }
+
+    pub fn leave_tournament(&mut self, user_web2_id: UserWeb2Id, tournament_id: TournamentId) -> Result<(), Bet2ChessErrors> {
+        let tournament = self.tournaments
+            .tournaments
+            .get_mut(&tournament_id)
+            .ok_or(Bet2ChessErrors::TournamentDoesNotExists(tournament_id))?;
+
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Bet2ChessErrors::TournamentRegistrationIsClosed(tournament_id));
+        }
+
+        let player = tournament.players
+            .remove(&user_web2_id)
+            .ok_or(Bet2ChessErrors::PlayerIsNotInTournament(tournament_id))?;
+
+        let entry_fee = tournament.config.entry_fee;
+        tournament.prize_pool -= entry_fee;
+
+        self.credit(player.address, entry_fee);
+
+        Ok(())
+    }
+
+    // Only tournaments that have not started can be cancelled, every
+    // player gets the entry fee back
+    pub fn cancel_tournament(&mut self, caller: ActorId, tournament_id: TournamentId) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        let tournament = self.tournaments
+            .tournaments
+            .get_mut(&tournament_id)
+            .ok_or(Bet2ChessErrors::TournamentDoesNotExists(tournament_id))?;
+
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Bet2ChessErrors::TournamentRegistrationIsClosed(tournament_id));
+        }
+
+        let entry_fee = tournament.config.entry_fee;
+        let addresses: Vec<ActorId> = tournament.players
+            .values()
+            .map(|player| player.address)
+            .collect();
+
+        tournament.status = TournamentStatus::Cancelled;
+        tournament.prize_pool = 0;
+
+        for address in addresses {
+            self.credit(address, entry_fee);
+        }
+
+        Ok(())
+    }
+
+    pub fn start_tournament(&mut self, caller: ActorId, tournament_id: TournamentId) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        let tournament = self.tournaments
+            .tournaments
+            .get(&tournament_id)
+            .ok_or(Bet2ChessErrors::TournamentDoesNotExists(tournament_id))?;
+
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Bet2ChessErrors::TournamentRegistrationIsClosed(tournament_id));
+        }
+
+        // An arena opens its window even without players, they can join
+        // while it is open
+        let arena_duration = match tournament.config.format {
+            TournamentFormat::Arena { duration_blocks } => Some(duration_blocks),
+            _ => None
+        };
+
+        if arena_duration.is_none() && tournament.players.len() < 2 {
+            return Err(Bet2ChessErrors::NotEnoughPlayersInTournament(tournament_id));
+        }
+
+        let mut seeds: Vec<UserWeb2Id> = tournament.players.keys().copied().collect();
+        seeds.sort_by_key(|web2_id| Reverse(self.player_rating(*web2_id)));
+
+        if let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) {
+            tournament.status = TournamentStatus::Running;
+            tournament.seeds = seeds.clone();
+        }
+
+        let Some(duration_blocks) = arena_duration else {
+            return self.start_tournament_round(tournament_id);
+        };
+
+        if let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) {
+            tournament.ends_at_block = exec::block_height().saturating_add(duration_blocks);
+            tournament.arena_queue = seeds;
+            tournament.rounds.push(Vec::new());
+        }
+
+        // If the message can't be scheduled the arena can still be closed
+        // with `CloseArena` once its window is over
+        let _ = Self::send_delayed_service_call("Tournament", "CloseArena", tournament_id, duration_blocks);
+
+        self.pair_arena_players(tournament_id);
+
+        Ok(())
+    }
+
+    // Anyone can close an arena once its window is over, the contract also
+    // schedules a message to itself to do it when the arena starts. Games
+    // that have not ended yet don't count
+    pub fn close_arena(&mut self, tournament_id: TournamentId) -> Result<(), Bet2ChessErrors> {
+        let tournament = self.tournament(tournament_id)
+            .ok_or(Bet2ChessErrors::TournamentDoesNotExists(tournament_id))?;
+
+        if !tournament.is_arena() {
+            return Err(Bet2ChessErrors::TournamentIsNotAnArena(tournament_id));
+        }
+
+        if tournament.status != TournamentStatus::Running || exec::block_height() < tournament.ends_at_block {
+            return Err(Bet2ChessErrors::ArenaHasNotEnded(tournament_id));
+        }
+
+        if let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) {
+            tournament.arena_queue.clear();
+        }
+
+        self.finish_tournament(tournament_id);
+
+        Ok(())
+    }
+
+    // Called when the result of a game is recorded, the next round starts
+    // once every game of the current one has ended
+    pub(crate) fn record_tournament_result(&mut self, game_id: GameId) {
+        let Some(tournament_id) = self.tournaments.tournament_by_game.get(&game_id).copied() else {
+            return;
+        };
+
+        let Some(game_data) = self.games_by_id.get(&game_id) else {
+            return;
+        };
+
+        let GameStatus::Ended { winner } = game_data.status else {
+            return;
+        };
+
+        let players = (game_data.player1_web2_id, game_data.player2_web2_id);
+        let result = match winner {
+            None => PairingResult::Draw,
+            Some(winner) if winner == game_data.player1 => PairingResult::Winner(players.0),
+            Some(_) => PairingResult::Winner(players.1)
+        };
+
+        let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) else {
+            return;
+        };
+
+        if tournament.status != TournamentStatus::Running {
+            return;
+        }
+
+        if tournament.is_arena() {
+            self.record_arena_result(tournament_id, game_id, players, result);
+            return;
+        }
+
+        let round = tournament.rounds.len() as u32;
+        let format = tournament.config.format;
+
+        let Some(pairing) = tournament.rounds
+            .last_mut()
+            .and_then(|pairings| pairings.iter_mut().find(|pairing| pairing.game_id == Some(game_id)))
+        else {
+            return;
+        };
+
+        match (result, format) {
+            (PairingResult::Draw, TournamentFormat::Knockout) => {
+                // The game is replayed, player2 of the drawn game gets white.
+                // If the game can't be created the pairing stays pending and
+                // `create_pending_tournament_games` retries it
+                pairing.player1 = players.1;
+                pairing.player2 = Some(players.0);
+                pairing.game_id = None;
+
+                let _ = self.create_pending_tournament_games(tournament_id);
+
+                return;
+            },
+            (PairingResult::Draw, _) => {
+                pairing.result = Some(result);
+
+                for web2_id in [players.0, players.1] {
+                    if let Some(player) = tournament.players.get_mut(&web2_id) {
+                        player.score += POINTS_FOR_DRAW;
+                    }
+                }
+            },
+            (PairingResult::Winner(winner), _) => {
+                pairing.result = Some(result);
+
+                let loser = if winner == players.0 { players.1 } else { players.0 };
+
+                if let Some(player) = tournament.players.get_mut(&winner) {
+                    player.score += POINTS_FOR_WIN;
+                }
+
+                if let (TournamentFormat::Knockout, Some(player)) = (format, tournament.players.get_mut(&loser)) {
+                    player.eliminated_in_round = Some(round);
+                }
+            }
+        }
+
+        if !tournament.round_is_over() {
+            return;
+        }
+
+        if tournament.is_over() {
+            self.finish_tournament(tournament_id);
+        } else {
+            let _ = self.start_tournament_round(tournament_id);
+        }
+    }
+
+    // Tournament games can't be aborted, so admins and result oracles end
+    // the game of a player that doesn't show up or runs out of time by
+    // giving the win to the opponent
+    pub fn forfeit_tournament_game(
+        &mut self,
+        caller: ActorId,
+        game_id: GameId,
+        absent_player: UserWeb2Id
+    ) -> Result<GameId, Bet2ChessErrors> {
+        if !self.is_tournament_game(game_id) {
+            return Err(Bet2ChessErrors::NotATournamentGame(game_id));
+        }
+
+        let game_data = self.games_by_id
+            .get(&game_id)
+            .ok_or(Bet2ChessErrors::GameIdDoesNotExists(game_id))?;
+
+        let winner = if absent_player == game_data.player1_web2_id {
+            game_data.player2
+        } else if absent_player == game_data.player2_web2_id {
+            game_data.player1
+        } else {
+            return Err(Bet2ChessErrors::UserIsNotAPlayerOfTheGame(game_id));
+        };
+
+        self.end_match(game_id, caller, Some(winner))
+    }
+
+    pub(crate) fn is_tournament_game(&self, game_id: GameId) -> bool {
+        self.tournaments
+            .tournament_by_game
+            .contains_key(&game_id)
+    }
+
+    fn check_tournament_registration(
+        &self,
+        user_web2_id: UserWeb2Id,
+        tournament_id: TournamentId,
+        entry_fee: BetAmout
+    ) -> Result<(), Bet2ChessErrors> {
+        let tournament = self.tournament(tournament_id)
+            .ok_or(Bet2ChessErrors::TournamentDoesNotExists(tournament_id))?;
+
+        if !tournament.is_open_for_registration(exec::block_height()) {
+            return Err(Bet2ChessErrors::TournamentRegistrationIsClosed(tournament_id));
+        }
+
+        if tournament.config.entry_fee != entry_fee {
+            return Err(Bet2ChessErrors::EntryFeeIsNotTheSame {
+                entry_fee: tournament.config.entry_fee,
+                sent: entry_fee
+            });
+        }
+
+        if tournament.players.contains_key(&user_web2_id) {
+            return Err(Bet2ChessErrors::PlayerAlreadyInTournament(tournament_id));
+        }
+
+        if tournament.players.len() as u32 >= tournament.config.max_players {
+            return Err(Bet2ChessErrors::TournamentIsFull(tournament_id));
+        }
+
+        Ok(())
+    }
+
+    // Wins and draws score double after `ARENA_STREAK_FOR_BONUS` consecutive
+    // wins, then both players go back to the queue while the window is open
+    fn record_arena_result(
+        &mut self,
+        tournament_id: TournamentId,
+        game_id: GameId,
+        players: (UserWeb2Id, UserWeb2Id),
+        result: PairingResult
+    ) {
+        let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) else {
+            return;
+        };
+
+        if let Some(pairing) = tournament.rounds
+            .last_mut()
+            .and_then(|pairings| pairings.iter_mut().find(|pairing| pairing.game_id == Some(game_id)))
+        {
+            pairing.result = Some(result);
+        }
+
+        for web2_id in [players.0, players.1] {
+            let Some(player) = tournament.players.get_mut(&web2_id) else {
+                continue;
+            };
+
+            let multiplier = if player.win_streak >= ARENA_STREAK_FOR_BONUS { 2 } else { 1 };
+
+            match result {
+                PairingResult::Winner(winner) if winner == web2_id => {
+                    player.score += POINTS_FOR_WIN * multiplier;
+                    player.win_streak += 1;
+                },
+                PairingResult::Winner(_) => {
+                    player.win_streak = 0;
+                },
+                PairingResult::Draw => {
+                    player.score += POINTS_FOR_DRAW * multiplier;
+                    player.win_streak = 0;
+                }
+            }
+        }
+
+        if exec::block_height() < tournament.ends_at_block {
+            tournament.arena_queue.extend([players.0, players.1]);
+            self.pair_arena_players(tournament_id);
+        }
+    }
+
+    // Pairs the players waiting in the arena queue in the order they
+    // arrived, avoiding the opponent of their last game when possible
+    fn pair_arena_players(&mut self, tournament_id: TournamentId) {
+        loop {
+            let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) else {
+                return;
+            };
+
+            if tournament.arena_queue.len() < 2 {
+                return;
+            }
+
+            let player1 = tournament.arena_queue.remove(0);
+            let last_opponent = tournament.players
+                .get(&player1)
+                .and_then(|player| player.opponents.last().copied());
+
+            let index = tournament.arena_queue
+                .iter()
+                .position(|web2_id| Some(*web2_id) != last_opponent)
+                .unwrap_or(0);
+
+            let player2 = tournament.arena_queue.remove(index);
+
+            // The pairing is only recorded once its game exists, if the game
+            // can't be created both players go back to their places in the
+            // queue and are paired again when the next game ends
+            let game_id = match self.create_tournament_game(tournament_id, player1, player2) {
+                Ok(game_id) => game_id,
+                Err(_) => {
+                    if let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) {
+                        tournament.arena_queue.insert(index, player2);
+                        tournament.arena_queue.insert(0, player1);
+                    }
+
+                    return;
+                }
+            };
+
+            let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) else {
+                return;
+            };
+
+            for (player, opponent) in [(player1, player2), (player2, player1)] {
+                if let Some(player) = tournament.players.get_mut(&player) {
+                    player.opponents.push(opponent);
+                }
+            }
+
+            if let Some(pairings) = tournament.rounds.last_mut() {
+                pairings.push(TournamentPairing {
+                    player1,
+                    player2: Some(player2),
+                    game_id: Some(game_id),
+                    result: None
+                });
+            }
+        }
+    }
+
+    // Pairs the players and creates the games of the round, byes count
+    // as a win
+    fn start_tournament_round(&mut self, tournament_id: TournamentId) -> Result<(), Bet2ChessErrors> {
+        let tournament = self.tournaments
+            .tournaments
+            .get_mut(&tournament_id)
+            .ok_or(Bet2ChessErrors::TournamentDoesNotExists(tournament_id))?;
+
+        let pairings = match tournament.config.format {
+            TournamentFormat::Swiss { .. } => tournament.swiss_pairings(),
+            TournamentFormat::Knockout => tournament.knockout_pairings(),
+            TournamentFormat::Arena { .. } => Vec::new()
+        };
+
+        let mut round = Vec::new();
+
+        for (player1, player2) in pairings.iter().copied() {
+            match player2 {
+                None => {
+                    if let Some(player) = tournament.players.get_mut(&player1) {
+                        player.score += POINTS_FOR_WIN;
+                        player.had_bye = true;
+                    }
+                },
+                Some(player2) => {
+                    for (player, opponent) in [(player1, player2), (player2, player1)] {
+                        if let Some(player) = tournament.players.get_mut(&player) {
+                            player.opponents.push(opponent);
+                        }
+                    }
+                }
+            }
+
+            round.push(TournamentPairing {
+                player1,
+                player2,
+                game_id: None,
+                result: player2.is_none().then_some(PairingResult::Winner(player1))
+            });
+        }
+
+        tournament.rounds.push(round);
+
+        // The round is kept even if a game can't be created, its pairing
+        // stays pending until `create_pending_tournament_games` creates it
+        let _ = self.create_pending_tournament_games(tournament_id);
+
+        // A round of byes only happens with one player left, but it is
+        // checked anyway so the tournament never gets stuck
+        let tournament_finished = self.tournament(tournament_id)
+            .is_some_and(|tournament| tournament.round_is_over() && tournament.is_over());
+
+        if tournament_finished {
+            self.finish_tournament(tournament_id);
+        }
+
+        Ok(())
+    }
+
+    // Creates the games of the pairings of the current round that don't have
+    // one yet, returns how many were created. Anyone can call it, the games
+    // are only missing if they could not be created when the round was paired
+    pub fn create_pending_tournament_games(&mut self, tournament_id: TournamentId) -> Result<u32, Bet2ChessErrors> {
+        let tournament = self.tournament(tournament_id)
+            .ok_or(Bet2ChessErrors::TournamentDoesNotExists(tournament_id))?;
+
+        if tournament.status != TournamentStatus::Running {
+            return Err(Bet2ChessErrors::TournamentIsNotRunning(tournament_id));
+        }
+
+        let pending: Vec<(UserWeb2Id, UserWeb2Id)> = tournament.rounds
+            .last()
+            .map(|pairings| {
+                pairings
+                    .iter()
+                    .filter(|pairing| pairing.game_id.is_none() && pairing.result.is_none())
+                    .filter_map(|pairing| pairing.player2.map(|player2| (pairing.player1, player2)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut created = 0;
+
+        for (player1, player2) in pending {
+            self.create_tournament_game(tournament_id, player1, player2)?;
+            created += 1;
+        }
+
+        Ok(created)
+    }
+
+    // Tournament games are free, the prize pool comes from the entry fees
+    fn create_tournament_game(
+        &mut self,
+        tournament_id: TournamentId,
+        player1: UserWeb2Id,
+        player2: UserWeb2Id
+    ) -> Result<GameId, Bet2ChessErrors> {
+        let tournament = self.tournament(tournament_id)
+            .ok_or(Bet2ChessErrors::TournamentDoesNotExists(tournament_id))?;
+
+        let (Some(white), Some(black)) = (
+            tournament.players.get(&player1).cloned(),
+            tournament.players.get(&player2).cloned()
+        ) else {
+            return Err(Bet2ChessErrors::PlayerIsNotInTournament(tournament_id));
+        };
+
+        let game_id = self.create_match(white.address, white.username, player1, 0, None)?;
+        self.join_match(black.address, black.username, player2, 0, None, game_id)?;
+
+        self.tournaments.tournament_by_game.insert(game_id, tournament_id);
+
+        let pairing = self.tournaments
+            .tournaments
+            .get_mut(&tournament_id)
+            .and_then(|tournament| tournament.rounds.last_mut())
+            .and_then(|pairings| pairings.iter_mut().find(|pairing| pairing.game_id.is_none() && pairing.is_between(player1, player2)));
+
+        if let Some(pairing) = pairing {
+            pairing.game_id = Some(game_id);
+        }
+
+        Ok(game_id)
+    }
+
+    // The platform fee is taken from the prize pool and the rest is split
+    // between the final positions. If fewer players finished than positions
+    // with a prize, the shares of the missing positions are split between
+    // the others and the planck lost to rounding go to the winner
+    fn finish_tournament(&mut self, tournament_id: TournamentId) {
+        let Some(tournament) = self.tournaments.tournaments.get_mut(&tournament_id) else {
+            return;
+        };
+
+        let standings = tournament.final_standings();
+        let prize_pool = tournament.prize_pool;
+
+        let prizes: Vec<(ActorId, u16)> = standings
+            .iter()
+            .zip(tournament.config.prize_distribution.iter())
+            .filter_map(|(web2_id, basis_points)| {
+                tournament.players
+                    .get(web2_id)
+                    .map(|player| (player.address, *basis_points))
+            })
+            .collect();
+
+        tournament.status = TournamentStatus::Finished;
+        tournament.standings = standings;
+        tournament.prize_pool = 0;
+
+        let left = self.collect_fee(prize_pool, None);
+        let total_basis_points: u128 = prizes
+            .iter()
+            .map(|(_, basis_points)| *basis_points as u128)
+            .sum::<u128>()
+            .max(1);
+
+        let mut credited = 0;
+
+        for (address, basis_points) in prizes.iter() {
+            let prize = (U256::from(left) * U256::from(*basis_points) / U256::from(total_basis_points)).as_u128();
+            credited += prize;
+            self.credit(*address, prize);
+        }
+
+        if let Some((winner, _)) = prizes.first() {
+            self.credit(*winner, left - credited);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        runtime::mock,
+        test_utils::{
+            address,
+            new_state,
+            start_game,
+            ADMIN,
+            STAKE
+        }
+    };
+
+    const ENTRY_FEE: BetAmout = 7;
+    const PLAYERS: [u64; 5] = [10, 11, 12, 13, 14];
+
+    // Every player has the default rating, so the seeds follow the web2 ids
+    fn tournament_with(
+        state: &mut ChessState,
+        format: TournamentFormat,
+        players: &[u64],
+        prize_distribution: Vec<u16>
+    ) -> TournamentId {
+        let tournament_id = state.create_tournament(address(ADMIN), TournamentConfig {
+            name: "Open".into(),
+            format,
+            entry_fee: ENTRY_FEE,
+            max_players: 8,
+            prize_distribution
+        }).unwrap();
+
+        for player in players {
+            state
+                .register_in_tournament(address(*player), format!("player{player}"), *player, tournament_id, ENTRY_FEE)
+                .unwrap();
+        }
+
+        tournament_id
+    }
+
+    fn started_tournament(state: &mut ChessState, format: TournamentFormat, players: &[u64]) -> TournamentId {
+        let tournament_id = tournament_with(state, format, players, Vec::from([MAX_FEE_BASIS_POINTS]));
+        state.start_tournament(address(ADMIN), tournament_id).unwrap();
+
+        tournament_id
+    }
+
+    fn current_round(state: &ChessState, tournament_id: TournamentId) -> &[TournamentPairing] {
+        state.tournament(tournament_id).unwrap().rounds.last().unwrap()
+    }
+
+    fn player_mut(state: &mut ChessState, tournament_id: TournamentId, web2_id: UserWeb2Id) -> &mut TournamentPlayer {
+        state.tournaments
+            .tournaments
+            .get_mut(&tournament_id)
+            .unwrap()
+            .players
+            .get_mut(&web2_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn swiss_bye_goes_to_the_lowest_ranked_player_without_one() {
+        let mut state = new_state();
+        let tournament_id = started_tournament(&mut state, TournamentFormat::Swiss { rounds: 3 }, &PLAYERS);
+        let round: Vec<(UserWeb2Id, Option<UserWeb2Id>)> = current_round(&state, tournament_id)
+            .iter()
+            .map(|pairing| (pairing.player1, pairing.player2))
+            .collect();
+
+        assert_eq!(round, [(14, None), (10, Some(11)), (12, Some(13))]);
+        assert!(current_round(&state, tournament_id)[0].result == Some(PairingResult::Winner(14)));
+
+        let tournament = state.tournament(tournament_id).unwrap();
+        assert!(tournament.players[&14].had_bye);
+        assert_eq!(tournament.players[&14].score, POINTS_FOR_WIN);
+
+        // 14 leads with the points of the bye, 13 is now the lowest without one
+        assert_eq!(tournament.swiss_pairings(), [(13, None), (14, Some(10)), (11, Some(12))]);
+    }
+
+    #[test]
+    fn swiss_players_are_not_paired_again_with_their_opponents() {
+        let mut state = new_state();
+        let tournament_id = tournament_with(&mut state, TournamentFormat::Swiss { rounds: 3 }, &PLAYERS[..4], Vec::from([MAX_FEE_BASIS_POINTS]));
+
+        state.tournaments.tournaments.get_mut(&tournament_id).unwrap().seeds = PLAYERS[..4].to_vec();
+        player_mut(&mut state, tournament_id, 10).opponents.push(11);
+
+        assert_eq!(state.tournament(tournament_id).unwrap().swiss_pairings(), [(10, Some(12)), (11, Some(13))]);
+    }
+
+    #[test]
+    fn knockout_bye_goes_to_the_best_seed_without_one() {
+        let mut state = new_state();
+        let tournament_id = tournament_with(&mut state, TournamentFormat::Knockout, &PLAYERS, Vec::from([MAX_FEE_BASIS_POINTS]));
+
+        state.tournaments.tournaments.get_mut(&tournament_id).unwrap().seeds = PLAYERS.to_vec();
+
+        assert_eq!(
+            state.tournament(tournament_id).unwrap().knockout_pairings(),
+            [(10, None), (11, Some(14)), (12, Some(13))]
+        );
+
+        player_mut(&mut state, tournament_id, 10).had_bye = true;
+        player_mut(&mut state, tournament_id, 13).eliminated_in_round = Some(1);
+
+        // Four players left, nobody gets a bye
+        assert_eq!(
+            state.tournament(tournament_id).unwrap().knockout_pairings(),
+            [(10, Some(14)), (11, Some(12))]
+        );
+
+        player_mut(&mut state, tournament_id, 14).eliminated_in_round = Some(1);
+
+        assert_eq!(
+            state.tournament(tournament_id).unwrap().knockout_pairings(),
+            [(11, None), (10, Some(12))]
+        );
+    }
+
+    #[test]
+    fn drawn_knockout_games_are_replayed_with_the_colors_swapped() {
+        let mut state = new_state();
+        let tournament_id = started_tournament(&mut state, TournamentFormat::Knockout, &PLAYERS[..2]);
+
+        let game_id = current_round(&state, tournament_id)[0].game_id.unwrap();
+        state.end_match(game_id, address(ADMIN), None).unwrap();
+
+        let pairing = &current_round(&state, tournament_id)[0];
+        let replay_id = pairing.game_id.unwrap();
+
+        assert_eq!((pairing.player1, pairing.player2), (11, Some(10)));
+        assert!(pairing.result.is_none());
+        assert_ne!(replay_id, game_id);
+
+        let replay = state.game_by_id(replay_id).unwrap();
+        assert_eq!((replay.player1_web2_id, replay.player2_web2_id), (11, 10));
+        assert_eq!(state.tournament(tournament_id).unwrap().rounds.len(), 1);
+    }
+
+    #[test]
+    fn prizes_are_split_by_basis_points_and_the_dust_goes_to_the_winner() {
+        let mut state = new_state();
+        let tournament_id = tournament_with(&mut state, TournamentFormat::Swiss { rounds: 1 }, &PLAYERS[..3], Vec::from([6_000, 3_000, 1_000]));
+
+        state.tournaments.tournaments.get_mut(&tournament_id).unwrap().seeds = PLAYERS[..3].to_vec();
+        player_mut(&mut state, tournament_id, 12).score = 4;
+        player_mut(&mut state, tournament_id, 10).score = 2;
+
+        state.finish_tournament(tournament_id);
+
+        // 21 planck: 12.6, 6.3 and 2.1 round down to 12, 6 and 2
+        let tournament = state.tournament(tournament_id).unwrap();
+        assert_eq!(tournament.standings, [12, 10, 11]);
+        assert!(tournament.status == TournamentStatus::Finished);
+        assert_eq!(state.balance(address(12)), 13);
+        assert_eq!(state.balance(address(10)), 6);
+        assert_eq!(state.balance(address(11)), 2);
+    }
+
+    #[test]
+    fn shares_of_missing_positions_are_split_between_the_others() {
+        let mut state = new_state();
+        let tournament_id = tournament_with(&mut state, TournamentFormat::Swiss { rounds: 1 }, &PLAYERS[..2], Vec::from([5_000, 3_000, 2_000]));
+
+        state.start_tournament(address(ADMIN), tournament_id).unwrap();
+
+        let game_id = current_round(&state, tournament_id)[0].game_id.unwrap();
+        state.resign(address(10), game_id).unwrap();
+
+        // 14 planck split 5 to 3: 8.75 and 5.25, plus the dust to the winner
+        assert_eq!(state.tournament(tournament_id).unwrap().standings, [11, 10]);
+        assert_eq!(state.balance(address(11)), 9);
+        assert_eq!(state.balance(address(10)), 5);
+    }
+
+    #[test]
+    fn pending_games_of_the_round_can_be_created_again() {
+        let mut state = new_state();
+        let tournament_id = tournament_with(&mut state, TournamentFormat::Swiss { rounds: 2 }, &PLAYERS[..4], Vec::from([MAX_FEE_BASIS_POINTS]));
+
+        assert_eq!(
+            state.create_pending_tournament_games(tournament_id),
+            Err(Bet2ChessErrors::TournamentIsNotRunning(tournament_id))
+        );
+
+        state.start_tournament(address(ADMIN), tournament_id).unwrap();
+        assert_eq!(state.create_pending_tournament_games(tournament_id), Ok(0));
+
+        // As if the game of the second pairing could not be created
+        state.tournaments.tournaments.get_mut(&tournament_id).unwrap().rounds[0][1].game_id = None;
+
+        assert_eq!(state.create_pending_tournament_games(tournament_id), Ok(1));
+        assert!(current_round(&state, tournament_id).iter().all(|pairing| pairing.game_id.is_some()));
+    }
+
+    #[test]
+    fn forfeited_games_are_won_by_the_opponent() {
+        let mut state = new_state();
+        let tournament_id = started_tournament(&mut state, TournamentFormat::Swiss { rounds: 2 }, &PLAYERS[..4]);
+        let game_id = current_round(&state, tournament_id)[0].game_id.unwrap();
+        let other_game = start_game(&mut state, 20, 21, (STAKE, STAKE));
+
+        assert_eq!(
+            state.forfeit_tournament_game(address(ADMIN), other_game, 20),
+            Err(Bet2ChessErrors::NotATournamentGame(other_game))
+        );
+        assert_eq!(
+            state.forfeit_tournament_game(address(ADMIN), game_id, 12),
+            Err(Bet2ChessErrors::UserIsNotAPlayerOfTheGame(game_id))
+        );
+        assert_eq!(
+            state.forfeit_tournament_game(address(10), game_id, 11),
+            Err(Bet2ChessErrors::OnlyAdminsCanEndGames)
+        );
+
+        state.forfeit_tournament_game(address(ADMIN), game_id, 10).unwrap();
+
+        assert!(state.game_by_id(game_id).unwrap().status == GameStatus::Ended { winner: Some(address(11)) });
+        assert_eq!(state.tournament(tournament_id).unwrap().players[&11].score, POINTS_FOR_WIN);
+    }
+
+    const ARENA: TournamentFormat = TournamentFormat::Arena { duration_blocks: 100 };
+
+    // Game of the arena that has not ended yet
+    fn arena_game(state: &ChessState, tournament_id: TournamentId) -> GameId {
+        current_round(state, tournament_id)
+            .iter()
+            .find(|pairing| pairing.result.is_none())
+            .and_then(|pairing| pairing.game_id)
+            .unwrap()
+    }
+
+    fn arena_player(state: &ChessState, tournament_id: TournamentId, web2_id: UserWeb2Id) -> (u32, u32) {
+        let player = &state.tournament(tournament_id).unwrap().players[&web2_id];
+
+        (player.score, player.win_streak)
+    }
+
+    #[test]
+    fn arena_wins_score_double_after_a_streak() {
+        let mut state = new_state();
+        let tournament_id = started_tournament(&mut state, ARENA, &PLAYERS[..2]);
+
+        for (score, streak) in [(2, 1), (4, 2), (8, 3)] {
+            let game_id = arena_game(&state, tournament_id);
+            state.resign(address(11), game_id).unwrap();
+
+            assert_eq!(arena_player(&state, tournament_id, 10), (score, streak));
+        }
+
+        // A draw also scores double but ends the streak
+        let game_id = arena_game(&state, tournament_id);
+        state.end_match(game_id, address(ADMIN), None).unwrap();
+
+        assert_eq!(arena_player(&state, tournament_id, 10), (10, 0));
+        assert_eq!(arena_player(&state, tournament_id, 11), (POINTS_FOR_DRAW, 0));
+
+        let game_id = arena_game(&state, tournament_id);
+        state.resign(address(11), game_id).unwrap();
+
+        assert_eq!(arena_player(&state, tournament_id, 10), (12, 1));
+    }
+
+    #[test]
+    fn arena_players_are_paired_in_the_order_they_finish() {
+        let mut state = new_state();
+        let tournament_id = started_tournament(&mut state, ARENA, &PLAYERS[..3]);
+
+        let game_id = arena_game(&state, tournament_id);
+        assert_eq!(state.tournament(tournament_id).unwrap().arena_queue, [12]);
+
+        // 12 waited the longest, it plays the first player of the queue
+        state.resign(address(11), game_id).unwrap();
+
+        let pairing = current_round(&state, tournament_id).last().unwrap();
+        assert_eq!((pairing.player1, pairing.player2), (12, Some(10)));
+        assert_eq!(state.tournament(tournament_id).unwrap().arena_queue, [11]);
+
+        // 11 just played 10, so it plays 12 even if 10 is first in the queue
+        state.resign(address(10), arena_game(&state, tournament_id)).unwrap();
+
+        let pairing = current_round(&state, tournament_id).last().unwrap();
+        assert_eq!((pairing.player1, pairing.player2), (11, Some(12)));
+        assert_eq!(state.tournament(tournament_id).unwrap().arena_queue, [10]);
+    }
+
+    #[test]
+    fn arenas_close_once_their_window_is_over() {
+        let mut state = new_state();
+        let tournament_id = started_tournament(&mut state, ARENA, &PLAYERS[..2]);
+
+        assert_eq!(state.close_arena(tournament_id), Err(Bet2ChessErrors::ArenaHasNotEnded(tournament_id)));
+
+        // Players can still join while the window is open
+        state.register_in_tournament(address(12), "player12".into(), 12, tournament_id, ENTRY_FEE).unwrap();
+        assert_eq!(state.tournament(tournament_id).unwrap().arena_queue, [12]);
+
+        mock::set_block_height(100);
+
+        assert_eq!(
+            state.register_in_tournament(address(13), "player13".into(), 13, tournament_id, ENTRY_FEE),
+            Err(Bet2ChessErrors::TournamentRegistrationIsClosed(tournament_id))
+        );
+
+        // Games that end after the window are not paired again
+        state.resign(address(11), arena_game(&state, tournament_id)).unwrap();
+        assert_eq!(current_round(&state, tournament_id).len(), 1);
+
+        state.close_arena(tournament_id).unwrap();
+
+        let tournament = state.tournament(tournament_id).unwrap();
+        assert!(tournament.status == TournamentStatus::Finished);
+        assert!(tournament.arena_queue.is_empty());
+        assert_eq!(tournament.standings[0], 10);
+        assert_eq!(state.balance(address(10)), 3 * ENTRY_FEE);
+        assert_eq!(state.balance(address(13)), ENTRY_FEE);
+    }
+}
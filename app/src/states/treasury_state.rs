@@ -0,0 +1,294 @@
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+
+use super::runtime::msg;
+
+use crate::services::bet2chess_service::Bet2ChessEvents;
+
+use super::bet2chess_state::{
+    Bet2ChessErrors,
+    ChessState
+};
+
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+// Fee taken from the pot of every settled game, amounts in planck.
+// `min_fee` and `max_fee` only apply to pots in VARA, pots in tokens
+// pay the percentage without limits (see `fee_for_token_pot`)
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Default)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FeeConfig {
+    pub fee_basis_points: u16,
+    pub min_fee: u128,
+    pub max_fee: u128
+}
+
+impl FeeConfig {
+    // The fee is never bigger than the pot
+    pub fn fee_for_pot(&self, pot: u128) -> u128 {
+        if self.fee_basis_points == 0 {
+            return 0;
+        }
+
+        let fee = pot.saturating_mul(self.fee_basis_points as u128) / MAX_FEE_BASIS_POINTS as u128;
+
+        fee.clamp(self.min_fee, self.max_fee)
+            .min(pot)
+    }
+
+    // The minimum and maximum are in planck, so pots in tokens only pay
+    // the percentage
+    pub fn fee_for_token_pot(&self, pot: u128) -> u128 {
+        pot.saturating_mul(self.fee_basis_points as u128) / MAX_FEE_BASIS_POINTS as u128
+    }
+}
+
+#[derive(Default)]
+pub struct Treasury {
+    pub fee_config: FeeConfig,
+    // Fees that have not been withdrawn yet
+    pub balance: u128,
+    // Fees collected since the contract was deployed
+    pub total_fees_collected: u128,
+    // Fees of the games with stakes in tokens, by token program
+    pub token_balances: BTreeMap<ActorId, u128>
+}
+
+impl ChessState {
+    pub fn set_fee_config(&mut self, caller: ActorId, fee_config: FeeConfig) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        // With a maximum of zero a percentage would never be charged
+        if fee_config.fee_basis_points > MAX_FEE_BASIS_POINTS
+            || fee_config.min_fee > fee_config.max_fee
+            || (fee_config.fee_basis_points > 0 && fee_config.max_fee == 0)
+        {
+            return Err(Bet2ChessErrors::InvalidFeeConfig);
+        }
+
+        self.treasury.fee_config = fee_config;
+
+        Ok(())
+    }
+
+    pub fn withdraw_treasury(&mut self, caller: ActorId, to: ActorId, amount: u128) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        if amount > self.treasury.balance {
+            return Err(Bet2ChessErrors::InsufficientTreasuryBalance(self.treasury.balance));
+        }
+
+        self.treasury.balance -= amount;
+
+        // If the message can't be sent the fees are kept in the treasury
+        let result = msg::send(to, Bet2ChessEvents::TreasuryWithdrawn { to, amount }, amount);
+
+        if result.is_err() {
+            self.treasury.balance += amount;
+            return Err(Bet2ChessErrors::CantSendWithdrawal);
+        }
+
+        Ok(())
+    }
+
+    // The fees in tokens are moved to the ledger balance of the address,
+    // that withdraws them from the token stakes service
+    pub fn withdraw_treasury_token(
+        &mut self,
+        caller: ActorId,
+        token: ActorId,
+        to: ActorId,
+        amount: u128
+    ) -> Result<(), Bet2ChessErrors> {
+        self.check_admin(caller)?;
+
+        let balance = self.treasury
+            .token_balances
+            .get(&token)
+            .copied()
+            .unwrap_or_default();
+
+        if amount > balance {
+            return Err(Bet2ChessErrors::InsufficientTreasuryBalance(balance));
+        }
+
+        self.treasury.token_balances.insert(token, balance - amount);
+        self.credit_token(token, to, amount);
+
+        Ok(())
+    }
+
+    // Takes the fee of a settled pot and returns what is left for the players.
+    // Refunds of games or invitations that were never played don't pay fees
+    pub(crate) fn collect_fee(&mut self, pot: u128, token: Option<ActorId>) -> u128 {
+        let fee = match token {
+            None => self.treasury.fee_config.fee_for_pot(pot),
+            Some(_) => self.treasury.fee_config.fee_for_token_pot(pot)
+        };
+
+        self.add_to_treasury(token, fee);
+
+        pot - fee
+    }
+
+    pub(crate) fn add_to_treasury(&mut self, token: Option<ActorId>, amount: u128) {
+        match token {
+            None => {
+                self.treasury.balance += amount;
+                self.treasury.total_fees_collected += amount;
+            },
+            Some(token) => {
+                *self.treasury.token_balances.entry(token).or_default() += amount;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{
+        runtime::mock,
+        test_utils::{
+            address,
+            new_state,
+            ADMIN,
+            STAKE
+        }
+    };
+
+    const TOKEN: u64 = 500;
+    const RECIPIENT: u64 = 30;
+
+    fn fee_config(fee_basis_points: u16, min_fee: u128, max_fee: u128) -> FeeConfig {
+        FeeConfig {
+            fee_basis_points,
+            min_fee,
+            max_fee
+        }
+    }
+
+    fn state_with_treasury(balance: u128) -> ChessState {
+        let mut state = new_state();
+        state.add_to_treasury(None, balance);
+        state.add_to_treasury(Some(address(TOKEN)), balance);
+
+        state
+    }
+
+    #[test]
+    fn fee_is_the_percentage_of_the_pot_between_the_limits() {
+        let config = fee_config(500, 10, 1_000);
+
+        assert_eq!(config.fee_for_pot(10_000), 500);
+        assert_eq!(config.fee_for_pot(100), 10);
+        assert_eq!(config.fee_for_pot(100_000), 1_000);
+        assert_eq!(fee_config(0, 10, 1_000).fee_for_pot(10_000), 0);
+    }
+
+    #[test]
+    fn fee_is_never_bigger_than_the_pot() {
+        let config = fee_config(500, 100, 1_000);
+
+        assert_eq!(config.fee_for_pot(40), 40);
+        assert_eq!(config.fee_for_pot(0), 0);
+        assert_eq!(fee_config(MAX_FEE_BASIS_POINTS, 0, u128::MAX).fee_for_pot(u128::MAX), u128::MAX / MAX_FEE_BASIS_POINTS as u128);
+    }
+
+    #[test]
+    fn token_pots_only_pay_the_percentage() {
+        let config = fee_config(500, 100, 200);
+
+        assert_eq!(config.fee_for_token_pot(10_000), 500);
+        assert_eq!(config.fee_for_token_pot(40), 2);
+    }
+
+    #[test]
+    fn invalid_fee_configs_are_rejected() {
+        let mut state = new_state();
+
+        for config in [fee_config(MAX_FEE_BASIS_POINTS + 1, 0, 1), fee_config(500, 10, 5), fee_config(500, 0, 0)] {
+            assert_eq!(state.set_fee_config(address(ADMIN), config), Err(Bet2ChessErrors::InvalidFeeConfig));
+        }
+
+        assert_eq!(state.set_fee_config(address(RECIPIENT), fee_config(500, 0, 1)), Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction));
+        assert_eq!(state.treasury.fee_config.fee_basis_points, 0);
+    }
+
+    #[test]
+    fn only_admins_withdraw_the_treasury() {
+        let mut state = state_with_treasury(STAKE);
+
+        assert_eq!(
+            state.withdraw_treasury(address(RECIPIENT), address(RECIPIENT), STAKE),
+            Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction)
+        );
+        assert_eq!(
+            state.withdraw_treasury_token(address(RECIPIENT), address(TOKEN), address(RECIPIENT), STAKE),
+            Err(Bet2ChessErrors::OnlyAdminsCanDoThisAction)
+        );
+        assert_eq!(state.treasury.balance, STAKE);
+        assert_eq!(state.treasury.token_balances[&address(TOKEN)], STAKE);
+        assert!(mock::sent_messages().is_empty());
+    }
+
+    #[test]
+    fn treasury_withdrawals_send_the_fees() {
+        let mut state = state_with_treasury(STAKE);
+
+        assert_eq!(
+            state.withdraw_treasury(address(ADMIN), address(RECIPIENT), STAKE + 1),
+            Err(Bet2ChessErrors::InsufficientTreasuryBalance(STAKE))
+        );
+
+        state.withdraw_treasury(address(ADMIN), address(RECIPIENT), STAKE / 2).unwrap();
+
+        assert_eq!(mock::sent_messages(), [(address(RECIPIENT), STAKE / 2)]);
+        assert_eq!(state.treasury.balance, STAKE / 2);
+        assert_eq!(state.treasury.total_fees_collected, STAKE);
+    }
+
+    #[test]
+    fn treasury_balance_is_kept_when_the_withdrawal_cant_be_sent() {
+        let mut state = state_with_treasury(STAKE);
+        mock::set_messages_fail(true);
+
+        assert_eq!(
+            state.withdraw_treasury(address(ADMIN), address(RECIPIENT), STAKE),
+            Err(Bet2ChessErrors::CantSendWithdrawal)
+        );
+        assert_eq!(state.treasury.balance, STAKE);
+    }
+
+    #[test]
+    fn treasury_token_withdrawals_are_credited_to_the_ledger() {
+        let mut state = state_with_treasury(STAKE);
+
+        assert_eq!(
+            state.withdraw_treasury_token(address(ADMIN), address(TOKEN), address(RECIPIENT), STAKE + 1),
+            Err(Bet2ChessErrors::InsufficientTreasuryBalance(STAKE))
+        );
+
+        state.withdraw_treasury_token(address(ADMIN), address(TOKEN), address(RECIPIENT), STAKE).unwrap();
+
+        assert_eq!(state.treasury.token_balances[&address(TOKEN)], 0);
+        assert_eq!(state.token_balance(address(TOKEN), address(RECIPIENT)), STAKE);
+        assert_eq!(state.treasury.balance, STAKE);
+    }
+
+    #[test]
+    fn collected_fees_go_to_the_treasury_of_their_currency() {
+        let mut state = new_state();
+        state.set_fee_config(address(ADMIN), fee_config(500, 0, u128::MAX)).unwrap();
+
+        assert_eq!(state.collect_fee(2 * STAKE, None), 2 * STAKE - STAKE / 10);
+        assert_eq!(state.collect_fee(2 * STAKE, Some(address(TOKEN))), 2 * STAKE - STAKE / 10);
+
+        assert_eq!(state.treasury.balance, STAKE / 10);
+        assert_eq!(state.treasury.total_fees_collected, STAKE / 10);
+        assert_eq!(state.treasury.token_balances[&address(TOKEN)], STAKE / 10);
+    }
+}
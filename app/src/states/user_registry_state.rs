@@ -0,0 +1,383 @@
+use sails_rs::{
+    prelude::*,
+    collections::BTreeMap
+};
+
+use super::runtime::exec;
+
+use super::bet2chess_state::UserWeb2Id;
+
+// Context used by substrate wallets and keyrings to sign with sr25519
+const SIGNING_CONTEXT: &[u8] = b"substrate";
+
+pub const MIN_USERNAME_LENGTH: usize = 3;
+pub const MAX_USERNAME_LENGTH: usize = 20;
+
+#[derive(Encode, Decode, TypeInfo, Clone)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PlayerProfile {
+    pub username: String,
+    // Hash of the avatar image stored off chain
+    pub avatar_hash: Option<[u8; 32]>,
+    // ISO 3166-1 alpha-2 code, like "MX"
+    pub country: Option<String>,
+    pub registered_at_block: u32
+}
+
+#[derive(Default)]
+pub struct UserRegistryState {
+    // sr25519 public key of the backend that attests the links
+    pub attester: Option<[u8; 32]>,
+    pub address_by_web2_id: BTreeMap<UserWeb2Id, ActorId>,
+    pub web2_id_by_address: BTreeMap<ActorId, UserWeb2Id>,
+    // Each attestation can only be used once, the nonce of a web2 id
+    // increases every time it is linked
+    pub link_nonces: BTreeMap<UserWeb2Id, u64>,
+    pub profiles: BTreeMap<UserWeb2Id, PlayerProfile>,
+    // Usernames are unique ignoring case, they are stored in lowercase
+    pub web2_id_by_username: BTreeMap<String, UserWeb2Id>
+}
+
+impl UserRegistryState {
+    pub fn is_linked(&self, web2_id: UserWeb2Id, address: ActorId) -> bool {
+        self.address_by_web2_id.get(&web2_id) == Some(&address)
+    }
+
+    pub fn link_nonce(&self, web2_id: UserWeb2Id) -> u64 {
+        self.link_nonces
+            .get(&web2_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    // Message the attester signs to link a web2 id with an address
+    pub fn link_message(program_id: ActorId, web2_id: UserWeb2Id, address: ActorId, nonce: u64) -> Vec<u8> {
+        (program_id, web2_id, address, nonce).encode()
+    }
+
+    pub fn link_web2_id(
+        &mut self,
+        program_id: ActorId,
+        web2_id: UserWeb2Id,
+        address: ActorId,
+        signature: [u8; 64]
+    ) -> Result<(), UserRegistryErrors> {
+        let attester = self.attester
+            .ok_or(UserRegistryErrors::AttesterIsNotConfigured)?;
+
+        if let Some(linked_web2_id) = self.web2_id_by_address.get(&address) {
+            if *linked_web2_id != web2_id {
+                return Err(UserRegistryErrors::AddressAlreadyLinked(*linked_web2_id));
+            }
+        }
+
+        let nonce = self.link_nonce(web2_id);
+        let message = Self::link_message(program_id, web2_id, address, nonce);
+
+        let public_key = schnorrkel::PublicKey::from_bytes(&attester)
+            .map_err(|_| UserRegistryErrors::InvalidAttesterKey)?;
+        let signature = schnorrkel::Signature::from_bytes(&signature)
+            .map_err(|_| UserRegistryErrors::InvalidSignature)?;
+
+        public_key
+            .verify_simple(SIGNING_CONTEXT, &message, &signature)
+            .map_err(|_| UserRegistryErrors::InvalidSignature)?;
+
+        // A web2 id can only be linked to one address, the old one is replaced
+        if let Some(previous_address) = self.address_by_web2_id.insert(web2_id, address) {
+            self.web2_id_by_address.remove(&previous_address);
+        }
+
+        self.web2_id_by_address.insert(address, web2_id);
+        self.link_nonces.insert(web2_id, nonce + 1);
+
+        Ok(())
+    }
+
+    pub fn unlink_web2_id(&mut self, web2_id: UserWeb2Id) -> Result<ActorId, UserRegistryErrors> {
+        let address = self.address_by_web2_id
+            .remove(&web2_id)
+            .ok_or(UserRegistryErrors::Web2IdIsNotLinked(web2_id))?;
+
+        self.web2_id_by_address.remove(&address);
+
+        Ok(address)
+    }
+
+    pub fn username(&self, web2_id: UserWeb2Id) -> Option<String> {
+        self.profiles
+            .get(&web2_id)
+            .map(|profile| profile.username.clone())
+    }
+
+    pub fn web2_id_by_username(&self, username: &str) -> Option<UserWeb2Id> {
+        self.web2_id_by_username
+            .get(&username.to_lowercase())
+            .copied()
+    }
+
+    // Registers the profile of the web2 id or updates it if it already exists
+    pub fn set_profile(
+        &mut self,
+        address: ActorId,
+        web2_id: UserWeb2Id,
+        username: String,
+        avatar_hash: Option<[u8; 32]>,
+        country: Option<String>
+    ) -> Result<(), UserRegistryErrors> {
+        if !self.is_linked(web2_id, address) {
+            return Err(UserRegistryErrors::UserAddressAndWeb2IdAreNotRelated);
+        }
+
+        if !Self::is_valid_username(&username) {
+            return Err(UserRegistryErrors::InvalidUsername);
+        }
+
+        if let Some(country) = &country {
+            if !Self::is_valid_country(country) {
+                return Err(UserRegistryErrors::InvalidCountryCode);
+            }
+        }
+
+        let username_key = username.to_lowercase();
+
+        if let Some(owner) = self.web2_id_by_username.get(&username_key) {
+            if *owner != web2_id {
+                return Err(UserRegistryErrors::UsernameAlreadyTaken(username));
+            }
+        }
+
+        let registered_at_block = match self.profiles.get(&web2_id) {
+            Some(profile) => {
+                self.web2_id_by_username.remove(&profile.username.to_lowercase());
+                profile.registered_at_block
+            },
+            None => exec::block_height()
+        };
+
+        self.web2_id_by_username.insert(username_key, web2_id);
+        self.profiles.insert(web2_id, PlayerProfile {
+            username,
+            avatar_hash,
+            country,
+            registered_at_block
+        });
+
+        Ok(())
+    }
+
+    fn is_valid_username(username: &str) -> bool {
+        let length = username.chars().count();
+
+        (MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&length)
+            && username
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '_' || character == '-')
+    }
+
+    fn is_valid_country(country: &str) -> bool {
+        country.len() == 2
+            && country
+                .chars()
+                .all(|character| character.is_ascii_uppercase())
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, PartialEq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum UserRegistryErrors {
+    OnlyAdminsCanDoThisAction,
+    AttesterIsNotConfigured,
+    InvalidAttesterKey,
+    InvalidSignature,
+    AddressAlreadyLinked(UserWeb2Id),
+    Web2IdIsNotLinked(UserWeb2Id),
+    UserAddressAndWeb2IdAreNotRelated,
+    InvalidUsername,
+    InvalidCountryCode,
+    UsernameAlreadyTaken(String)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::runtime::mock;
+    use schnorrkel::{
+        ExpansionMode,
+        Keypair,
+        MiniSecretKey
+    };
+
+    const PROGRAM: u64 = 1_000;
+    const WEB2_ID: UserWeb2Id = 42;
+
+    fn attester() -> Keypair {
+        MiniSecretKey::from_bytes(&[7; 32])
+            .unwrap()
+            .expand_to_keypair(ExpansionMode::Ed25519)
+    }
+
+    fn registry() -> UserRegistryState {
+        UserRegistryState {
+            attester: Some(attester().public.to_bytes()),
+            ..Default::default()
+        }
+    }
+
+    fn attest(keypair: &Keypair, program: u64, web2_id: UserWeb2Id, address: u64, nonce: u64) -> [u8; 64] {
+        let message = UserRegistryState::link_message(ActorId::from(program), web2_id, ActorId::from(address), nonce);
+
+        keypair
+            .sign_simple(SIGNING_CONTEXT, &message)
+            .to_bytes()
+    }
+
+    fn link(registry: &mut UserRegistryState, address: u64, signature: [u8; 64]) -> Result<(), UserRegistryErrors> {
+        registry.link_web2_id(ActorId::from(PROGRAM), WEB2_ID, ActorId::from(address), signature)
+    }
+
+    #[test]
+    fn attested_link_binds_the_web2_id_and_increases_the_nonce() {
+        let mut registry = registry();
+        let signature = attest(&attester(), PROGRAM, WEB2_ID, 5, 0);
+
+        link(&mut registry, 5, signature).unwrap();
+
+        assert!(registry.is_linked(WEB2_ID, ActorId::from(5)));
+        assert_eq!(registry.web2_id_by_address.get(&ActorId::from(5)), Some(&WEB2_ID));
+        assert_eq!(registry.link_nonce(WEB2_ID), 1);
+    }
+
+    #[test]
+    fn attestation_can_only_be_used_once() {
+        let mut registry = registry();
+        let signature = attest(&attester(), PROGRAM, WEB2_ID, 5, 0);
+
+        link(&mut registry, 5, signature).unwrap();
+
+        assert_eq!(link(&mut registry, 5, signature), Err(UserRegistryErrors::InvalidSignature));
+        assert_eq!(registry.link_nonce(WEB2_ID), 1);
+    }
+
+    #[test]
+    fn attestation_is_bound_to_the_address_program_and_nonce() {
+        let mut registry = registry();
+
+        let other_address = attest(&attester(), PROGRAM, WEB2_ID, 6, 0);
+        let other_program = attest(&attester(), PROGRAM + 1, WEB2_ID, 5, 0);
+        let future_nonce = attest(&attester(), PROGRAM, WEB2_ID, 5, 1);
+
+        for signature in [other_address, other_program, future_nonce] {
+            assert_eq!(link(&mut registry, 5, signature), Err(UserRegistryErrors::InvalidSignature));
+        }
+
+        assert!(registry.address_by_web2_id.is_empty());
+        assert_eq!(registry.link_nonce(WEB2_ID), 0);
+    }
+
+    #[test]
+    fn only_the_configured_attester_can_sign() {
+        let mut registry = registry();
+        let impostor = MiniSecretKey::from_bytes(&[8; 32])
+            .unwrap()
+            .expand_to_keypair(ExpansionMode::Ed25519);
+
+        let signature = attest(&impostor, PROGRAM, WEB2_ID, 5, 0);
+
+        assert_eq!(link(&mut registry, 5, signature), Err(UserRegistryErrors::InvalidSignature));
+
+        registry.attester = None;
+        let signature = attest(&attester(), PROGRAM, WEB2_ID, 5, 0);
+
+        assert_eq!(link(&mut registry, 5, signature), Err(UserRegistryErrors::AttesterIsNotConfigured));
+    }
+
+    #[test]
+    fn linking_again_replaces_the_old_address() {
+        let mut registry = registry();
+
+        link(&mut registry, 5, attest(&attester(), PROGRAM, WEB2_ID, 5, 0)).unwrap();
+        link(&mut registry, 6, attest(&attester(), PROGRAM, WEB2_ID, 6, 1)).unwrap();
+
+        assert!(registry.is_linked(WEB2_ID, ActorId::from(6)));
+        assert!(!registry.web2_id_by_address.contains_key(&ActorId::from(5)));
+        assert_eq!(registry.link_nonce(WEB2_ID), 2);
+    }
+
+    fn linked_registry(address: u64) -> UserRegistryState {
+        let mut registry = registry();
+        link(&mut registry, address, attest(&attester(), PROGRAM, WEB2_ID, address, 0)).unwrap();
+
+        registry
+    }
+
+    fn set_username(registry: &mut UserRegistryState, address: u64, web2_id: UserWeb2Id, username: &str) -> Result<(), UserRegistryErrors> {
+        registry.set_profile(ActorId::from(address), web2_id, username.into(), None, None)
+    }
+
+    #[test]
+    fn profiles_can_only_be_set_by_the_linked_address() {
+        let mut registry = linked_registry(5);
+
+        assert_eq!(
+            set_username(&mut registry, 6, WEB2_ID, "magnus"),
+            Err(UserRegistryErrors::UserAddressAndWeb2IdAreNotRelated)
+        );
+
+        set_username(&mut registry, 5, WEB2_ID, "magnus").unwrap();
+        assert_eq!(registry.username(WEB2_ID), Some("magnus".into()));
+    }
+
+    #[test]
+    fn usernames_and_countries_are_validated() {
+        let mut registry = linked_registry(5);
+
+        for username in ["ab", "a_name_that_is_too_long", "with space", "ñandu"] {
+            assert_eq!(set_username(&mut registry, 5, WEB2_ID, username), Err(UserRegistryErrors::InvalidUsername));
+        }
+
+        for country in ["mx", "MEX", "M1"] {
+            assert_eq!(
+                registry.set_profile(ActorId::from(5), WEB2_ID, "magnus".into(), None, Some(country.into())),
+                Err(UserRegistryErrors::InvalidCountryCode)
+            );
+        }
+
+        assert!(registry.profiles.is_empty());
+    }
+
+    #[test]
+    fn usernames_are_unique_ignoring_case_and_freed_when_changed() {
+        let mut registry = linked_registry(5);
+        registry.address_by_web2_id.insert(WEB2_ID + 1, ActorId::from(6));
+
+        set_username(&mut registry, 5, WEB2_ID, "Magnus").unwrap();
+
+        assert_eq!(
+            set_username(&mut registry, 6, WEB2_ID + 1, "MAGNUS"),
+            Err(UserRegistryErrors::UsernameAlreadyTaken("MAGNUS".into()))
+        );
+        assert_eq!(registry.web2_id_by_username("magnus"), Some(WEB2_ID));
+
+        set_username(&mut registry, 5, WEB2_ID, "Carlsen").unwrap();
+        set_username(&mut registry, 6, WEB2_ID + 1, "magnus").unwrap();
+
+        assert_eq!(registry.web2_id_by_username("carlsen"), Some(WEB2_ID));
+        assert_eq!(registry.web2_id_by_username("Magnus"), Some(WEB2_ID + 1));
+    }
+
+    #[test]
+    fn updating_a_profile_keeps_its_registration_block() {
+        let mut registry = linked_registry(5);
+
+        mock::set_block_height(10);
+        set_username(&mut registry, 5, WEB2_ID, "magnus").unwrap();
+
+        mock::set_block_height(20);
+        set_username(&mut registry, 5, WEB2_ID, "carlsen").unwrap();
+
+        assert_eq!(registry.profiles[&WEB2_ID].registered_at_block, 10);
+    }
+}
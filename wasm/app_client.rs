@@ -53,13 +53,14 @@ impl<R> Bet2Chess<R> {
 }
 impl<R: Remoting + Clone> traits::Bet2Chess for Bet2Chess<R> {
     type Args = R::Args;
+    fn abort_game(&mut self, game_id: u64) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::AbortGame>::new(self.remoting.clone(), game_id)
+    }
     fn accept_invitation(
         &mut self,
         web2_user_id: u64,
         web2_user_id_invitation_owner: u64,
         web2_match_game_id: u64,
-        username_from_user_who_invite: String,
-        own_username: String,
     ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
         RemotingAction::<_, bet_2_chess::io::AcceptInvitation>::new(
             self.remoting.clone(),
@@ -67,8 +68,6 @@ impl<R: Remoting + Clone> traits::Bet2Chess for Bet2Chess<R> {
                 web2_user_id,
                 web2_user_id_invitation_owner,
                 web2_match_game_id,
-                username_from_user_who_invite,
-                own_username,
             ),
         )
     }
@@ -78,8 +77,6 @@ impl<R: Remoting + Clone> traits::Bet2Chess for Bet2Chess<R> {
         web2_user_id: u64,
         web2_user_id_invitation_owner: u64,
         web2_match_game_id: u64,
-        username_from_user_who_invite: String,
-        own_username: String,
     ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
         RemotingAction::<_, bet_2_chess::io::AcceptInvitationSignless>::new(
             self.remoting.clone(),
@@ -88,8 +85,6 @@ impl<R: Remoting + Clone> traits::Bet2Chess for Bet2Chess<R> {
                 web2_user_id,
                 web2_user_id_invitation_owner,
                 web2_match_game_id,
-                username_from_user_who_invite,
-                own_username,
             ),
         )
     }
@@ -99,8 +94,6 @@ impl<R: Remoting + Clone> traits::Bet2Chess for Bet2Chess<R> {
         web2_user_id: u64,
         web2_user_id_invitation_owner: u64,
         web2_match_game_id: u64,
-        username_from_user_who_invite: String,
-        own_username: String,
     ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
         RemotingAction::<_, bet_2_chess::io::AcceptInvitationSignlessNoWallet>::new(
             self.remoting.clone(),
@@ -109,11 +102,21 @@ impl<R: Remoting + Clone> traits::Bet2Chess for Bet2Chess<R> {
                 web2_user_id,
                 web2_user_id_invitation_owner,
                 web2_match_game_id,
-                username_from_user_who_invite,
-                own_username,
             ),
         )
     }
+    fn add_admin(
+        &mut self,
+        new_admin: ActorId,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::AddAdmin>::new(self.remoting.clone(), new_admin)
+    }
+    fn add_result_oracle(
+        &mut self,
+        oracle: ActorId,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::AddResultOracle>::new(self.remoting.clone(), oracle)
+    }
     fn cancel_invitation(
         &mut self,
         first_web2_id: u64,
@@ -146,6 +149,122 @@ impl<R: Remoting + Clone> traits::Bet2Chess for Bet2Chess<R> {
             (no_wallet_name_encoded, first_web2_id, second_web2_id),
         )
     }
+    fn cancel_open_game(
+        &mut self,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::CancelOpenGame>::new(self.remoting.clone(), game_id)
+    }
+    fn cancel_open_game_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::CancelOpenGameSignless>::new(
+            self.remoting.clone(),
+            (user_address, game_id),
+        )
+    }
+    fn cancel_open_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::CancelOpenGameSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, game_id),
+        )
+    }
+    fn claim_spectator_winnings(
+        &mut self,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::ClaimSpectatorWinnings>::new(
+            self.remoting.clone(),
+            game_id,
+        )
+    }
+    fn claim_spectator_winnings_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::ClaimSpectatorWinningsSignless>::new(
+            self.remoting.clone(),
+            (user_address, game_id),
+        )
+    }
+    fn claim_spectator_winnings_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::ClaimSpectatorWinningsSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, game_id),
+        )
+    }
+    fn create_open_game(
+        &mut self,
+        web2_user_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::CreateOpenGame>::new(
+            self.remoting.clone(),
+            web2_user_id,
+        )
+    }
+    fn create_open_game_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::CreateOpenGameSignless>::new(
+            self.remoting.clone(),
+            (user_address, web2_user_id),
+        )
+    }
+    fn create_open_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::CreateOpenGameSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, web2_user_id),
+        )
+    }
+    fn decline_invitation(
+        &mut self,
+        first_web2_id: u64,
+        second_web2_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::DeclineInvitation>::new(
+            self.remoting.clone(),
+            (first_web2_id, second_web2_id),
+        )
+    }
+    fn decline_invitation_signless(
+        &mut self,
+        user_address: ActorId,
+        first_web2_id: u64,
+        second_web2_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::DeclineInvitationSignless>::new(
+            self.remoting.clone(),
+            (user_address, first_web2_id, second_web2_id),
+        )
+    }
+    fn decline_invitation_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        first_web2_id: u64,
+        second_web2_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::DeclineInvitationSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, first_web2_id, second_web2_id),
+        )
+    }
     fn end_game_by_id(
         &mut self,
         game_id: u64,
@@ -166,598 +285,3834 @@ impl<R: Remoting + Clone> traits::Bet2Chess for Bet2Chess<R> {
             (game_id, game_winner),
         )
     }
-    fn send_invitation(
+    fn expire_invitation(
+        &mut self,
+        first_web2_id: u64,
+        second_web2_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::ExpireInvitation>::new(
+            self.remoting.clone(),
+            (first_web2_id, second_web2_id),
+        )
+    }
+    fn join_any_waiting_game(
         &mut self,
         web2_user_id: u64,
-        web2_guest_id: u64,
     ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::SendInvitation>::new(
+        RemotingAction::<_, bet_2_chess::io::JoinAnyWaitingGame>::new(
             self.remoting.clone(),
-            (web2_user_id, web2_guest_id),
+            web2_user_id,
         )
     }
-    fn send_invitation_signless(
+    fn join_any_waiting_game_signless(
         &mut self,
         user_address: ActorId,
         web2_user_id: u64,
-        web2_guest_id: u64,
     ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::SendInvitationSignless>::new(
+        RemotingAction::<_, bet_2_chess::io::JoinAnyWaitingGameSignless>::new(
             self.remoting.clone(),
-            (user_address, web2_user_id, web2_guest_id),
+            (user_address, web2_user_id),
         )
     }
-    fn send_invitation_signless_no_wallet(
+    fn join_any_waiting_game_signless_no_wallet(
         &mut self,
         no_wallet_name_encoded: String,
         web2_user_id: u64,
-        web2_guest_id: u64,
     ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::SendInvitationSignlessNoWallet>::new(
+        RemotingAction::<_, bet_2_chess::io::JoinAnyWaitingGameSignlessNoWallet>::new(
             self.remoting.clone(),
-            (no_wallet_name_encoded, web2_user_id, web2_guest_id),
+            (no_wallet_name_encoded, web2_user_id),
         )
     }
-    fn all_games(&self) -> impl Query<Output = Vec<(u64, GameData)>, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::AllGames>::new(self.remoting.clone(), ())
+    fn join_matchmaking_queue(
+        &mut self,
+        web2_user_id: u64,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::JoinMatchmakingQueue>::new(
+            self.remoting.clone(),
+            (web2_user_id, time_control, rating_range),
+        )
     }
-    fn game_data(&self, game_id: u64) -> impl Query<Output = Option<GameData>, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::GameData>::new(self.remoting.clone(), game_id)
+    fn join_matchmaking_queue_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::JoinMatchmakingQueueSignless>::new(
+            self.remoting.clone(),
+            (user_address, web2_user_id, time_control, rating_range),
+        )
     }
-    fn games_id_ended(&self) -> impl Query<Output = Vec<u64>, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::GamesIdEnded>::new(self.remoting.clone(), ())
+    fn join_matchmaking_queue_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        time_control: Option<u32>,
+        rating_range: Option<RatingRange>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::JoinMatchmakingQueueSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (
+                no_wallet_name_encoded,
+                web2_user_id,
+                time_control,
+                rating_range,
+            ),
+        )
     }
-    fn games_id_started(&self) -> impl Query<Output = Vec<u64>, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::GamesIdStarted>::new(self.remoting.clone(), ())
+    fn join_open_game(
+        &mut self,
+        web2_user_id: u64,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::JoinOpenGame>::new(
+            self.remoting.clone(),
+            (web2_user_id, game_id),
+        )
     }
-    fn games_id_waiting(&self) -> impl Query<Output = Vec<u64>, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::GamesIdWaiting>::new(self.remoting.clone(), ())
+    fn join_open_game_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::JoinOpenGameSignless>::new(
+            self.remoting.clone(),
+            (user_address, web2_user_id, game_id),
+        )
     }
-    fn invitation_bet(
-        &self,
-        first_web2_id: u64,
-        second_web2_id: u64,
-    ) -> impl Query<Output = Option<u128>, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::InvitationBet>::new(
+    fn join_open_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::JoinOpenGameSignlessNoWallet>::new(
             self.remoting.clone(),
-            (first_web2_id, second_web2_id),
+            (no_wallet_name_encoded, web2_user_id, game_id),
         )
     }
-    fn invitations_from_web_2_id(
-        &self,
-        web2_id: u64,
-    ) -> impl Query<Output = Option<InvitationsState>, Args = R::Args> {
-        RemotingAction::<_, bet_2_chess::io::InvitationsFromWeb2Id>::new(
+    fn leave_matchmaking_queue(&mut self) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::LeaveMatchmakingQueue>::new(self.remoting.clone(), ())
+    }
+    fn leave_matchmaking_queue_signless(
+        &mut self,
+        user_address: ActorId,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::LeaveMatchmakingQueueSignless>::new(
             self.remoting.clone(),
-            web2_id,
+            user_address,
         )
     }
-}
-
-pub mod bet_2_chess {
-    use super::*;
-
-    pub mod io {
-        use super::*;
-        use sails_rs::calls::ActionIo;
-        pub struct AcceptInvitation(());
-        impl AcceptInvitation {
-            #[allow(dead_code)]
-            pub fn encode_call(
-                web2_user_id: u64,
-                web2_user_id_invitation_owner: u64,
-                web2_match_game_id: u64,
-                username_from_user_who_invite: String,
-                own_username: String,
-            ) -> Vec<u8> {
-                <AcceptInvitation as ActionIo>::encode_call(&(
-                    web2_user_id,
-                    web2_user_id_invitation_owner,
-                    web2_match_game_id,
-                    username_from_user_who_invite,
-                    own_username,
-                ))
-            }
-        }
-        impl ActionIo for AcceptInvitation {
-            const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 65, 99, 99, 101, 112, 116, 73,
-                110, 118, 105, 116, 97, 116, 105, 111, 110,
-            ];
-            type Params = (u64, u64, u64, String, String);
-            type Reply = super::Bet2ChessEvents;
-        }
-        pub struct AcceptInvitationSignless(());
-        impl AcceptInvitationSignless {
-            #[allow(dead_code)]
-            pub fn encode_call(
-                user_address: ActorId,
-                web2_user_id: u64,
-                web2_user_id_invitation_owner: u64,
-                web2_match_game_id: u64,
-                username_from_user_who_invite: String,
-                own_username: String,
-            ) -> Vec<u8> {
-                <AcceptInvitationSignless as ActionIo>::encode_call(&(
-                    user_address,
-                    web2_user_id,
-                    web2_user_id_invitation_owner,
-                    web2_match_game_id,
-                    username_from_user_who_invite,
-                    own_username,
-                ))
-            }
-        }
-        impl ActionIo for AcceptInvitationSignless {
-            const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 96, 65, 99, 99, 101, 112, 116, 73,
-                110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
-            ];
-            type Params = (ActorId, u64, u64, u64, String, String);
-            type Reply = super::Bet2ChessEvents;
-        }
-        pub struct AcceptInvitationSignlessNoWallet(());
-        impl AcceptInvitationSignlessNoWallet {
-            #[allow(dead_code)]
-            pub fn encode_call(
-                no_wallet_name_encoded: String,
-                web2_user_id: u64,
-                web2_user_id_invitation_owner: u64,
-                web2_match_game_id: u64,
-                username_from_user_who_invite: String,
-                own_username: String,
-            ) -> Vec<u8> {
-                <AcceptInvitationSignlessNoWallet as ActionIo>::encode_call(&(
-                    no_wallet_name_encoded,
-                    web2_user_id,
-                    web2_user_id_invitation_owner,
-                    web2_match_game_id,
-                    username_from_user_who_invite,
-                    own_username,
-                ))
-            }
-        }
-        impl ActionIo for AcceptInvitationSignlessNoWallet {
-            const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 128, 65, 99, 99, 101, 112, 116, 73,
-                110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
-                78, 111, 87, 97, 108, 108, 101, 116,
-            ];
-            type Params = (String, u64, u64, u64, String, String);
-            type Reply = super::Bet2ChessEvents;
-        }
-        pub struct CancelInvitation(());
-        impl CancelInvitation {
+    fn leave_matchmaking_queue_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::LeaveMatchmakingQueueSignlessNoWallet>::new(
+            self.remoting.clone(),
+            no_wallet_name_encoded,
+        )
+    }
+    fn make_move(
+        &mut self,
+        game_id: u64,
+        chess_move: ChessMove,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::MakeMove>::new(
+            self.remoting.clone(),
+            (game_id, chess_move),
+        )
+    }
+    fn make_move_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64,
+        chess_move: ChessMove,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::MakeMoveSignless>::new(
+            self.remoting.clone(),
+            (user_address, game_id, chess_move),
+        )
+    }
+    fn make_move_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64,
+        chess_move: ChessMove,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::MakeMoveSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, game_id, chess_move),
+        )
+    }
+    fn place_spectator_bet(
+        &mut self,
+        game_id: u64,
+        outcome: MarketOutcome,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::PlaceSpectatorBet>::new(
+            self.remoting.clone(),
+            (game_id, outcome),
+        )
+    }
+    fn place_spectator_bet_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64,
+        outcome: MarketOutcome,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::PlaceSpectatorBetSignless>::new(
+            self.remoting.clone(),
+            (user_address, game_id, outcome),
+        )
+    }
+    fn place_spectator_bet_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64,
+        outcome: MarketOutcome,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::PlaceSpectatorBetSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, game_id, outcome),
+        )
+    }
+    fn remove_admin(
+        &mut self,
+        admin: ActorId,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::RemoveAdmin>::new(self.remoting.clone(), admin)
+    }
+    fn remove_result_oracle(
+        &mut self,
+        oracle: ActorId,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::RemoveResultOracle>::new(self.remoting.clone(), oracle)
+    }
+    fn resign(&mut self, game_id: u64) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::Resign>::new(self.remoting.clone(), game_id)
+    }
+    fn resign_signless(
+        &mut self,
+        user_address: ActorId,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::ResignSignless>::new(
+            self.remoting.clone(),
+            (user_address, game_id),
+        )
+    }
+    fn resign_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        game_id: u64,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::ResignSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, game_id),
+        )
+    }
+    fn send_invitation(
+        &mut self,
+        web2_user_id: u64,
+        web2_guest_id: u64,
+        guest_bet_amount: Option<u128>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SendInvitation>::new(
+            self.remoting.clone(),
+            (web2_user_id, web2_guest_id, guest_bet_amount),
+        )
+    }
+    fn send_invitation_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        web2_guest_id: u64,
+        guest_bet_amount: Option<u128>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SendInvitationSignless>::new(
+            self.remoting.clone(),
+            (user_address, web2_user_id, web2_guest_id, guest_bet_amount),
+        )
+    }
+    fn send_invitation_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        web2_guest_id: u64,
+        guest_bet_amount: Option<u128>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SendInvitationSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (
+                no_wallet_name_encoded,
+                web2_user_id,
+                web2_guest_id,
+                guest_bet_amount,
+            ),
+        )
+    }
+    fn set_fee_config(
+        &mut self,
+        fee_config: FeeConfig,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SetFeeConfig>::new(self.remoting.clone(), fee_config)
+    }
+    fn set_invitation_ttl(
+        &mut self,
+        ttl_blocks: u32,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SetInvitationTtl>::new(
+            self.remoting.clone(),
+            ttl_blocks,
+        )
+    }
+    fn set_market_config(
+        &mut self,
+        market_config: MarketConfig,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SetMarketConfig>::new(
+            self.remoting.clone(),
+            market_config,
+        )
+    }
+    fn set_stake_limits(
+        &mut self,
+        stake_limits: StakeLimits,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SetStakeLimits>::new(
+            self.remoting.clone(),
+            stake_limits,
+        )
+    }
+    fn sweep_expired_invitations(
+        &mut self,
+        limit: u32,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SweepExpiredInvitations>::new(
+            self.remoting.clone(),
+            limit,
+        )
+    }
+    fn withdraw(&mut self, amount: u128) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::Withdraw>::new(self.remoting.clone(), amount)
+    }
+    fn withdraw_signless(
+        &mut self,
+        user_address: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::WithdrawSignless>::new(
+            self.remoting.clone(),
+            (user_address, amount),
+        )
+    }
+    fn withdraw_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::WithdrawSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, amount),
+        )
+    }
+    fn withdraw_treasury(
+        &mut self,
+        to: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::WithdrawTreasury>::new(
+            self.remoting.clone(),
+            (to, amount),
+        )
+    }
+    fn admins(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::Admins>::new(self.remoting.clone(), ())
+    }
+    fn all_games(&self) -> impl Query<Output = Vec<(u64, GameData)>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::AllGames>::new(self.remoting.clone(), ())
+    }
+    fn balance(&self, address: ActorId) -> impl Query<Output = u128, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::Balance>::new(self.remoting.clone(), address)
+    }
+    fn fee_config(&self) -> impl Query<Output = FeeConfig, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::FeeConfig>::new(self.remoting.clone(), ())
+    }
+    fn game_board(&self, game_id: u64) -> impl Query<Output = Option<ChessBoard>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GameBoard>::new(self.remoting.clone(), game_id)
+    }
+    fn game_data(&self, game_id: u64) -> impl Query<Output = Option<GameData>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GameData>::new(self.remoting.clone(), game_id)
+    }
+    fn game_id_by_web_2_id(
+        &self,
+        web2_game_id: u64,
+    ) -> impl Query<Output = Option<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GameIdByWeb2Id>::new(
+            self.remoting.clone(),
+            web2_game_id,
+        )
+    }
+    fn games(
+        &self,
+        offset: u32,
+        limit: u32,
+        filter: GamesFilter,
+    ) -> impl Query<Output = GamesPage, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::Games>::new(
+            self.remoting.clone(),
+            (offset, limit, filter),
+        )
+    }
+    fn games_after(
+        &self,
+        cursor: u64,
+        limit: u32,
+        filter: GamesFilter,
+    ) -> impl Query<Output = GamesPage, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesAfter>::new(
+            self.remoting.clone(),
+            (cursor, limit, filter),
+        )
+    }
+    fn games_id_by_player_address(
+        &self,
+        address: ActorId,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdByPlayerAddress>::new(
+            self.remoting.clone(),
+            (address, offset, limit),
+        )
+    }
+    fn games_id_by_player_web_2_id(
+        &self,
+        web2_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdByPlayerWeb2Id>::new(
+            self.remoting.clone(),
+            (web2_id, offset, limit),
+        )
+    }
+    fn games_id_cancelled(&self) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdCancelled>::new(self.remoting.clone(), ())
+    }
+    fn games_id_cancelled_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdCancelledPage>::new(
+            self.remoting.clone(),
+            (offset, limit),
+        )
+    }
+    fn games_id_ended(&self) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdEnded>::new(self.remoting.clone(), ())
+    }
+    fn games_id_ended_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdEndedPage>::new(
+            self.remoting.clone(),
+            (offset, limit),
+        )
+    }
+    fn games_id_started(&self) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdStarted>::new(self.remoting.clone(), ())
+    }
+    fn games_id_started_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdStartedPage>::new(
+            self.remoting.clone(),
+            (offset, limit),
+        )
+    }
+    fn games_id_waiting(&self) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdWaiting>::new(self.remoting.clone(), ())
+    }
+    fn games_id_waiting_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<u64>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::GamesIdWaitingPage>::new(
+            self.remoting.clone(),
+            (offset, limit),
+        )
+    }
+    fn invitation_bet(
+        &self,
+        first_web2_id: u64,
+        second_web2_id: u64,
+    ) -> impl Query<Output = Option<u128>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::InvitationBet>::new(
+            self.remoting.clone(),
+            (first_web2_id, second_web2_id),
+        )
+    }
+    fn invitation_guest_bet(
+        &self,
+        first_web2_id: u64,
+        second_web2_id: u64,
+    ) -> impl Query<Output = Option<u128>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::InvitationGuestBet>::new(
+            self.remoting.clone(),
+            (first_web2_id, second_web2_id),
+        )
+    }
+    fn invitation_ttl_blocks(&self) -> impl Query<Output = u32, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::InvitationTtlBlocks>::new(self.remoting.clone(), ())
+    }
+    fn invitations_from_web_2_id(
+        &self,
+        web2_id: u64,
+    ) -> impl Query<Output = Option<InvitationsState>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::InvitationsFromWeb2Id>::new(
+            self.remoting.clone(),
+            web2_id,
+        )
+    }
+    fn invitations_lifetime(
+        &self,
+        web2_id: u64,
+    ) -> impl Query<Output = Vec<InvitationLifetime>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::InvitationsLifetime>::new(
+            self.remoting.clone(),
+            web2_id,
+        )
+    }
+    fn leaderboard(
+        &self,
+        kind: LeaderboardKind,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<LeaderboardEntry>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::Leaderboard>::new(
+            self.remoting.clone(),
+            (kind, offset, limit),
+        )
+    }
+    fn legal_moves(&self, game_id: u64) -> impl Query<Output = Vec<ChessMove>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::LegalMoves>::new(self.remoting.clone(), game_id)
+    }
+    fn market_config(&self) -> impl Query<Output = MarketConfig, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::MarketConfig>::new(self.remoting.clone(), ())
+    }
+    fn matchmaking_queue(
+        &self,
+        bet_amount: u128,
+        time_control: Option<u32>,
+    ) -> impl Query<Output = Vec<MatchmakingEntry>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::MatchmakingQueue>::new(
+            self.remoting.clone(),
+            (bet_amount, time_control),
+        )
+    }
+    fn player_rating(&self, web2_id: u64) -> impl Query<Output = PlayerRating, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::PlayerRating>::new(self.remoting.clone(), web2_id)
+    }
+    fn player_stats(&self, web2_id: u64) -> impl Query<Output = PlayerStats, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::PlayerStats>::new(self.remoting.clone(), web2_id)
+    }
+    fn rating_history(
+        &self,
+        web2_id: u64,
+    ) -> impl Query<Output = Vec<RatingChange>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::RatingHistory>::new(self.remoting.clone(), web2_id)
+    }
+    fn result_oracles(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::ResultOracles>::new(self.remoting.clone(), ())
+    }
+    fn spectator_bets(
+        &self,
+        game_id: u64,
+        address: ActorId,
+    ) -> impl Query<Output = Vec<(MarketOutcome, u128)>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SpectatorBets>::new(
+            self.remoting.clone(),
+            (game_id, address),
+        )
+    }
+    fn spectator_market(
+        &self,
+        game_id: u64,
+    ) -> impl Query<Output = Option<SpectatorMarketInfo>, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::SpectatorMarket>::new(self.remoting.clone(), game_id)
+    }
+    fn stake_limits(&self) -> impl Query<Output = StakeLimits, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::StakeLimits>::new(self.remoting.clone(), ())
+    }
+    fn total_fees_collected(&self) -> impl Query<Output = u128, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::TotalFeesCollected>::new(self.remoting.clone(), ())
+    }
+    fn treasury_balance(&self) -> impl Query<Output = u128, Args = R::Args> {
+        RemotingAction::<_, bet_2_chess::io::TreasuryBalance>::new(self.remoting.clone(), ())
+    }
+}
+
+pub mod bet_2_chess {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct AbortGame(());
+        impl AbortGame {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64) -> Vec<u8> {
+                <AbortGame as ActionIo>::encode_call(&game_id)
+            }
+        }
+        impl ActionIo for AbortGame {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 36, 65, 98, 111, 114, 116, 71, 97,
+                109, 101,
+            ];
+            type Params = u64;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct AcceptInvitation(());
+        impl AcceptInvitation {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                web2_user_id: u64,
+                web2_user_id_invitation_owner: u64,
+                web2_match_game_id: u64,
+            ) -> Vec<u8> {
+                <AcceptInvitation as ActionIo>::encode_call(&(
+                    web2_user_id,
+                    web2_user_id_invitation_owner,
+                    web2_match_game_id,
+                ))
+            }
+        }
+        impl ActionIo for AcceptInvitation {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 65, 99, 99, 101, 112, 116, 73,
+                110, 118, 105, 116, 97, 116, 105, 111, 110,
+            ];
+            type Params = (u64, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct AcceptInvitationSignless(());
+        impl AcceptInvitationSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                web2_user_id: u64,
+                web2_user_id_invitation_owner: u64,
+                web2_match_game_id: u64,
+            ) -> Vec<u8> {
+                <AcceptInvitationSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    web2_user_id_invitation_owner,
+                    web2_match_game_id,
+                ))
+            }
+        }
+        impl ActionIo for AcceptInvitationSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 96, 65, 99, 99, 101, 112, 116, 73,
+                110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct AcceptInvitationSignlessNoWallet(());
+        impl AcceptInvitationSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                web2_user_id_invitation_owner: u64,
+                web2_match_game_id: u64,
+            ) -> Vec<u8> {
+                <AcceptInvitationSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    web2_user_id_invitation_owner,
+                    web2_match_game_id,
+                ))
+            }
+        }
+        impl ActionIo for AcceptInvitationSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 128, 65, 99, 99, 101, 112, 116, 73,
+                110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
+                78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct AddAdmin(());
+        impl AddAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call(new_admin: ActorId) -> Vec<u8> {
+                <AddAdmin as ActionIo>::encode_call(&new_admin)
+            }
+        }
+        impl ActionIo for AddAdmin {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 65, 100, 100, 65, 100, 109, 105,
+                110,
+            ];
+            type Params = ActorId;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct AddResultOracle(());
+        impl AddResultOracle {
+            #[allow(dead_code)]
+            pub fn encode_call(oracle: ActorId) -> Vec<u8> {
+                <AddResultOracle as ActionIo>::encode_call(&oracle)
+            }
+        }
+        impl ActionIo for AddResultOracle {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 60, 65, 100, 100, 82, 101, 115, 117,
+                108, 116, 79, 114, 97, 99, 108, 101,
+            ];
+            type Params = ActorId;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CancelInvitation(());
+        impl CancelInvitation {
+            #[allow(dead_code)]
+            pub fn encode_call(first_web2_id: u64, second_web2_id: u64) -> Vec<u8> {
+                <CancelInvitation as ActionIo>::encode_call(&(first_web2_id, second_web2_id))
+            }
+        }
+        impl ActionIo for CancelInvitation {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 67, 97, 110, 99, 101, 108, 73,
+                110, 118, 105, 116, 97, 116, 105, 111, 110,
+            ];
+            type Params = (u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CancelInvitationSignless(());
+        impl CancelInvitationSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                first_web2_id: u64,
+                second_web2_id: u64,
+            ) -> Vec<u8> {
+                <CancelInvitationSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    first_web2_id,
+                    second_web2_id,
+                ))
+            }
+        }
+        impl ActionIo for CancelInvitationSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 96, 67, 97, 110, 99, 101, 108, 73,
+                110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CancelInvitationSignlessNoWallet(());
+        impl CancelInvitationSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                first_web2_id: u64,
+                second_web2_id: u64,
+            ) -> Vec<u8> {
+                <CancelInvitationSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    first_web2_id,
+                    second_web2_id,
+                ))
+            }
+        }
+        impl ActionIo for CancelInvitationSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 128, 67, 97, 110, 99, 101, 108, 73,
+                110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
+                78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CancelOpenGame(());
+        impl CancelOpenGame {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64) -> Vec<u8> {
+                <CancelOpenGame as ActionIo>::encode_call(&game_id)
+            }
+        }
+        impl ActionIo for CancelOpenGame {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 67, 97, 110, 99, 101, 108, 79,
+                112, 101, 110, 71, 97, 109, 101,
+            ];
+            type Params = u64;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CancelOpenGameSignless(());
+        impl CancelOpenGameSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId, game_id: u64) -> Vec<u8> {
+                <CancelOpenGameSignless as ActionIo>::encode_call(&(user_address, game_id))
+            }
+        }
+        impl ActionIo for CancelOpenGameSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 88, 67, 97, 110, 99, 101, 108, 79,
+                112, 101, 110, 71, 97, 109, 101, 83, 105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CancelOpenGameSignlessNoWallet(());
+        impl CancelOpenGameSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(no_wallet_name_encoded: String, game_id: u64) -> Vec<u8> {
+                <CancelOpenGameSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    game_id,
+                ))
+            }
+        }
+        impl ActionIo for CancelOpenGameSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 120, 67, 97, 110, 99, 101, 108, 79,
+                112, 101, 110, 71, 97, 109, 101, 83, 105, 103, 110, 108, 101, 115, 115, 78, 111,
+                87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct ClaimSpectatorWinnings(());
+        impl ClaimSpectatorWinnings {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64) -> Vec<u8> {
+                <ClaimSpectatorWinnings as ActionIo>::encode_call(&game_id)
+            }
+        }
+        impl ActionIo for ClaimSpectatorWinnings {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 88, 67, 108, 97, 105, 109, 83, 112,
+                101, 99, 116, 97, 116, 111, 114, 87, 105, 110, 110, 105, 110, 103, 115,
+            ];
+            type Params = u64;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct ClaimSpectatorWinningsSignless(());
+        impl ClaimSpectatorWinningsSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId, game_id: u64) -> Vec<u8> {
+                <ClaimSpectatorWinningsSignless as ActionIo>::encode_call(&(user_address, game_id))
+            }
+        }
+        impl ActionIo for ClaimSpectatorWinningsSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 120, 67, 108, 97, 105, 109, 83, 112,
+                101, 99, 116, 97, 116, 111, 114, 87, 105, 110, 110, 105, 110, 103, 115, 83, 105,
+                103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct ClaimSpectatorWinningsSignlessNoWallet(());
+        impl ClaimSpectatorWinningsSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(no_wallet_name_encoded: String, game_id: u64) -> Vec<u8> {
+                <ClaimSpectatorWinningsSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    game_id,
+                ))
+            }
+        }
+        impl ActionIo for ClaimSpectatorWinningsSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 152, 67, 108, 97, 105, 109, 83, 112,
+                101, 99, 116, 97, 116, 111, 114, 87, 105, 110, 110, 105, 110, 103, 115, 83, 105,
+                103, 110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CreateOpenGame(());
+        impl CreateOpenGame {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_user_id: u64) -> Vec<u8> {
+                <CreateOpenGame as ActionIo>::encode_call(&web2_user_id)
+            }
+        }
+        impl ActionIo for CreateOpenGame {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 67, 114, 101, 97, 116, 101, 79,
+                112, 101, 110, 71, 97, 109, 101,
+            ];
+            type Params = u64;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CreateOpenGameSignless(());
+        impl CreateOpenGameSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId, web2_user_id: u64) -> Vec<u8> {
+                <CreateOpenGameSignless as ActionIo>::encode_call(&(user_address, web2_user_id))
+            }
+        }
+        impl ActionIo for CreateOpenGameSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 88, 67, 114, 101, 97, 116, 101, 79,
+                112, 101, 110, 71, 97, 109, 101, 83, 105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CreateOpenGameSignlessNoWallet(());
+        impl CreateOpenGameSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(no_wallet_name_encoded: String, web2_user_id: u64) -> Vec<u8> {
+                <CreateOpenGameSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                ))
+            }
+        }
+        impl ActionIo for CreateOpenGameSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 120, 67, 114, 101, 97, 116, 101, 79,
+                112, 101, 110, 71, 97, 109, 101, 83, 105, 103, 110, 108, 101, 115, 115, 78, 111,
+                87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct DeclineInvitation(());
+        impl DeclineInvitation {
+            #[allow(dead_code)]
+            pub fn encode_call(first_web2_id: u64, second_web2_id: u64) -> Vec<u8> {
+                <DeclineInvitation as ActionIo>::encode_call(&(first_web2_id, second_web2_id))
+            }
+        }
+        impl ActionIo for DeclineInvitation {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 68, 68, 101, 99, 108, 105, 110, 101,
+                73, 110, 118, 105, 116, 97, 116, 105, 111, 110,
+            ];
+            type Params = (u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct DeclineInvitationSignless(());
+        impl DeclineInvitationSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                first_web2_id: u64,
+                second_web2_id: u64,
+            ) -> Vec<u8> {
+                <DeclineInvitationSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    first_web2_id,
+                    second_web2_id,
+                ))
+            }
+        }
+        impl ActionIo for DeclineInvitationSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 100, 68, 101, 99, 108, 105, 110, 101,
+                73, 110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115,
+                115,
+            ];
+            type Params = (ActorId, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct DeclineInvitationSignlessNoWallet(());
+        impl DeclineInvitationSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                first_web2_id: u64,
+                second_web2_id: u64,
+            ) -> Vec<u8> {
+                <DeclineInvitationSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    first_web2_id,
+                    second_web2_id,
+                ))
+            }
+        }
+        impl ActionIo for DeclineInvitationSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 132, 68, 101, 99, 108, 105, 110, 101,
+                73, 110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115,
+                115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct EndGameById(());
+        impl EndGameById {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64, game_winner: Option<ActorId>) -> Vec<u8> {
+                <EndGameById as ActionIo>::encode_call(&(game_id, game_winner))
+            }
+        }
+        impl ActionIo for EndGameById {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 44, 69, 110, 100, 71, 97, 109, 101,
+                66, 121, 73, 100,
+            ];
+            type Params = (u64, Option<ActorId>);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct EndMatch(());
+        impl EndMatch {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64, game_winner: Option<ActorId>) -> Vec<u8> {
+                <EndMatch as ActionIo>::encode_call(&(game_id, game_winner))
+            }
+        }
+        impl ActionIo for EndMatch {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 69, 110, 100, 77, 97, 116, 99,
+                104,
+            ];
+            type Params = (u64, Option<ActorId>);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct ExpireInvitation(());
+        impl ExpireInvitation {
+            #[allow(dead_code)]
+            pub fn encode_call(first_web2_id: u64, second_web2_id: u64) -> Vec<u8> {
+                <ExpireInvitation as ActionIo>::encode_call(&(first_web2_id, second_web2_id))
+            }
+        }
+        impl ActionIo for ExpireInvitation {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 69, 120, 112, 105, 114, 101, 73,
+                110, 118, 105, 116, 97, 116, 105, 111, 110,
+            ];
+            type Params = (u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinAnyWaitingGame(());
+        impl JoinAnyWaitingGame {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_user_id: u64) -> Vec<u8> {
+                <JoinAnyWaitingGame as ActionIo>::encode_call(&web2_user_id)
+            }
+        }
+        impl ActionIo for JoinAnyWaitingGame {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 72, 74, 111, 105, 110, 65, 110, 121,
+                87, 97, 105, 116, 105, 110, 103, 71, 97, 109, 101,
+            ];
+            type Params = u64;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinAnyWaitingGameSignless(());
+        impl JoinAnyWaitingGameSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId, web2_user_id: u64) -> Vec<u8> {
+                <JoinAnyWaitingGameSignless as ActionIo>::encode_call(&(user_address, web2_user_id))
+            }
+        }
+        impl ActionIo for JoinAnyWaitingGameSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 104, 74, 111, 105, 110, 65, 110, 121,
+                87, 97, 105, 116, 105, 110, 103, 71, 97, 109, 101, 83, 105, 103, 110, 108, 101,
+                115, 115,
+            ];
+            type Params = (ActorId, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinAnyWaitingGameSignlessNoWallet(());
+        impl JoinAnyWaitingGameSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(no_wallet_name_encoded: String, web2_user_id: u64) -> Vec<u8> {
+                <JoinAnyWaitingGameSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                ))
+            }
+        }
+        impl ActionIo for JoinAnyWaitingGameSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 136, 74, 111, 105, 110, 65, 110, 121,
+                87, 97, 105, 116, 105, 110, 103, 71, 97, 109, 101, 83, 105, 103, 110, 108, 101,
+                115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinMatchmakingQueue(());
+        impl JoinMatchmakingQueue {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                web2_user_id: u64,
+                time_control: Option<u32>,
+                rating_range: Option<super::RatingRange>,
+            ) -> Vec<u8> {
+                <JoinMatchmakingQueue as ActionIo>::encode_call(&(
+                    web2_user_id,
+                    time_control,
+                    rating_range,
+                ))
+            }
+        }
+        impl ActionIo for JoinMatchmakingQueue {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 80, 74, 111, 105, 110, 77, 97, 116,
+                99, 104, 109, 97, 107, 105, 110, 103, 81, 117, 101, 117, 101,
+            ];
+            type Params = (u64, Option<u32>, Option<super::RatingRange>);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinMatchmakingQueueSignless(());
+        impl JoinMatchmakingQueueSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                web2_user_id: u64,
+                time_control: Option<u32>,
+                rating_range: Option<super::RatingRange>,
+            ) -> Vec<u8> {
+                <JoinMatchmakingQueueSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    time_control,
+                    rating_range,
+                ))
+            }
+        }
+        impl ActionIo for JoinMatchmakingQueueSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 112, 74, 111, 105, 110, 77, 97, 116,
+                99, 104, 109, 97, 107, 105, 110, 103, 81, 117, 101, 117, 101, 83, 105, 103, 110,
+                108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64, Option<u32>, Option<super::RatingRange>);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinMatchmakingQueueSignlessNoWallet(());
+        impl JoinMatchmakingQueueSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                time_control: Option<u32>,
+                rating_range: Option<super::RatingRange>,
+            ) -> Vec<u8> {
+                <JoinMatchmakingQueueSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    time_control,
+                    rating_range,
+                ))
+            }
+        }
+        impl ActionIo for JoinMatchmakingQueueSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 144, 74, 111, 105, 110, 77, 97, 116,
+                99, 104, 109, 97, 107, 105, 110, 103, 81, 117, 101, 117, 101, 83, 105, 103, 110,
+                108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, Option<u32>, Option<super::RatingRange>);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinOpenGame(());
+        impl JoinOpenGame {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_user_id: u64, game_id: u64) -> Vec<u8> {
+                <JoinOpenGame as ActionIo>::encode_call(&(web2_user_id, game_id))
+            }
+        }
+        impl ActionIo for JoinOpenGame {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 48, 74, 111, 105, 110, 79, 112, 101,
+                110, 71, 97, 109, 101,
+            ];
+            type Params = (u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinOpenGameSignless(());
+        impl JoinOpenGameSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId, web2_user_id: u64, game_id: u64) -> Vec<u8> {
+                <JoinOpenGameSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    game_id,
+                ))
+            }
+        }
+        impl ActionIo for JoinOpenGameSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 80, 74, 111, 105, 110, 79, 112, 101,
+                110, 71, 97, 109, 101, 83, 105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinOpenGameSignlessNoWallet(());
+        impl JoinOpenGameSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                game_id: u64,
+            ) -> Vec<u8> {
+                <JoinOpenGameSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    game_id,
+                ))
+            }
+        }
+        impl ActionIo for JoinOpenGameSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 112, 74, 111, 105, 110, 79, 112, 101,
+                110, 71, 97, 109, 101, 83, 105, 103, 110, 108, 101, 115, 115, 78, 111, 87, 97, 108,
+                108, 101, 116,
+            ];
+            type Params = (String, u64, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct LeaveMatchmakingQueue(());
+        impl LeaveMatchmakingQueue {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <LeaveMatchmakingQueue as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for LeaveMatchmakingQueue {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 84, 76, 101, 97, 118, 101, 77, 97,
+                116, 99, 104, 109, 97, 107, 105, 110, 103, 81, 117, 101, 117, 101,
+            ];
+            type Params = ();
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct LeaveMatchmakingQueueSignless(());
+        impl LeaveMatchmakingQueueSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId) -> Vec<u8> {
+                <LeaveMatchmakingQueueSignless as ActionIo>::encode_call(&user_address)
+            }
+        }
+        impl ActionIo for LeaveMatchmakingQueueSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 116, 76, 101, 97, 118, 101, 77, 97,
+                116, 99, 104, 109, 97, 107, 105, 110, 103, 81, 117, 101, 117, 101, 83, 105, 103,
+                110, 108, 101, 115, 115,
+            ];
+            type Params = ActorId;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct LeaveMatchmakingQueueSignlessNoWallet(());
+        impl LeaveMatchmakingQueueSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(no_wallet_name_encoded: String) -> Vec<u8> {
+                <LeaveMatchmakingQueueSignlessNoWallet as ActionIo>::encode_call(
+                    &no_wallet_name_encoded,
+                )
+            }
+        }
+        impl ActionIo for LeaveMatchmakingQueueSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 148, 76, 101, 97, 118, 101, 77, 97,
+                116, 99, 104, 109, 97, 107, 105, 110, 103, 81, 117, 101, 117, 101, 83, 105, 103,
+                110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = String;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct MakeMove(());
+        impl MakeMove {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64, chess_move: super::ChessMove) -> Vec<u8> {
+                <MakeMove as ActionIo>::encode_call(&(game_id, chess_move))
+            }
+        }
+        impl ActionIo for MakeMove {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 77, 97, 107, 101, 77, 111, 118,
+                101,
+            ];
+            type Params = (u64, super::ChessMove);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct MakeMoveSignless(());
+        impl MakeMoveSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                game_id: u64,
+                chess_move: super::ChessMove,
+            ) -> Vec<u8> {
+                <MakeMoveSignless as ActionIo>::encode_call(&(user_address, game_id, chess_move))
+            }
+        }
+        impl ActionIo for MakeMoveSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 77, 97, 107, 101, 77, 111, 118,
+                101, 83, 105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64, super::ChessMove);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct MakeMoveSignlessNoWallet(());
+        impl MakeMoveSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                game_id: u64,
+                chess_move: super::ChessMove,
+            ) -> Vec<u8> {
+                <MakeMoveSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    game_id,
+                    chess_move,
+                ))
+            }
+        }
+        impl ActionIo for MakeMoveSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 96, 77, 97, 107, 101, 77, 111, 118,
+                101, 83, 105, 103, 110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, super::ChessMove);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct PlaceSpectatorBet(());
+        impl PlaceSpectatorBet {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64, outcome: super::MarketOutcome) -> Vec<u8> {
+                <PlaceSpectatorBet as ActionIo>::encode_call(&(game_id, outcome))
+            }
+        }
+        impl ActionIo for PlaceSpectatorBet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 68, 80, 108, 97, 99, 101, 83, 112,
+                101, 99, 116, 97, 116, 111, 114, 66, 101, 116,
+            ];
+            type Params = (u64, super::MarketOutcome);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct PlaceSpectatorBetSignless(());
+        impl PlaceSpectatorBetSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                game_id: u64,
+                outcome: super::MarketOutcome,
+            ) -> Vec<u8> {
+                <PlaceSpectatorBetSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    game_id,
+                    outcome,
+                ))
+            }
+        }
+        impl ActionIo for PlaceSpectatorBetSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 100, 80, 108, 97, 99, 101, 83, 112,
+                101, 99, 116, 97, 116, 111, 114, 66, 101, 116, 83, 105, 103, 110, 108, 101, 115,
+                115,
+            ];
+            type Params = (ActorId, u64, super::MarketOutcome);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct PlaceSpectatorBetSignlessNoWallet(());
+        impl PlaceSpectatorBetSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                game_id: u64,
+                outcome: super::MarketOutcome,
+            ) -> Vec<u8> {
+                <PlaceSpectatorBetSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    game_id,
+                    outcome,
+                ))
+            }
+        }
+        impl ActionIo for PlaceSpectatorBetSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 132, 80, 108, 97, 99, 101, 83, 112,
+                101, 99, 116, 97, 116, 111, 114, 66, 101, 116, 83, 105, 103, 110, 108, 101, 115,
+                115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, super::MarketOutcome);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct RemoveAdmin(());
+        impl RemoveAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call(admin: ActorId) -> Vec<u8> {
+                <RemoveAdmin as ActionIo>::encode_call(&admin)
+            }
+        }
+        impl ActionIo for RemoveAdmin {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 44, 82, 101, 109, 111, 118, 101, 65,
+                100, 109, 105, 110,
+            ];
+            type Params = ActorId;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct RemoveResultOracle(());
+        impl RemoveResultOracle {
+            #[allow(dead_code)]
+            pub fn encode_call(oracle: ActorId) -> Vec<u8> {
+                <RemoveResultOracle as ActionIo>::encode_call(&oracle)
+            }
+        }
+        impl ActionIo for RemoveResultOracle {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 72, 82, 101, 109, 111, 118, 101, 82,
+                101, 115, 117, 108, 116, 79, 114, 97, 99, 108, 101,
+            ];
+            type Params = ActorId;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct Resign(());
+        impl Resign {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64) -> Vec<u8> {
+                <Resign as ActionIo>::encode_call(&game_id)
+            }
+        }
+        impl ActionIo for Resign {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 24, 82, 101, 115, 105, 103, 110,
+            ];
+            type Params = u64;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct ResignSignless(());
+        impl ResignSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId, game_id: u64) -> Vec<u8> {
+                <ResignSignless as ActionIo>::encode_call(&(user_address, game_id))
+            }
+        }
+        impl ActionIo for ResignSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 82, 101, 115, 105, 103, 110, 83,
+                105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct ResignSignlessNoWallet(());
+        impl ResignSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(no_wallet_name_encoded: String, game_id: u64) -> Vec<u8> {
+                <ResignSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    game_id,
+                ))
+            }
+        }
+        impl ActionIo for ResignSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 88, 82, 101, 115, 105, 103, 110, 83,
+                105, 103, 110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SendInvitation(());
+        impl SendInvitation {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                web2_user_id: u64,
+                web2_guest_id: u64,
+                guest_bet_amount: Option<u128>,
+            ) -> Vec<u8> {
+                <SendInvitation as ActionIo>::encode_call(&(
+                    web2_user_id,
+                    web2_guest_id,
+                    guest_bet_amount,
+                ))
+            }
+        }
+        impl ActionIo for SendInvitation {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 83, 101, 110, 100, 73, 110, 118,
+                105, 116, 97, 116, 105, 111, 110,
+            ];
+            type Params = (u64, u64, Option<u128>);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SendInvitationSignless(());
+        impl SendInvitationSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                web2_user_id: u64,
+                web2_guest_id: u64,
+                guest_bet_amount: Option<u128>,
+            ) -> Vec<u8> {
+                <SendInvitationSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    web2_guest_id,
+                    guest_bet_amount,
+                ))
+            }
+        }
+        impl ActionIo for SendInvitationSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 88, 83, 101, 110, 100, 73, 110, 118,
+                105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64, u64, Option<u128>);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SendInvitationSignlessNoWallet(());
+        impl SendInvitationSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                web2_guest_id: u64,
+                guest_bet_amount: Option<u128>,
+            ) -> Vec<u8> {
+                <SendInvitationSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    web2_guest_id,
+                    guest_bet_amount,
+                ))
+            }
+        }
+        impl ActionIo for SendInvitationSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 120, 83, 101, 110, 100, 73, 110, 118,
+                105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115, 78, 111,
+                87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, u64, Option<u128>);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SetFeeConfig(());
+        impl SetFeeConfig {
+            #[allow(dead_code)]
+            pub fn encode_call(fee_config: super::FeeConfig) -> Vec<u8> {
+                <SetFeeConfig as ActionIo>::encode_call(&fee_config)
+            }
+        }
+        impl ActionIo for SetFeeConfig {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 48, 83, 101, 116, 70, 101, 101, 67,
+                111, 110, 102, 105, 103,
+            ];
+            type Params = super::FeeConfig;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SetInvitationTtl(());
+        impl SetInvitationTtl {
+            #[allow(dead_code)]
+            pub fn encode_call(ttl_blocks: u32) -> Vec<u8> {
+                <SetInvitationTtl as ActionIo>::encode_call(&ttl_blocks)
+            }
+        }
+        impl ActionIo for SetInvitationTtl {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 83, 101, 116, 73, 110, 118, 105,
+                116, 97, 116, 105, 111, 110, 84, 116, 108,
+            ];
+            type Params = u32;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SetMarketConfig(());
+        impl SetMarketConfig {
+            #[allow(dead_code)]
+            pub fn encode_call(market_config: super::MarketConfig) -> Vec<u8> {
+                <SetMarketConfig as ActionIo>::encode_call(&market_config)
+            }
+        }
+        impl ActionIo for SetMarketConfig {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 60, 83, 101, 116, 77, 97, 114, 107,
+                101, 116, 67, 111, 110, 102, 105, 103,
+            ];
+            type Params = super::MarketConfig;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SetStakeLimits(());
+        impl SetStakeLimits {
+            #[allow(dead_code)]
+            pub fn encode_call(stake_limits: super::StakeLimits) -> Vec<u8> {
+                <SetStakeLimits as ActionIo>::encode_call(&stake_limits)
+            }
+        }
+        impl ActionIo for SetStakeLimits {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 83, 101, 116, 83, 116, 97, 107,
+                101, 76, 105, 109, 105, 116, 115,
+            ];
+            type Params = super::StakeLimits;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SweepExpiredInvitations(());
+        impl SweepExpiredInvitations {
+            #[allow(dead_code)]
+            pub fn encode_call(limit: u32) -> Vec<u8> {
+                <SweepExpiredInvitations as ActionIo>::encode_call(&limit)
+            }
+        }
+        impl ActionIo for SweepExpiredInvitations {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 92, 83, 119, 101, 101, 112, 69, 120,
+                112, 105, 114, 101, 100, 73, 110, 118, 105, 116, 97, 116, 105, 111, 110, 115,
+            ];
+            type Params = u32;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct Withdraw(());
+        impl Withdraw {
+            #[allow(dead_code)]
+            pub fn encode_call(amount: u128) -> Vec<u8> {
+                <Withdraw as ActionIo>::encode_call(&amount)
+            }
+        }
+        impl ActionIo for Withdraw {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 87, 105, 116, 104, 100, 114, 97,
+                119,
+            ];
+            type Params = u128;
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct WithdrawSignless(());
+        impl WithdrawSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId, amount: u128) -> Vec<u8> {
+                <WithdrawSignless as ActionIo>::encode_call(&(user_address, amount))
+            }
+        }
+        impl ActionIo for WithdrawSignless {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 87, 105, 116, 104, 100, 114, 97,
+                119, 83, 105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct WithdrawSignlessNoWallet(());
+        impl WithdrawSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(no_wallet_name_encoded: String, amount: u128) -> Vec<u8> {
+                <WithdrawSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for WithdrawSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 96, 87, 105, 116, 104, 100, 114, 97,
+                119, 83, 105, 103, 110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct WithdrawTreasury(());
+        impl WithdrawTreasury {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId, amount: u128) -> Vec<u8> {
+                <WithdrawTreasury as ActionIo>::encode_call(&(to, amount))
+            }
+        }
+        impl ActionIo for WithdrawTreasury {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 87, 105, 116, 104, 100, 114, 97,
+                119, 84, 114, 101, 97, 115, 117, 114, 121,
+            ];
+            type Params = (ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct Admins(());
+        impl Admins {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Admins as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Admins {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 24, 65, 100, 109, 105, 110, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
+        pub struct AllGames(());
+        impl AllGames {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AllGames as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AllGames {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 65, 108, 108, 71, 97, 109, 101,
+                115,
+            ];
+            type Params = ();
+            type Reply = Vec<(u64, super::GameData)>;
+        }
+        pub struct Balance(());
+        impl Balance {
+            #[allow(dead_code)]
+            pub fn encode_call(address: ActorId) -> Vec<u8> {
+                <Balance as ActionIo>::encode_call(&address)
+            }
+        }
+        impl ActionIo for Balance {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 28, 66, 97, 108, 97, 110, 99, 101,
+            ];
+            type Params = ActorId;
+            type Reply = u128;
+        }
+        pub struct FeeConfig(());
+        impl FeeConfig {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <FeeConfig as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for FeeConfig {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 36, 70, 101, 101, 67, 111, 110, 102,
+                105, 103,
+            ];
+            type Params = ();
+            type Reply = super::FeeConfig;
+        }
+        pub struct GameBoard(());
+        impl GameBoard {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64) -> Vec<u8> {
+                <GameBoard as ActionIo>::encode_call(&game_id)
+            }
+        }
+        impl ActionIo for GameBoard {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 36, 71, 97, 109, 101, 66, 111, 97,
+                114, 100,
+            ];
+            type Params = u64;
+            type Reply = Option<super::ChessBoard>;
+        }
+        pub struct GameData(());
+        impl GameData {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64) -> Vec<u8> {
+                <GameData as ActionIo>::encode_call(&game_id)
+            }
+        }
+        impl ActionIo for GameData {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 71, 97, 109, 101, 68, 97, 116, 97,
+            ];
+            type Params = u64;
+            type Reply = Option<super::GameData>;
+        }
+        pub struct GameIdByWeb2Id(());
+        impl GameIdByWeb2Id {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_game_id: u64) -> Vec<u8> {
+                <GameIdByWeb2Id as ActionIo>::encode_call(&web2_game_id)
+            }
+        }
+        impl ActionIo for GameIdByWeb2Id {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 71, 97, 109, 101, 73, 100, 66,
+                121, 87, 101, 98, 50, 73, 100,
+            ];
+            type Params = u64;
+            type Reply = Option<u64>;
+        }
+        pub struct Games(());
+        impl Games {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u32, limit: u32, filter: super::GamesFilter) -> Vec<u8> {
+                <Games as ActionIo>::encode_call(&(offset, limit, filter))
+            }
+        }
+        impl ActionIo for Games {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 20, 71, 97, 109, 101, 115,
+            ];
+            type Params = (u32, u32, super::GamesFilter);
+            type Reply = super::GamesPage;
+        }
+        pub struct GamesAfter(());
+        impl GamesAfter {
+            #[allow(dead_code)]
+            pub fn encode_call(cursor: u64, limit: u32, filter: super::GamesFilter) -> Vec<u8> {
+                <GamesAfter as ActionIo>::encode_call(&(cursor, limit, filter))
+            }
+        }
+        impl ActionIo for GamesAfter {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 40, 71, 97, 109, 101, 115, 65, 102,
+                116, 101, 114,
+            ];
+            type Params = (u64, u32, super::GamesFilter);
+            type Reply = super::GamesPage;
+        }
+        pub struct GamesIdByPlayerAddress(());
+        impl GamesIdByPlayerAddress {
+            #[allow(dead_code)]
+            pub fn encode_call(address: ActorId, offset: u32, limit: u32) -> Vec<u8> {
+                <GamesIdByPlayerAddress as ActionIo>::encode_call(&(address, offset, limit))
+            }
+        }
+        impl ActionIo for GamesIdByPlayerAddress {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 88, 71, 97, 109, 101, 115, 73, 100,
+                66, 121, 80, 108, 97, 121, 101, 114, 65, 100, 100, 114, 101, 115, 115,
+            ];
+            type Params = (ActorId, u32, u32);
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdByPlayerWeb2Id(());
+        impl GamesIdByPlayerWeb2Id {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64, offset: u32, limit: u32) -> Vec<u8> {
+                <GamesIdByPlayerWeb2Id as ActionIo>::encode_call(&(web2_id, offset, limit))
+            }
+        }
+        impl ActionIo for GamesIdByPlayerWeb2Id {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 84, 71, 97, 109, 101, 115, 73, 100,
+                66, 121, 80, 108, 97, 121, 101, 114, 87, 101, 98, 50, 73, 100,
+            ];
+            type Params = (u64, u32, u32);
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdCancelled(());
+        impl GamesIdCancelled {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GamesIdCancelled as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GamesIdCancelled {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 71, 97, 109, 101, 115, 73, 100,
+                67, 97, 110, 99, 101, 108, 108, 101, 100,
+            ];
+            type Params = ();
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdCancelledPage(());
+        impl GamesIdCancelledPage {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u32, limit: u32) -> Vec<u8> {
+                <GamesIdCancelledPage as ActionIo>::encode_call(&(offset, limit))
+            }
+        }
+        impl ActionIo for GamesIdCancelledPage {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 80, 71, 97, 109, 101, 115, 73, 100,
+                67, 97, 110, 99, 101, 108, 108, 101, 100, 80, 97, 103, 101,
+            ];
+            type Params = (u32, u32);
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdEnded(());
+        impl GamesIdEnded {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GamesIdEnded as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GamesIdEnded {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 48, 71, 97, 109, 101, 115, 73, 100,
+                69, 110, 100, 101, 100,
+            ];
+            type Params = ();
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdEndedPage(());
+        impl GamesIdEndedPage {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u32, limit: u32) -> Vec<u8> {
+                <GamesIdEndedPage as ActionIo>::encode_call(&(offset, limit))
+            }
+        }
+        impl ActionIo for GamesIdEndedPage {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 71, 97, 109, 101, 115, 73, 100,
+                69, 110, 100, 101, 100, 80, 97, 103, 101,
+            ];
+            type Params = (u32, u32);
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdStarted(());
+        impl GamesIdStarted {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GamesIdStarted as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GamesIdStarted {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 71, 97, 109, 101, 115, 73, 100,
+                83, 116, 97, 114, 116, 101, 100,
+            ];
+            type Params = ();
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdStartedPage(());
+        impl GamesIdStartedPage {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u32, limit: u32) -> Vec<u8> {
+                <GamesIdStartedPage as ActionIo>::encode_call(&(offset, limit))
+            }
+        }
+        impl ActionIo for GamesIdStartedPage {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 72, 71, 97, 109, 101, 115, 73, 100,
+                83, 116, 97, 114, 116, 101, 100, 80, 97, 103, 101,
+            ];
+            type Params = (u32, u32);
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdWaiting(());
+        impl GamesIdWaiting {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GamesIdWaiting as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GamesIdWaiting {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 71, 97, 109, 101, 115, 73, 100,
+                87, 97, 105, 116, 105, 110, 103,
+            ];
+            type Params = ();
+            type Reply = Vec<u64>;
+        }
+        pub struct GamesIdWaitingPage(());
+        impl GamesIdWaitingPage {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u32, limit: u32) -> Vec<u8> {
+                <GamesIdWaitingPage as ActionIo>::encode_call(&(offset, limit))
+            }
+        }
+        impl ActionIo for GamesIdWaitingPage {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 72, 71, 97, 109, 101, 115, 73, 100,
+                87, 97, 105, 116, 105, 110, 103, 80, 97, 103, 101,
+            ];
+            type Params = (u32, u32);
+            type Reply = Vec<u64>;
+        }
+        pub struct InvitationBet(());
+        impl InvitationBet {
+            #[allow(dead_code)]
+            pub fn encode_call(first_web2_id: u64, second_web2_id: u64) -> Vec<u8> {
+                <InvitationBet as ActionIo>::encode_call(&(first_web2_id, second_web2_id))
+            }
+        }
+        impl ActionIo for InvitationBet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 52, 73, 110, 118, 105, 116, 97, 116,
+                105, 111, 110, 66, 101, 116,
+            ];
+            type Params = (u64, u64);
+            type Reply = Option<u128>;
+        }
+        pub struct InvitationGuestBet(());
+        impl InvitationGuestBet {
+            #[allow(dead_code)]
+            pub fn encode_call(first_web2_id: u64, second_web2_id: u64) -> Vec<u8> {
+                <InvitationGuestBet as ActionIo>::encode_call(&(first_web2_id, second_web2_id))
+            }
+        }
+        impl ActionIo for InvitationGuestBet {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 72, 73, 110, 118, 105, 116, 97, 116,
+                105, 111, 110, 71, 117, 101, 115, 116, 66, 101, 116,
+            ];
+            type Params = (u64, u64);
+            type Reply = Option<u128>;
+        }
+        pub struct InvitationTtlBlocks(());
+        impl InvitationTtlBlocks {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <InvitationTtlBlocks as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for InvitationTtlBlocks {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 76, 73, 110, 118, 105, 116, 97, 116,
+                105, 111, 110, 84, 116, 108, 66, 108, 111, 99, 107, 115,
+            ];
+            type Params = ();
+            type Reply = u32;
+        }
+        pub struct InvitationsFromWeb2Id(());
+        impl InvitationsFromWeb2Id {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <InvitationsFromWeb2Id as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for InvitationsFromWeb2Id {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 84, 73, 110, 118, 105, 116, 97, 116,
+                105, 111, 110, 115, 70, 114, 111, 109, 87, 101, 98, 50, 73, 100,
+            ];
+            type Params = u64;
+            type Reply = Option<super::InvitationsState>;
+        }
+        pub struct InvitationsLifetime(());
+        impl InvitationsLifetime {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <InvitationsLifetime as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for InvitationsLifetime {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 76, 73, 110, 118, 105, 116, 97, 116,
+                105, 111, 110, 115, 76, 105, 102, 101, 116, 105, 109, 101,
+            ];
+            type Params = u64;
+            type Reply = Vec<super::InvitationLifetime>;
+        }
+        pub struct Leaderboard(());
+        impl Leaderboard {
+            #[allow(dead_code)]
+            pub fn encode_call(kind: super::LeaderboardKind, offset: u32, limit: u32) -> Vec<u8> {
+                <Leaderboard as ActionIo>::encode_call(&(kind, offset, limit))
+            }
+        }
+        impl ActionIo for Leaderboard {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 44, 76, 101, 97, 100, 101, 114, 98,
+                111, 97, 114, 100,
+            ];
+            type Params = (super::LeaderboardKind, u32, u32);
+            type Reply = Vec<super::LeaderboardEntry>;
+        }
+        pub struct LegalMoves(());
+        impl LegalMoves {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64) -> Vec<u8> {
+                <LegalMoves as ActionIo>::encode_call(&game_id)
+            }
+        }
+        impl ActionIo for LegalMoves {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 40, 76, 101, 103, 97, 108, 77, 111,
+                118, 101, 115,
+            ];
+            type Params = u64;
+            type Reply = Vec<super::ChessMove>;
+        }
+        pub struct MarketConfig(());
+        impl MarketConfig {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <MarketConfig as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for MarketConfig {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 48, 77, 97, 114, 107, 101, 116, 67,
+                111, 110, 102, 105, 103,
+            ];
+            type Params = ();
+            type Reply = super::MarketConfig;
+        }
+        pub struct MatchmakingQueue(());
+        impl MatchmakingQueue {
+            #[allow(dead_code)]
+            pub fn encode_call(bet_amount: u128, time_control: Option<u32>) -> Vec<u8> {
+                <MatchmakingQueue as ActionIo>::encode_call(&(bet_amount, time_control))
+            }
+        }
+        impl ActionIo for MatchmakingQueue {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 77, 97, 116, 99, 104, 109, 97,
+                107, 105, 110, 103, 81, 117, 101, 117, 101,
+            ];
+            type Params = (u128, Option<u32>);
+            type Reply = Vec<super::MatchmakingEntry>;
+        }
+        pub struct PlayerRating(());
+        impl PlayerRating {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <PlayerRating as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for PlayerRating {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 48, 80, 108, 97, 121, 101, 114, 82,
+                97, 116, 105, 110, 103,
+            ];
+            type Params = u64;
+            type Reply = super::PlayerRating;
+        }
+        pub struct PlayerStats(());
+        impl PlayerStats {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <PlayerStats as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for PlayerStats {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 44, 80, 108, 97, 121, 101, 114, 83,
+                116, 97, 116, 115,
+            ];
+            type Params = u64;
+            type Reply = super::PlayerStats;
+        }
+        pub struct RatingHistory(());
+        impl RatingHistory {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <RatingHistory as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for RatingHistory {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 52, 82, 97, 116, 105, 110, 103, 72,
+                105, 115, 116, 111, 114, 121,
+            ];
+            type Params = u64;
+            type Reply = Vec<super::RatingChange>;
+        }
+        pub struct ResultOracles(());
+        impl ResultOracles {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <ResultOracles as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for ResultOracles {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 52, 82, 101, 115, 117, 108, 116, 79,
+                114, 97, 99, 108, 101, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
+        pub struct SpectatorBets(());
+        impl SpectatorBets {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64, address: ActorId) -> Vec<u8> {
+                <SpectatorBets as ActionIo>::encode_call(&(game_id, address))
+            }
+        }
+        impl ActionIo for SpectatorBets {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 52, 83, 112, 101, 99, 116, 97, 116,
+                111, 114, 66, 101, 116, 115,
+            ];
+            type Params = (u64, ActorId);
+            type Reply = Vec<(super::MarketOutcome, u128)>;
+        }
+        pub struct SpectatorMarket(());
+        impl SpectatorMarket {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64) -> Vec<u8> {
+                <SpectatorMarket as ActionIo>::encode_call(&game_id)
+            }
+        }
+        impl ActionIo for SpectatorMarket {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 60, 83, 112, 101, 99, 116, 97, 116,
+                111, 114, 77, 97, 114, 107, 101, 116,
+            ];
+            type Params = u64;
+            type Reply = Option<super::SpectatorMarketInfo>;
+        }
+        pub struct StakeLimits(());
+        impl StakeLimits {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <StakeLimits as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for StakeLimits {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 44, 83, 116, 97, 107, 101, 76, 105,
+                109, 105, 116, 115,
+            ];
+            type Params = ();
+            type Reply = super::StakeLimits;
+        }
+        pub struct TotalFeesCollected(());
+        impl TotalFeesCollected {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <TotalFeesCollected as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for TotalFeesCollected {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 72, 84, 111, 116, 97, 108, 70, 101,
+                101, 115, 67, 111, 108, 108, 101, 99, 116, 101, 100,
+            ];
+            type Params = ();
+            type Reply = u128;
+        }
+        pub struct TreasuryBalance(());
+        impl TreasuryBalance {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <TreasuryBalance as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for TreasuryBalance {
+            const ROUTE: &'static [u8] = &[
+                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 60, 84, 114, 101, 97, 115, 117, 114,
+                121, 66, 97, 108, 97, 110, 99, 101,
+            ];
+            type Params = ();
+            type Reply = u128;
+        }
+    }
+}
+pub struct QueryService<R> {
+    remoting: R,
+}
+impl<R> QueryService<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::QueryService for QueryService<R> {
+    type Args = R::Args;
+    fn signless_account_data(
+        &self,
+        signless_address: ActorId,
+    ) -> impl Query<Output = QueryEvent, Args = R::Args> {
+        RemotingAction::<_, query_service::io::SignlessAccountData>::new(
+            self.remoting.clone(),
+            signless_address,
+        )
+    }
+    fn signless_address_from_no_wallet_account(
+        &self,
+        no_wallet_account: String,
+    ) -> impl Query<Output = QueryEvent, Args = R::Args> {
+        RemotingAction::<_, query_service::io::SignlessAddressFromNoWalletAccount>::new(
+            self.remoting.clone(),
+            no_wallet_account,
+        )
+    }
+    fn signless_address_from_user_address(
+        &self,
+        user_address: ActorId,
+    ) -> impl Query<Output = QueryEvent, Args = R::Args> {
+        RemotingAction::<_, query_service::io::SignlessAddressFromUserAddress>::new(
+            self.remoting.clone(),
+            user_address,
+        )
+    }
+}
+
+pub mod query_service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct SignlessAccountData(());
+        impl SignlessAccountData {
+            #[allow(dead_code)]
+            pub fn encode_call(signless_address: ActorId) -> Vec<u8> {
+                <SignlessAccountData as ActionIo>::encode_call(&signless_address)
+            }
+        }
+        impl ActionIo for SignlessAccountData {
+            const ROUTE: &'static [u8] = &[
+                48, 81, 117, 101, 114, 121, 83, 101, 114, 118, 105, 99, 101, 76, 83, 105, 103, 110,
+                108, 101, 115, 115, 65, 99, 99, 111, 117, 110, 116, 68, 97, 116, 97,
+            ];
+            type Params = ActorId;
+            type Reply = super::QueryEvent;
+        }
+        pub struct SignlessAddressFromNoWalletAccount(());
+        impl SignlessAddressFromNoWalletAccount {
+            #[allow(dead_code)]
+            pub fn encode_call(no_wallet_account: String) -> Vec<u8> {
+                <SignlessAddressFromNoWalletAccount as ActionIo>::encode_call(&no_wallet_account)
+            }
+        }
+        impl ActionIo for SignlessAddressFromNoWalletAccount {
+            const ROUTE: &'static [u8] = &[
+                48, 81, 117, 101, 114, 121, 83, 101, 114, 118, 105, 99, 101, 136, 83, 105, 103,
+                110, 108, 101, 115, 115, 65, 100, 100, 114, 101, 115, 115, 70, 114, 111, 109, 78,
+                111, 87, 97, 108, 108, 101, 116, 65, 99, 99, 111, 117, 110, 116,
+            ];
+            type Params = String;
+            type Reply = super::QueryEvent;
+        }
+        pub struct SignlessAddressFromUserAddress(());
+        impl SignlessAddressFromUserAddress {
+            #[allow(dead_code)]
+            pub fn encode_call(user_address: ActorId) -> Vec<u8> {
+                <SignlessAddressFromUserAddress as ActionIo>::encode_call(&user_address)
+            }
+        }
+        impl ActionIo for SignlessAddressFromUserAddress {
+            const ROUTE: &'static [u8] = &[
+                48, 81, 117, 101, 114, 121, 83, 101, 114, 118, 105, 99, 101, 120, 83, 105, 103,
+                110, 108, 101, 115, 115, 65, 100, 100, 114, 101, 115, 115, 70, 114, 111, 109, 85,
+                115, 101, 114, 65, 100, 100, 114, 101, 115, 115,
+            ];
+            type Params = ActorId;
+            type Reply = super::QueryEvent;
+        }
+    }
+}
+pub struct Signless<R> {
+    remoting: R,
+}
+impl<R> Signless<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Signless for Signless<R> {
+    type Args = R::Args;
+    fn bind_signless_data_to_address(
+        &mut self,
+        user_address: ActorId,
+        signless_data: SignlessAccount,
+    ) -> impl Call<Output = SignlessEvent, Args = R::Args> {
+        RemotingAction::<_, signless::io::BindSignlessDataToAddress>::new(
+            self.remoting.clone(),
+            (user_address, signless_data),
+        )
+    }
+    fn bind_signless_data_to_no_wallet_account(
+        &mut self,
+        no_wallet_account: String,
+        signless_data: SignlessAccount,
+    ) -> impl Call<Output = SignlessEvent, Args = R::Args> {
+        RemotingAction::<_, signless::io::BindSignlessDataToNoWalletAccount>::new(
+            self.remoting.clone(),
+            (no_wallet_account, signless_data),
+        )
+    }
+}
+
+pub mod signless {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct BindSignlessDataToAddress(());
+        impl BindSignlessDataToAddress {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                signless_data: super::SignlessAccount,
+            ) -> Vec<u8> {
+                <BindSignlessDataToAddress as ActionIo>::encode_call(&(user_address, signless_data))
+            }
+        }
+        impl ActionIo for BindSignlessDataToAddress {
+            const ROUTE: &'static [u8] = &[
+                32, 83, 105, 103, 110, 108, 101, 115, 115, 100, 66, 105, 110, 100, 83, 105, 103,
+                110, 108, 101, 115, 115, 68, 97, 116, 97, 84, 111, 65, 100, 100, 114, 101, 115,
+                115,
+            ];
+            type Params = (ActorId, super::SignlessAccount);
+            type Reply = super::SignlessEvent;
+        }
+        pub struct BindSignlessDataToNoWalletAccount(());
+        impl BindSignlessDataToNoWalletAccount {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_account: String,
+                signless_data: super::SignlessAccount,
+            ) -> Vec<u8> {
+                <BindSignlessDataToNoWalletAccount as ActionIo>::encode_call(&(
+                    no_wallet_account,
+                    signless_data,
+                ))
+            }
+        }
+        impl ActionIo for BindSignlessDataToNoWalletAccount {
+            const ROUTE: &'static [u8] = &[
+                32, 83, 105, 103, 110, 108, 101, 115, 115, 132, 66, 105, 110, 100, 83, 105, 103,
+                110, 108, 101, 115, 115, 68, 97, 116, 97, 84, 111, 78, 111, 87, 97, 108, 108, 101,
+                116, 65, 99, 99, 111, 117, 110, 116,
+            ];
+            type Params = (String, super::SignlessAccount);
+            type Reply = super::SignlessEvent;
+        }
+    }
+}
+pub struct TokenStakes<R> {
+    remoting: R,
+}
+impl<R> TokenStakes<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::TokenStakes for TokenStakes<R> {
+    type Args = R::Args;
+    fn accept_token_invitation(
+        &mut self,
+        web2_user_id: u64,
+        web2_user_id_invitation_owner: u64,
+        web2_match_game_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::AcceptTokenInvitation>::new(
+            self.remoting.clone(),
+            (
+                web2_user_id,
+                web2_user_id_invitation_owner,
+                web2_match_game_id,
+                token,
+                amount,
+            ),
+        )
+    }
+    fn accept_token_invitation_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        web2_user_id_invitation_owner: u64,
+        web2_match_game_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::AcceptTokenInvitationSignless>::new(
+            self.remoting.clone(),
+            (
+                user_address,
+                web2_user_id,
+                web2_user_id_invitation_owner,
+                web2_match_game_id,
+                token,
+                amount,
+            ),
+        )
+    }
+    fn accept_token_invitation_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        web2_user_id_invitation_owner: u64,
+        web2_match_game_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::AcceptTokenInvitationSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (
+                no_wallet_name_encoded,
+                web2_user_id,
+                web2_user_id_invitation_owner,
+                web2_match_game_id,
+                token,
+                amount,
+            ),
+        )
+    }
+    fn create_open_token_game(
+        &mut self,
+        web2_user_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::CreateOpenTokenGame>::new(
+            self.remoting.clone(),
+            (web2_user_id, token, amount),
+        )
+    }
+    fn create_open_token_game_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::CreateOpenTokenGameSignless>::new(
+            self.remoting.clone(),
+            (user_address, web2_user_id, token, amount),
+        )
+    }
+    fn create_open_token_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::CreateOpenTokenGameSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, web2_user_id, token, amount),
+        )
+    }
+    fn join_open_token_game(
+        &mut self,
+        web2_user_id: u64,
+        game_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::JoinOpenTokenGame>::new(
+            self.remoting.clone(),
+            (web2_user_id, game_id, token, amount),
+        )
+    }
+    fn join_open_token_game_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        game_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::JoinOpenTokenGameSignless>::new(
+            self.remoting.clone(),
+            (user_address, web2_user_id, game_id, token, amount),
+        )
+    }
+    fn join_open_token_game_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        game_id: u64,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::JoinOpenTokenGameSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, web2_user_id, game_id, token, amount),
+        )
+    }
+    fn send_token_invitation(
+        &mut self,
+        web2_user_id: u64,
+        guest_web2_id: u64,
+        token: ActorId,
+        amount: u128,
+        guest_amount: Option<u128>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::SendTokenInvitation>::new(
+            self.remoting.clone(),
+            (web2_user_id, guest_web2_id, token, amount, guest_amount),
+        )
+    }
+    fn send_token_invitation_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        guest_web2_id: u64,
+        token: ActorId,
+        amount: u128,
+        guest_amount: Option<u128>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::SendTokenInvitationSignless>::new(
+            self.remoting.clone(),
+            (
+                user_address,
+                web2_user_id,
+                guest_web2_id,
+                token,
+                amount,
+                guest_amount,
+            ),
+        )
+    }
+    fn send_token_invitation_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        guest_web2_id: u64,
+        token: ActorId,
+        amount: u128,
+        guest_amount: Option<u128>,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::SendTokenInvitationSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (
+                no_wallet_name_encoded,
+                web2_user_id,
+                guest_web2_id,
+                token,
+                amount,
+                guest_amount,
+            ),
+        )
+    }
+    fn withdraw_token(
+        &mut self,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::WithdrawToken>::new(
+            self.remoting.clone(),
+            (token, amount),
+        )
+    }
+    fn withdraw_token_signless(
+        &mut self,
+        user_address: ActorId,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::WithdrawTokenSignless>::new(
+            self.remoting.clone(),
+            (user_address, token, amount),
+        )
+    }
+    fn withdraw_token_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        token: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::WithdrawTokenSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, token, amount),
+        )
+    }
+    fn withdraw_treasury_token(
+        &mut self,
+        token: ActorId,
+        to: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Bet2ChessEvents, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::WithdrawTreasuryToken>::new(
+            self.remoting.clone(),
+            (token, to, amount),
+        )
+    }
+    fn token_balance(
+        &self,
+        token: ActorId,
+        address: ActorId,
+    ) -> impl Query<Output = u128, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::TokenBalance>::new(
+            self.remoting.clone(),
+            (token, address),
+        )
+    }
+    fn treasury_token_balance(&self, token: ActorId) -> impl Query<Output = u128, Args = R::Args> {
+        RemotingAction::<_, token_stakes::io::TreasuryTokenBalance>::new(
+            self.remoting.clone(),
+            token,
+        )
+    }
+}
+
+pub mod token_stakes {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct AcceptTokenInvitation(());
+        impl AcceptTokenInvitation {
             #[allow(dead_code)]
-            pub fn encode_call(first_web2_id: u64, second_web2_id: u64) -> Vec<u8> {
-                <CancelInvitation as ActionIo>::encode_call(&(first_web2_id, second_web2_id))
+            pub fn encode_call(
+                web2_user_id: u64,
+                web2_user_id_invitation_owner: u64,
+                web2_match_game_id: u64,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <AcceptTokenInvitation as ActionIo>::encode_call(&(
+                    web2_user_id,
+                    web2_user_id_invitation_owner,
+                    web2_match_game_id,
+                    token,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for AcceptTokenInvitation {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 84, 65, 99, 99, 101, 112,
+                116, 84, 111, 107, 101, 110, 73, 110, 118, 105, 116, 97, 116, 105, 111, 110,
+            ];
+            type Params = (u64, u64, u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct AcceptTokenInvitationSignless(());
+        impl AcceptTokenInvitationSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                web2_user_id: u64,
+                web2_user_id_invitation_owner: u64,
+                web2_match_game_id: u64,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <AcceptTokenInvitationSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    web2_user_id_invitation_owner,
+                    web2_match_game_id,
+                    token,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for AcceptTokenInvitationSignless {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 116, 65, 99, 99, 101, 112,
+                116, 84, 111, 107, 101, 110, 73, 110, 118, 105, 116, 97, 116, 105, 111, 110, 83,
+                105, 103, 110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64, u64, u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct AcceptTokenInvitationSignlessNoWallet(());
+        impl AcceptTokenInvitationSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                web2_user_id_invitation_owner: u64,
+                web2_match_game_id: u64,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <AcceptTokenInvitationSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    web2_user_id_invitation_owner,
+                    web2_match_game_id,
+                    token,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for AcceptTokenInvitationSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 148, 65, 99, 99, 101, 112,
+                116, 84, 111, 107, 101, 110, 73, 110, 118, 105, 116, 97, 116, 105, 111, 110, 83,
+                105, 103, 110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, u64, u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CreateOpenTokenGame(());
+        impl CreateOpenTokenGame {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_user_id: u64, token: ActorId, amount: u128) -> Vec<u8> {
+                <CreateOpenTokenGame as ActionIo>::encode_call(&(web2_user_id, token, amount))
+            }
+        }
+        impl ActionIo for CreateOpenTokenGame {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 76, 67, 114, 101, 97, 116,
+                101, 79, 112, 101, 110, 84, 111, 107, 101, 110, 71, 97, 109, 101,
+            ];
+            type Params = (u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CreateOpenTokenGameSignless(());
+        impl CreateOpenTokenGameSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                web2_user_id: u64,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <CreateOpenTokenGameSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    token,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for CreateOpenTokenGameSignless {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 108, 67, 114, 101, 97, 116,
+                101, 79, 112, 101, 110, 84, 111, 107, 101, 110, 71, 97, 109, 101, 83, 105, 103,
+                110, 108, 101, 115, 115,
+            ];
+            type Params = (ActorId, u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct CreateOpenTokenGameSignlessNoWallet(());
+        impl CreateOpenTokenGameSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <CreateOpenTokenGameSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    token,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for CreateOpenTokenGameSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 140, 67, 114, 101, 97, 116,
+                101, 79, 112, 101, 110, 84, 111, 107, 101, 110, 71, 97, 109, 101, 83, 105, 103,
+                110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinOpenTokenGame(());
+        impl JoinOpenTokenGame {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                web2_user_id: u64,
+                game_id: u64,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <JoinOpenTokenGame as ActionIo>::encode_call(&(
+                    web2_user_id,
+                    game_id,
+                    token,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for JoinOpenTokenGame {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 68, 74, 111, 105, 110, 79,
+                112, 101, 110, 84, 111, 107, 101, 110, 71, 97, 109, 101,
+            ];
+            type Params = (u64, u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinOpenTokenGameSignless(());
+        impl JoinOpenTokenGameSignless {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                user_address: ActorId,
+                web2_user_id: u64,
+                game_id: u64,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <JoinOpenTokenGameSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    game_id,
+                    token,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for JoinOpenTokenGameSignless {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 100, 74, 111, 105, 110, 79,
+                112, 101, 110, 84, 111, 107, 101, 110, 71, 97, 109, 101, 83, 105, 103, 110, 108,
+                101, 115, 115,
+            ];
+            type Params = (ActorId, u64, u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct JoinOpenTokenGameSignlessNoWallet(());
+        impl JoinOpenTokenGameSignlessNoWallet {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                game_id: u64,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <JoinOpenTokenGameSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    game_id,
+                    token,
+                    amount,
+                ))
+            }
+        }
+        impl ActionIo for JoinOpenTokenGameSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 132, 74, 111, 105, 110, 79,
+                112, 101, 110, 84, 111, 107, 101, 110, 71, 97, 109, 101, 83, 105, 103, 110, 108,
+                101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, u64, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct SendTokenInvitation(());
+        impl SendTokenInvitation {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                web2_user_id: u64,
+                guest_web2_id: u64,
+                token: ActorId,
+                amount: u128,
+                guest_amount: Option<u128>,
+            ) -> Vec<u8> {
+                <SendTokenInvitation as ActionIo>::encode_call(&(
+                    web2_user_id,
+                    guest_web2_id,
+                    token,
+                    amount,
+                    guest_amount,
+                ))
             }
         }
-        impl ActionIo for CancelInvitation {
+        impl ActionIo for SendTokenInvitation {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 64, 67, 97, 110, 99, 101, 108, 73,
-                110, 118, 105, 116, 97, 116, 105, 111, 110,
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 76, 83, 101, 110, 100, 84,
+                111, 107, 101, 110, 73, 110, 118, 105, 116, 97, 116, 105, 111, 110,
             ];
-            type Params = (u64, u64);
+            type Params = (u64, u64, ActorId, u128, Option<u128>);
             type Reply = super::Bet2ChessEvents;
         }
-        pub struct CancelInvitationSignless(());
-        impl CancelInvitationSignless {
+        pub struct SendTokenInvitationSignless(());
+        impl SendTokenInvitationSignless {
             #[allow(dead_code)]
             pub fn encode_call(
                 user_address: ActorId,
-                first_web2_id: u64,
-                second_web2_id: u64,
+                web2_user_id: u64,
+                guest_web2_id: u64,
+                token: ActorId,
+                amount: u128,
+                guest_amount: Option<u128>,
             ) -> Vec<u8> {
-                <CancelInvitationSignless as ActionIo>::encode_call(&(
+                <SendTokenInvitationSignless as ActionIo>::encode_call(&(
                     user_address,
-                    first_web2_id,
-                    second_web2_id,
+                    web2_user_id,
+                    guest_web2_id,
+                    token,
+                    amount,
+                    guest_amount,
                 ))
             }
         }
-        impl ActionIo for CancelInvitationSignless {
+        impl ActionIo for SendTokenInvitationSignless {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 96, 67, 97, 110, 99, 101, 108, 73,
-                110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 108, 83, 101, 110, 100, 84,
+                111, 107, 101, 110, 73, 110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103,
+                110, 108, 101, 115, 115,
             ];
-            type Params = (ActorId, u64, u64);
+            type Params = (ActorId, u64, u64, ActorId, u128, Option<u128>);
             type Reply = super::Bet2ChessEvents;
         }
-        pub struct CancelInvitationSignlessNoWallet(());
-        impl CancelInvitationSignlessNoWallet {
+        pub struct SendTokenInvitationSignlessNoWallet(());
+        impl SendTokenInvitationSignlessNoWallet {
             #[allow(dead_code)]
             pub fn encode_call(
                 no_wallet_name_encoded: String,
-                first_web2_id: u64,
-                second_web2_id: u64,
+                web2_user_id: u64,
+                guest_web2_id: u64,
+                token: ActorId,
+                amount: u128,
+                guest_amount: Option<u128>,
             ) -> Vec<u8> {
-                <CancelInvitationSignlessNoWallet as ActionIo>::encode_call(&(
+                <SendTokenInvitationSignlessNoWallet as ActionIo>::encode_call(&(
                     no_wallet_name_encoded,
-                    first_web2_id,
-                    second_web2_id,
+                    web2_user_id,
+                    guest_web2_id,
+                    token,
+                    amount,
+                    guest_amount,
                 ))
             }
         }
-        impl ActionIo for CancelInvitationSignlessNoWallet {
+        impl ActionIo for SendTokenInvitationSignlessNoWallet {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 128, 67, 97, 110, 99, 101, 108, 73,
-                110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
-                78, 111, 87, 97, 108, 108, 101, 116,
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 140, 83, 101, 110, 100, 84,
+                111, 107, 101, 110, 73, 110, 118, 105, 116, 97, 116, 105, 111, 110, 83, 105, 103,
+                110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
             ];
-            type Params = (String, u64, u64);
+            type Params = (String, u64, u64, ActorId, u128, Option<u128>);
             type Reply = super::Bet2ChessEvents;
         }
-        pub struct EndGameById(());
-        impl EndGameById {
+        pub struct WithdrawToken(());
+        impl WithdrawToken {
             #[allow(dead_code)]
-            pub fn encode_call(game_id: u64, game_winner: Option<ActorId>) -> Vec<u8> {
-                <EndGameById as ActionIo>::encode_call(&(game_id, game_winner))
+            pub fn encode_call(token: ActorId, amount: u128) -> Vec<u8> {
+                <WithdrawToken as ActionIo>::encode_call(&(token, amount))
             }
         }
-        impl ActionIo for EndGameById {
+        impl ActionIo for WithdrawToken {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 44, 69, 110, 100, 71, 97, 109, 101,
-                66, 121, 73, 100,
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 52, 87, 105, 116, 104, 100,
+                114, 97, 119, 84, 111, 107, 101, 110,
             ];
-            type Params = (u64, Option<ActorId>);
+            type Params = (ActorId, u128);
             type Reply = super::Bet2ChessEvents;
         }
-        pub struct EndMatch(());
-        impl EndMatch {
+        pub struct WithdrawTokenSignless(());
+        impl WithdrawTokenSignless {
             #[allow(dead_code)]
-            pub fn encode_call(game_id: u64, game_winner: Option<ActorId>) -> Vec<u8> {
-                <EndMatch as ActionIo>::encode_call(&(game_id, game_winner))
+            pub fn encode_call(user_address: ActorId, token: ActorId, amount: u128) -> Vec<u8> {
+                <WithdrawTokenSignless as ActionIo>::encode_call(&(user_address, token, amount))
             }
         }
-        impl ActionIo for EndMatch {
+        impl ActionIo for WithdrawTokenSignless {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 69, 110, 100, 77, 97, 116, 99,
-                104,
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 84, 87, 105, 116, 104, 100,
+                114, 97, 119, 84, 111, 107, 101, 110, 83, 105, 103, 110, 108, 101, 115, 115,
             ];
-            type Params = (u64, Option<ActorId>);
+            type Params = (ActorId, ActorId, u128);
             type Reply = super::Bet2ChessEvents;
         }
-        pub struct SendInvitation(());
-        impl SendInvitation {
+        pub struct WithdrawTokenSignlessNoWallet(());
+        impl WithdrawTokenSignlessNoWallet {
             #[allow(dead_code)]
-            pub fn encode_call(web2_user_id: u64, web2_guest_id: u64) -> Vec<u8> {
-                <SendInvitation as ActionIo>::encode_call(&(web2_user_id, web2_guest_id))
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                token: ActorId,
+                amount: u128,
+            ) -> Vec<u8> {
+                <WithdrawTokenSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    token,
+                    amount,
+                ))
             }
         }
-        impl ActionIo for SendInvitation {
+        impl ActionIo for WithdrawTokenSignlessNoWallet {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 83, 101, 110, 100, 73, 110, 118,
-                105, 116, 97, 116, 105, 111, 110,
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 116, 87, 105, 116, 104,
+                100, 114, 97, 119, 84, 111, 107, 101, 110, 83, 105, 103, 110, 108, 101, 115, 115,
+                78, 111, 87, 97, 108, 108, 101, 116,
             ];
-            type Params = (u64, u64);
+            type Params = (String, ActorId, u128);
             type Reply = super::Bet2ChessEvents;
         }
-        pub struct SendInvitationSignless(());
-        impl SendInvitationSignless {
+        pub struct WithdrawTreasuryToken(());
+        impl WithdrawTreasuryToken {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, to: ActorId, amount: u128) -> Vec<u8> {
+                <WithdrawTreasuryToken as ActionIo>::encode_call(&(token, to, amount))
+            }
+        }
+        impl ActionIo for WithdrawTreasuryToken {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 84, 87, 105, 116, 104, 100,
+                114, 97, 119, 84, 114, 101, 97, 115, 117, 114, 121, 84, 111, 107, 101, 110,
+            ];
+            type Params = (ActorId, ActorId, u128);
+            type Reply = super::Bet2ChessEvents;
+        }
+        pub struct TokenBalance(());
+        impl TokenBalance {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, address: ActorId) -> Vec<u8> {
+                <TokenBalance as ActionIo>::encode_call(&(token, address))
+            }
+        }
+        impl ActionIo for TokenBalance {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 48, 84, 111, 107, 101, 110,
+                66, 97, 108, 97, 110, 99, 101,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = u128;
+        }
+        pub struct TreasuryTokenBalance(());
+        impl TreasuryTokenBalance {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId) -> Vec<u8> {
+                <TreasuryTokenBalance as ActionIo>::encode_call(&token)
+            }
+        }
+        impl ActionIo for TreasuryTokenBalance {
+            const ROUTE: &'static [u8] = &[
+                44, 84, 111, 107, 101, 110, 83, 116, 97, 107, 101, 115, 80, 84, 114, 101, 97, 115,
+                117, 114, 121, 84, 111, 107, 101, 110, 66, 97, 108, 97, 110, 99, 101,
+            ];
+            type Params = ActorId;
+            type Reply = u128;
+        }
+    }
+}
+pub struct Tournament<R> {
+    remoting: R,
+}
+impl<R> Tournament<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Tournament for Tournament<R> {
+    type Args = R::Args;
+    fn cancel_tournament(
+        &mut self,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::CancelTournament>::new(
+            self.remoting.clone(),
+            tournament_id,
+        )
+    }
+    fn close_arena(
+        &mut self,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::CloseArena>::new(self.remoting.clone(), tournament_id)
+    }
+    fn create_pending_tournament_games(
+        &mut self,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::CreatePendingTournamentGames>::new(
+            self.remoting.clone(),
+            tournament_id,
+        )
+    }
+    fn create_tournament(
+        &mut self,
+        config: TournamentConfig,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::CreateTournament>::new(self.remoting.clone(), config)
+    }
+    fn forfeit_tournament_game(
+        &mut self,
+        game_id: u64,
+        absent_player: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::ForfeitTournamentGame>::new(
+            self.remoting.clone(),
+            (game_id, absent_player),
+        )
+    }
+    fn leave_tournament(
+        &mut self,
+        web2_user_id: u64,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::LeaveTournament>::new(
+            self.remoting.clone(),
+            (web2_user_id, tournament_id),
+        )
+    }
+    fn leave_tournament_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::LeaveTournamentSignless>::new(
+            self.remoting.clone(),
+            (user_address, web2_user_id, tournament_id),
+        )
+    }
+    fn leave_tournament_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::LeaveTournamentSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, web2_user_id, tournament_id),
+        )
+    }
+    fn register_in_tournament(
+        &mut self,
+        web2_user_id: u64,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::RegisterInTournament>::new(
+            self.remoting.clone(),
+            (web2_user_id, tournament_id),
+        )
+    }
+    fn register_in_tournament_signless(
+        &mut self,
+        user_address: ActorId,
+        web2_user_id: u64,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::RegisterInTournamentSignless>::new(
+            self.remoting.clone(),
+            (user_address, web2_user_id, tournament_id),
+        )
+    }
+    fn register_in_tournament_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::RegisterInTournamentSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (no_wallet_name_encoded, web2_user_id, tournament_id),
+        )
+    }
+    fn start_tournament(
+        &mut self,
+        tournament_id: u64,
+    ) -> impl Call<Output = TournamentEvents, Args = R::Args> {
+        RemotingAction::<_, tournament::io::StartTournament>::new(
+            self.remoting.clone(),
+            tournament_id,
+        )
+    }
+    fn tournament(
+        &self,
+        tournament_id: u64,
+    ) -> impl Query<Output = Option<Tournament>, Args = R::Args> {
+        RemotingAction::<_, tournament::io::Tournament>::new(self.remoting.clone(), tournament_id)
+    }
+    fn tournament_round(
+        &self,
+        tournament_id: u64,
+        round: u32,
+    ) -> impl Query<Output = Vec<TournamentPairing>, Args = R::Args> {
+        RemotingAction::<_, tournament::io::TournamentRound>::new(
+            self.remoting.clone(),
+            (tournament_id, round),
+        )
+    }
+    fn tournament_standings(
+        &self,
+        tournament_id: u64,
+    ) -> impl Query<Output = Vec<(u64, u32)>, Args = R::Args> {
+        RemotingAction::<_, tournament::io::TournamentStandings>::new(
+            self.remoting.clone(),
+            tournament_id,
+        )
+    }
+}
+
+pub mod tournament {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct CancelTournament(());
+        impl CancelTournament {
+            #[allow(dead_code)]
+            pub fn encode_call(tournament_id: u64) -> Vec<u8> {
+                <CancelTournament as ActionIo>::encode_call(&tournament_id)
+            }
+        }
+        impl ActionIo for CancelTournament {
+            const ROUTE: &'static [u8] = &[
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 64, 67, 97, 110, 99, 101, 108,
+                84, 111, 117, 114, 110, 97, 109, 101, 110, 116,
+            ];
+            type Params = u64;
+            type Reply = super::TournamentEvents;
+        }
+        pub struct CloseArena(());
+        impl CloseArena {
+            #[allow(dead_code)]
+            pub fn encode_call(tournament_id: u64) -> Vec<u8> {
+                <CloseArena as ActionIo>::encode_call(&tournament_id)
+            }
+        }
+        impl ActionIo for CloseArena {
+            const ROUTE: &'static [u8] = &[
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 40, 67, 108, 111, 115, 101, 65,
+                114, 101, 110, 97,
+            ];
+            type Params = u64;
+            type Reply = super::TournamentEvents;
+        }
+        pub struct CreatePendingTournamentGames(());
+        impl CreatePendingTournamentGames {
+            #[allow(dead_code)]
+            pub fn encode_call(tournament_id: u64) -> Vec<u8> {
+                <CreatePendingTournamentGames as ActionIo>::encode_call(&tournament_id)
+            }
+        }
+        impl ActionIo for CreatePendingTournamentGames {
+            const ROUTE: &'static [u8] = &[
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 112, 67, 114, 101, 97, 116,
+                101, 80, 101, 110, 100, 105, 110, 103, 84, 111, 117, 114, 110, 97, 109, 101, 110,
+                116, 71, 97, 109, 101, 115,
+            ];
+            type Params = u64;
+            type Reply = super::TournamentEvents;
+        }
+        pub struct CreateTournament(());
+        impl CreateTournament {
+            #[allow(dead_code)]
+            pub fn encode_call(config: super::TournamentConfig) -> Vec<u8> {
+                <CreateTournament as ActionIo>::encode_call(&config)
+            }
+        }
+        impl ActionIo for CreateTournament {
+            const ROUTE: &'static [u8] = &[
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 64, 67, 114, 101, 97, 116, 101,
+                84, 111, 117, 114, 110, 97, 109, 101, 110, 116,
+            ];
+            type Params = super::TournamentConfig;
+            type Reply = super::TournamentEvents;
+        }
+        pub struct ForfeitTournamentGame(());
+        impl ForfeitTournamentGame {
+            #[allow(dead_code)]
+            pub fn encode_call(game_id: u64, absent_player: u64) -> Vec<u8> {
+                <ForfeitTournamentGame as ActionIo>::encode_call(&(game_id, absent_player))
+            }
+        }
+        impl ActionIo for ForfeitTournamentGame {
+            const ROUTE: &'static [u8] = &[
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 84, 70, 111, 114, 102, 101,
+                105, 116, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 71, 97, 109, 101,
+            ];
+            type Params = (u64, u64);
+            type Reply = super::TournamentEvents;
+        }
+        pub struct LeaveTournament(());
+        impl LeaveTournament {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_user_id: u64, tournament_id: u64) -> Vec<u8> {
+                <LeaveTournament as ActionIo>::encode_call(&(web2_user_id, tournament_id))
+            }
+        }
+        impl ActionIo for LeaveTournament {
+            const ROUTE: &'static [u8] = &[
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 60, 76, 101, 97, 118, 101, 84,
+                111, 117, 114, 110, 97, 109, 101, 110, 116,
+            ];
+            type Params = (u64, u64);
+            type Reply = super::TournamentEvents;
+        }
+        pub struct LeaveTournamentSignless(());
+        impl LeaveTournamentSignless {
             #[allow(dead_code)]
             pub fn encode_call(
                 user_address: ActorId,
                 web2_user_id: u64,
-                web2_guest_id: u64,
+                tournament_id: u64,
             ) -> Vec<u8> {
-                <SendInvitationSignless as ActionIo>::encode_call(&(
+                <LeaveTournamentSignless as ActionIo>::encode_call(&(
                     user_address,
                     web2_user_id,
-                    web2_guest_id,
+                    tournament_id,
                 ))
             }
         }
-        impl ActionIo for SendInvitationSignless {
+        impl ActionIo for LeaveTournamentSignless {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 88, 83, 101, 110, 100, 73, 110, 118,
-                105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 92, 76, 101, 97, 118, 101, 84,
+                111, 117, 114, 110, 97, 109, 101, 110, 116, 83, 105, 103, 110, 108, 101, 115, 115,
             ];
             type Params = (ActorId, u64, u64);
-            type Reply = super::Bet2ChessEvents;
+            type Reply = super::TournamentEvents;
         }
-        pub struct SendInvitationSignlessNoWallet(());
-        impl SendInvitationSignlessNoWallet {
+        pub struct LeaveTournamentSignlessNoWallet(());
+        impl LeaveTournamentSignlessNoWallet {
             #[allow(dead_code)]
             pub fn encode_call(
                 no_wallet_name_encoded: String,
                 web2_user_id: u64,
-                web2_guest_id: u64,
+                tournament_id: u64,
             ) -> Vec<u8> {
-                <SendInvitationSignlessNoWallet as ActionIo>::encode_call(&(
+                <LeaveTournamentSignlessNoWallet as ActionIo>::encode_call(&(
                     no_wallet_name_encoded,
                     web2_user_id,
-                    web2_guest_id,
+                    tournament_id,
                 ))
             }
         }
-        impl ActionIo for SendInvitationSignlessNoWallet {
+        impl ActionIo for LeaveTournamentSignlessNoWallet {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 120, 83, 101, 110, 100, 73, 110, 118,
-                105, 116, 97, 116, 105, 111, 110, 83, 105, 103, 110, 108, 101, 115, 115, 78, 111,
-                87, 97, 108, 108, 101, 116,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 124, 76, 101, 97, 118, 101, 84,
+                111, 117, 114, 110, 97, 109, 101, 110, 116, 83, 105, 103, 110, 108, 101, 115, 115,
+                78, 111, 87, 97, 108, 108, 101, 116,
             ];
             type Params = (String, u64, u64);
-            type Reply = super::Bet2ChessEvents;
+            type Reply = super::TournamentEvents;
         }
-        pub struct AllGames(());
-        impl AllGames {
+        pub struct RegisterInTournament(());
+        impl RegisterInTournament {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <AllGames as ActionIo>::encode_call(&())
+            pub fn encode_call(web2_user_id: u64, tournament_id: u64) -> Vec<u8> {
+                <RegisterInTournament as ActionIo>::encode_call(&(web2_user_id, tournament_id))
             }
         }
-        impl ActionIo for AllGames {
+        impl ActionIo for RegisterInTournament {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 65, 108, 108, 71, 97, 109, 101,
-                115,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 80, 82, 101, 103, 105, 115,
+                116, 101, 114, 73, 110, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116,
             ];
-            type Params = ();
-            type Reply = Vec<(u64, super::GameData)>;
+            type Params = (u64, u64);
+            type Reply = super::TournamentEvents;
         }
-        pub struct GameData(());
-        impl GameData {
+        pub struct RegisterInTournamentSignless(());
+        impl RegisterInTournamentSignless {
             #[allow(dead_code)]
-            pub fn encode_call(game_id: u64) -> Vec<u8> {
-                <GameData as ActionIo>::encode_call(&game_id)
+            pub fn encode_call(
+                user_address: ActorId,
+                web2_user_id: u64,
+                tournament_id: u64,
+            ) -> Vec<u8> {
+                <RegisterInTournamentSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    tournament_id,
+                ))
             }
         }
-        impl ActionIo for GameData {
+        impl ActionIo for RegisterInTournamentSignless {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 32, 71, 97, 109, 101, 68, 97, 116, 97,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 112, 82, 101, 103, 105, 115,
+                116, 101, 114, 73, 110, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 83, 105,
+                103, 110, 108, 101, 115, 115,
             ];
-            type Params = u64;
-            type Reply = Option<super::GameData>;
+            type Params = (ActorId, u64, u64);
+            type Reply = super::TournamentEvents;
         }
-        pub struct GamesIdEnded(());
-        impl GamesIdEnded {
+        pub struct RegisterInTournamentSignlessNoWallet(());
+        impl RegisterInTournamentSignlessNoWallet {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <GamesIdEnded as ActionIo>::encode_call(&())
+            pub fn encode_call(
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                tournament_id: u64,
+            ) -> Vec<u8> {
+                <RegisterInTournamentSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    tournament_id,
+                ))
             }
         }
-        impl ActionIo for GamesIdEnded {
+        impl ActionIo for RegisterInTournamentSignlessNoWallet {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 48, 71, 97, 109, 101, 115, 73, 100,
-                69, 110, 100, 101, 100,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 144, 82, 101, 103, 105, 115,
+                116, 101, 114, 73, 110, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 83, 105,
+                103, 110, 108, 101, 115, 115, 78, 111, 87, 97, 108, 108, 101, 116,
             ];
-            type Params = ();
-            type Reply = Vec<u64>;
+            type Params = (String, u64, u64);
+            type Reply = super::TournamentEvents;
         }
-        pub struct GamesIdStarted(());
-        impl GamesIdStarted {
+        pub struct StartTournament(());
+        impl StartTournament {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <GamesIdStarted as ActionIo>::encode_call(&())
+            pub fn encode_call(tournament_id: u64) -> Vec<u8> {
+                <StartTournament as ActionIo>::encode_call(&tournament_id)
             }
         }
-        impl ActionIo for GamesIdStarted {
+        impl ActionIo for StartTournament {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 71, 97, 109, 101, 115, 73, 100,
-                83, 116, 97, 114, 116, 101, 100,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 60, 83, 116, 97, 114, 116, 84,
+                111, 117, 114, 110, 97, 109, 101, 110, 116,
             ];
-            type Params = ();
-            type Reply = Vec<u64>;
+            type Params = u64;
+            type Reply = super::TournamentEvents;
         }
-        pub struct GamesIdWaiting(());
-        impl GamesIdWaiting {
+        pub struct Tournament(());
+        impl Tournament {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <GamesIdWaiting as ActionIo>::encode_call(&())
+            pub fn encode_call(tournament_id: u64) -> Vec<u8> {
+                <Tournament as ActionIo>::encode_call(&tournament_id)
             }
         }
-        impl ActionIo for GamesIdWaiting {
+        impl ActionIo for Tournament {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 56, 71, 97, 109, 101, 115, 73, 100,
-                87, 97, 105, 116, 105, 110, 103,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 40, 84, 111, 117, 114, 110, 97,
+                109, 101, 110, 116,
             ];
-            type Params = ();
-            type Reply = Vec<u64>;
+            type Params = u64;
+            type Reply = Option<super::Tournament>;
         }
-        pub struct InvitationBet(());
-        impl InvitationBet {
+        pub struct TournamentRound(());
+        impl TournamentRound {
             #[allow(dead_code)]
-            pub fn encode_call(first_web2_id: u64, second_web2_id: u64) -> Vec<u8> {
-                <InvitationBet as ActionIo>::encode_call(&(first_web2_id, second_web2_id))
+            pub fn encode_call(tournament_id: u64, round: u32) -> Vec<u8> {
+                <TournamentRound as ActionIo>::encode_call(&(tournament_id, round))
             }
         }
-        impl ActionIo for InvitationBet {
+        impl ActionIo for TournamentRound {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 52, 73, 110, 118, 105, 116, 97, 116,
-                105, 111, 110, 66, 101, 116,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 60, 84, 111, 117, 114, 110, 97,
+                109, 101, 110, 116, 82, 111, 117, 110, 100,
             ];
-            type Params = (u64, u64);
-            type Reply = Option<u128>;
+            type Params = (u64, u32);
+            type Reply = Vec<super::TournamentPairing>;
         }
-        pub struct InvitationsFromWeb2Id(());
-        impl InvitationsFromWeb2Id {
+        pub struct TournamentStandings(());
+        impl TournamentStandings {
             #[allow(dead_code)]
-            pub fn encode_call(web2_id: u64) -> Vec<u8> {
-                <InvitationsFromWeb2Id as ActionIo>::encode_call(&web2_id)
+            pub fn encode_call(tournament_id: u64) -> Vec<u8> {
+                <TournamentStandings as ActionIo>::encode_call(&tournament_id)
             }
         }
-        impl ActionIo for InvitationsFromWeb2Id {
+        impl ActionIo for TournamentStandings {
             const ROUTE: &'static [u8] = &[
-                36, 66, 101, 116, 50, 67, 104, 101, 115, 115, 84, 73, 110, 118, 105, 116, 97, 116,
-                105, 111, 110, 115, 70, 114, 111, 109, 87, 101, 98, 50, 73, 100,
+                40, 84, 111, 117, 114, 110, 97, 109, 101, 110, 116, 76, 84, 111, 117, 114, 110, 97,
+                109, 101, 110, 116, 83, 116, 97, 110, 100, 105, 110, 103, 115,
             ];
             type Params = u64;
-            type Reply = Option<super::InvitationsState>;
+            type Reply = Vec<(u64, u32)>;
         }
     }
 }
-pub struct QueryService<R> {
+pub struct UserRegistry<R> {
     remoting: R,
 }
-impl<R> QueryService<R> {
+impl<R> UserRegistry<R> {
     pub fn new(remoting: R) -> Self {
         Self { remoting }
     }
 }
-impl<R: Remoting + Clone> traits::QueryService for QueryService<R> {
+impl<R: Remoting + Clone> traits::UserRegistry for UserRegistry<R> {
     type Args = R::Args;
-    fn signless_account_data(
-        &self,
-        signless_address: ActorId,
-    ) -> impl Query<Output = QueryEvent, Args = R::Args> {
-        RemotingAction::<_, query_service::io::SignlessAccountData>::new(
+    fn link_web_2_id(
+        &mut self,
+        web2_id: u64,
+        address: ActorId,
+        signature: [u8; 64],
+    ) -> impl Call<Output = UserRegistryEvents, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::LinkWeb2Id>::new(
             self.remoting.clone(),
-            signless_address,
+            (web2_id, address, signature),
         )
     }
-    fn signless_address_from_no_wallet_account(
-        &self,
-        no_wallet_account: String,
-    ) -> impl Query<Output = QueryEvent, Args = R::Args> {
-        RemotingAction::<_, query_service::io::SignlessAddressFromNoWalletAccount>::new(
+    fn set_attester(
+        &mut self,
+        attester: [u8; 32],
+    ) -> impl Call<Output = UserRegistryEvents, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::SetAttester>::new(self.remoting.clone(), attester)
+    }
+    fn set_profile(
+        &mut self,
+        web2_user_id: u64,
+        username: String,
+        avatar_hash: Option<[u8; 32]>,
+        country: Option<String>,
+    ) -> impl Call<Output = UserRegistryEvents, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::SetProfile>::new(
             self.remoting.clone(),
-            no_wallet_account,
+            (web2_user_id, username, avatar_hash, country),
         )
     }
-    fn signless_address_from_user_address(
-        &self,
+    fn set_profile_signless(
+        &mut self,
         user_address: ActorId,
-    ) -> impl Query<Output = QueryEvent, Args = R::Args> {
-        RemotingAction::<_, query_service::io::SignlessAddressFromUserAddress>::new(
+        web2_user_id: u64,
+        username: String,
+        avatar_hash: Option<[u8; 32]>,
+        country: Option<String>,
+    ) -> impl Call<Output = UserRegistryEvents, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::SetProfileSignless>::new(
             self.remoting.clone(),
-            user_address,
+            (user_address, web2_user_id, username, avatar_hash, country),
+        )
+    }
+    fn set_profile_signless_no_wallet(
+        &mut self,
+        no_wallet_name_encoded: String,
+        web2_user_id: u64,
+        username: String,
+        avatar_hash: Option<[u8; 32]>,
+        country: Option<String>,
+    ) -> impl Call<Output = UserRegistryEvents, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::SetProfileSignlessNoWallet>::new(
+            self.remoting.clone(),
+            (
+                no_wallet_name_encoded,
+                web2_user_id,
+                username,
+                avatar_hash,
+                country,
+            ),
+        )
+    }
+    fn unlink_web_2_id(
+        &mut self,
+        web2_id: u64,
+    ) -> impl Call<Output = UserRegistryEvents, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::UnlinkWeb2Id>::new(self.remoting.clone(), web2_id)
+    }
+    fn address_by_web_2_id(
+        &self,
+        web2_id: u64,
+    ) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::AddressByWeb2Id>::new(self.remoting.clone(), web2_id)
+    }
+    fn attester(&self) -> impl Query<Output = Option<[u8; 32]>, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::Attester>::new(self.remoting.clone(), ())
+    }
+    fn link_nonce(&self, web2_id: u64) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::LinkNonce>::new(self.remoting.clone(), web2_id)
+    }
+    fn profile(&self, web2_id: u64) -> impl Query<Output = Option<PlayerProfile>, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::Profile>::new(self.remoting.clone(), web2_id)
+    }
+    fn web_2_id_by_address(
+        &self,
+        address: ActorId,
+    ) -> impl Query<Output = Option<u64>, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::Web2IdByAddress>::new(self.remoting.clone(), address)
+    }
+    fn web_2_id_by_username(
+        &self,
+        username: String,
+    ) -> impl Query<Output = Option<u64>, Args = R::Args> {
+        RemotingAction::<_, user_registry::io::Web2IdByUsername>::new(
+            self.remoting.clone(),
+            username,
         )
     }
 }
 
-pub mod query_service {
+pub mod user_registry {
     use super::*;
 
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
-        pub struct SignlessAccountData(());
-        impl SignlessAccountData {
+        pub struct LinkWeb2Id(());
+        impl LinkWeb2Id {
             #[allow(dead_code)]
-            pub fn encode_call(signless_address: ActorId) -> Vec<u8> {
-                <SignlessAccountData as ActionIo>::encode_call(&signless_address)
+            pub fn encode_call(web2_id: u64, address: ActorId, signature: [u8; 64]) -> Vec<u8> {
+                <LinkWeb2Id as ActionIo>::encode_call(&(web2_id, address, signature))
             }
         }
-        impl ActionIo for SignlessAccountData {
+        impl ActionIo for LinkWeb2Id {
             const ROUTE: &'static [u8] = &[
-                48, 81, 117, 101, 114, 121, 83, 101, 114, 118, 105, 99, 101, 76, 83, 105, 103, 110,
-                108, 101, 115, 115, 65, 99, 99, 111, 117, 110, 116, 68, 97, 116, 97,
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 40, 76, 105, 110,
+                107, 87, 101, 98, 50, 73, 100,
             ];
-            type Params = ActorId;
-            type Reply = super::QueryEvent;
+            type Params = (u64, ActorId, [u8; 64]);
+            type Reply = super::UserRegistryEvents;
         }
-        pub struct SignlessAddressFromNoWalletAccount(());
-        impl SignlessAddressFromNoWalletAccount {
+        pub struct SetAttester(());
+        impl SetAttester {
             #[allow(dead_code)]
-            pub fn encode_call(no_wallet_account: String) -> Vec<u8> {
-                <SignlessAddressFromNoWalletAccount as ActionIo>::encode_call(&no_wallet_account)
+            pub fn encode_call(attester: [u8; 32]) -> Vec<u8> {
+                <SetAttester as ActionIo>::encode_call(&attester)
             }
         }
-        impl ActionIo for SignlessAddressFromNoWalletAccount {
+        impl ActionIo for SetAttester {
             const ROUTE: &'static [u8] = &[
-                48, 81, 117, 101, 114, 121, 83, 101, 114, 118, 105, 99, 101, 136, 83, 105, 103,
-                110, 108, 101, 115, 115, 65, 100, 100, 114, 101, 115, 115, 70, 114, 111, 109, 78,
-                111, 87, 97, 108, 108, 101, 116, 65, 99, 99, 111, 117, 110, 116,
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 44, 83, 101, 116, 65,
+                116, 116, 101, 115, 116, 101, 114,
             ];
-            type Params = String;
-            type Reply = super::QueryEvent;
+            type Params = [u8; 32];
+            type Reply = super::UserRegistryEvents;
         }
-        pub struct SignlessAddressFromUserAddress(());
-        impl SignlessAddressFromUserAddress {
+        pub struct SetProfile(());
+        impl SetProfile {
             #[allow(dead_code)]
-            pub fn encode_call(user_address: ActorId) -> Vec<u8> {
-                <SignlessAddressFromUserAddress as ActionIo>::encode_call(&user_address)
+            pub fn encode_call(
+                web2_user_id: u64,
+                username: String,
+                avatar_hash: Option<[u8; 32]>,
+                country: Option<String>,
+            ) -> Vec<u8> {
+                <SetProfile as ActionIo>::encode_call(&(
+                    web2_user_id,
+                    username,
+                    avatar_hash,
+                    country,
+                ))
             }
         }
-        impl ActionIo for SignlessAddressFromUserAddress {
+        impl ActionIo for SetProfile {
             const ROUTE: &'static [u8] = &[
-                48, 81, 117, 101, 114, 121, 83, 101, 114, 118, 105, 99, 101, 120, 83, 105, 103,
-                110, 108, 101, 115, 115, 65, 100, 100, 114, 101, 115, 115, 70, 114, 111, 109, 85,
-                115, 101, 114, 65, 100, 100, 114, 101, 115, 115,
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 40, 83, 101, 116, 80,
+                114, 111, 102, 105, 108, 101,
             ];
-            type Params = ActorId;
-            type Reply = super::QueryEvent;
+            type Params = (u64, String, Option<[u8; 32]>, Option<String>);
+            type Reply = super::UserRegistryEvents;
         }
-    }
-}
-pub struct Signless<R> {
-    remoting: R,
-}
-impl<R> Signless<R> {
-    pub fn new(remoting: R) -> Self {
-        Self { remoting }
-    }
-}
-impl<R: Remoting + Clone> traits::Signless for Signless<R> {
-    type Args = R::Args;
-    fn bind_signless_data_to_address(
-        &mut self,
-        user_address: ActorId,
-        signless_data: SignlessAccount,
-    ) -> impl Call<Output = SignlessEvent, Args = R::Args> {
-        RemotingAction::<_, signless::io::BindSignlessDataToAddress>::new(
-            self.remoting.clone(),
-            (user_address, signless_data),
-        )
-    }
-    fn bind_signless_data_to_no_wallet_account(
-        &mut self,
-        no_wallet_account: String,
-        signless_data: SignlessAccount,
-    ) -> impl Call<Output = SignlessEvent, Args = R::Args> {
-        RemotingAction::<_, signless::io::BindSignlessDataToNoWalletAccount>::new(
-            self.remoting.clone(),
-            (no_wallet_account, signless_data),
-        )
-    }
-}
-
-pub mod signless {
-    use super::*;
-
-    pub mod io {
-        use super::*;
-        use sails_rs::calls::ActionIo;
-        pub struct BindSignlessDataToAddress(());
-        impl BindSignlessDataToAddress {
+        pub struct SetProfileSignless(());
+        impl SetProfileSignless {
             #[allow(dead_code)]
             pub fn encode_call(
                 user_address: ActorId,
-                signless_data: super::SignlessAccount,
+                web2_user_id: u64,
+                username: String,
+                avatar_hash: Option<[u8; 32]>,
+                country: Option<String>,
             ) -> Vec<u8> {
-                <BindSignlessDataToAddress as ActionIo>::encode_call(&(user_address, signless_data))
+                <SetProfileSignless as ActionIo>::encode_call(&(
+                    user_address,
+                    web2_user_id,
+                    username,
+                    avatar_hash,
+                    country,
+                ))
             }
         }
-        impl ActionIo for BindSignlessDataToAddress {
+        impl ActionIo for SetProfileSignless {
             const ROUTE: &'static [u8] = &[
-                32, 83, 105, 103, 110, 108, 101, 115, 115, 100, 66, 105, 110, 100, 83, 105, 103,
-                110, 108, 101, 115, 115, 68, 97, 116, 97, 84, 111, 65, 100, 100, 114, 101, 115,
-                115,
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 72, 83, 101, 116, 80,
+                114, 111, 102, 105, 108, 101, 83, 105, 103, 110, 108, 101, 115, 115,
             ];
-            type Params = (ActorId, super::SignlessAccount);
-            type Reply = super::SignlessEvent;
+            type Params = (ActorId, u64, String, Option<[u8; 32]>, Option<String>);
+            type Reply = super::UserRegistryEvents;
         }
-        pub struct BindSignlessDataToNoWalletAccount(());
-        impl BindSignlessDataToNoWalletAccount {
+        pub struct SetProfileSignlessNoWallet(());
+        impl SetProfileSignlessNoWallet {
             #[allow(dead_code)]
             pub fn encode_call(
-                no_wallet_account: String,
-                signless_data: super::SignlessAccount,
+                no_wallet_name_encoded: String,
+                web2_user_id: u64,
+                username: String,
+                avatar_hash: Option<[u8; 32]>,
+                country: Option<String>,
             ) -> Vec<u8> {
-                <BindSignlessDataToNoWalletAccount as ActionIo>::encode_call(&(
-                    no_wallet_account,
-                    signless_data,
+                <SetProfileSignlessNoWallet as ActionIo>::encode_call(&(
+                    no_wallet_name_encoded,
+                    web2_user_id,
+                    username,
+                    avatar_hash,
+                    country,
                 ))
             }
         }
-        impl ActionIo for BindSignlessDataToNoWalletAccount {
+        impl ActionIo for SetProfileSignlessNoWallet {
+            const ROUTE: &'static [u8] = &[
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 104, 83, 101, 116,
+                80, 114, 111, 102, 105, 108, 101, 83, 105, 103, 110, 108, 101, 115, 115, 78, 111,
+                87, 97, 108, 108, 101, 116,
+            ];
+            type Params = (String, u64, String, Option<[u8; 32]>, Option<String>);
+            type Reply = super::UserRegistryEvents;
+        }
+        pub struct UnlinkWeb2Id(());
+        impl UnlinkWeb2Id {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <UnlinkWeb2Id as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for UnlinkWeb2Id {
+            const ROUTE: &'static [u8] = &[
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 48, 85, 110, 108,
+                105, 110, 107, 87, 101, 98, 50, 73, 100,
+            ];
+            type Params = u64;
+            type Reply = super::UserRegistryEvents;
+        }
+        pub struct AddressByWeb2Id(());
+        impl AddressByWeb2Id {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <AddressByWeb2Id as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for AddressByWeb2Id {
+            const ROUTE: &'static [u8] = &[
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 60, 65, 100, 100,
+                114, 101, 115, 115, 66, 121, 87, 101, 98, 50, 73, 100,
+            ];
+            type Params = u64;
+            type Reply = Option<ActorId>;
+        }
+        pub struct Attester(());
+        impl Attester {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Attester as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Attester {
+            const ROUTE: &'static [u8] = &[
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 32, 65, 116, 116,
+                101, 115, 116, 101, 114,
+            ];
+            type Params = ();
+            type Reply = Option<[u8; 32]>;
+        }
+        pub struct LinkNonce(());
+        impl LinkNonce {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <LinkNonce as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for LinkNonce {
+            const ROUTE: &'static [u8] = &[
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 36, 76, 105, 110,
+                107, 78, 111, 110, 99, 101,
+            ];
+            type Params = u64;
+            type Reply = u64;
+        }
+        pub struct Profile(());
+        impl Profile {
+            #[allow(dead_code)]
+            pub fn encode_call(web2_id: u64) -> Vec<u8> {
+                <Profile as ActionIo>::encode_call(&web2_id)
+            }
+        }
+        impl ActionIo for Profile {
+            const ROUTE: &'static [u8] = &[
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 28, 80, 114, 111,
+                102, 105, 108, 101,
+            ];
+            type Params = u64;
+            type Reply = Option<super::PlayerProfile>;
+        }
+        pub struct Web2IdByAddress(());
+        impl Web2IdByAddress {
+            #[allow(dead_code)]
+            pub fn encode_call(address: ActorId) -> Vec<u8> {
+                <Web2IdByAddress as ActionIo>::encode_call(&address)
+            }
+        }
+        impl ActionIo for Web2IdByAddress {
+            const ROUTE: &'static [u8] = &[
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 60, 87, 101, 98, 50,
+                73, 100, 66, 121, 65, 100, 100, 114, 101, 115, 115,
+            ];
+            type Params = ActorId;
+            type Reply = Option<u64>;
+        }
+        pub struct Web2IdByUsername(());
+        impl Web2IdByUsername {
+            #[allow(dead_code)]
+            pub fn encode_call(username: String) -> Vec<u8> {
+                <Web2IdByUsername as ActionIo>::encode_call(&username)
+            }
+        }
+        impl ActionIo for Web2IdByUsername {
             const ROUTE: &'static [u8] = &[
-                32, 83, 105, 103, 110, 108, 101, 115, 115, 132, 66, 105, 110, 100, 83, 105, 103,
-                110, 108, 101, 115, 115, 68, 97, 116, 97, 84, 111, 78, 111, 87, 97, 108, 108, 101,
-                116, 65, 99, 99, 111, 117, 110, 116,
+                48, 85, 115, 101, 114, 82, 101, 103, 105, 115, 116, 114, 121, 64, 87, 101, 98, 50,
+                73, 100, 66, 121, 85, 115, 101, 114, 110, 97, 109, 101,
             ];
-            type Params = (String, super::SignlessAccount);
-            type Reply = super::SignlessEvent;
+            type Params = String;
+            type Reply = Option<u64>;
         }
     }
 }
@@ -771,9 +4126,53 @@ pub enum Bet2ChessEvents {
     GameCreated(u64),
     JoinedInGame(u64),
     GameEnded(u64),
+    GameDrawn(u64),
+    GameCancelled(u64),
+    GameAborted(u64),
+    JoinedMatchmakingQueue,
+    LeftMatchmakingQueue,
     InvitationSentTo(u64),
     InvitationCancelled,
+    InvitationDeclined,
+    InvitationExpired,
+    InvitationsExpired(u32),
+    InvitationTtlSet(u32),
     Price,
+    AdminAdded(ActorId),
+    AdminRemoved(ActorId),
+    ResultOracleAdded(ActorId),
+    ResultOracleRemoved(ActorId),
+    FeeConfigSet(FeeConfig),
+    StakeLimitsSet(StakeLimits),
+    MarketConfigSet(MarketConfig),
+    SpectatorBetPlaced {
+        game_id: u64,
+        outcome: MarketOutcome,
+        amount: u128,
+    },
+    SpectatorWinningsClaimed {
+        game_id: u64,
+        amount: u128,
+    },
+    Withdrawn(u128),
+    TokenWithdrawn {
+        token: ActorId,
+        amount: u128,
+    },
+    TreasuryTokenWithdrawn {
+        token: ActorId,
+        to: ActorId,
+        amount: u128,
+    },
+    TreasuryWithdrawn {
+        to: ActorId,
+        amount: u128,
+    },
+    MovePlayed {
+        game_id: u64,
+        chess_move: ChessMove,
+        board_status: BoardStatus,
+    },
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -804,6 +4203,139 @@ pub enum Bet2ChessErrors {
     ThereAreNoGamesWaiting,
     MinAmoutToBetIsOneToken,
     OnlyAdminsCanEndGames,
+    GameIsNotStarted(u64),
+    UserIsNotAPlayerOfTheGame(u64),
+    NotPlayerTurn(u64),
+    IllegalMove(ChessMove),
+    OnlyAdminsCanDoThisAction,
+    AdminAlreadyExists(ActorId),
+    AddressIsNotAnAdmin(ActorId),
+    CantRemoveTheLastAdmin,
+    ResultOracleAlreadyExists(ActorId),
+    AddressIsNotAResultOracle(ActorId),
+    InvalidWinner(ActorId),
+    GameAlreadyEnded(u64),
+    Web2GameIdAlreadyExists(u64),
+    GameWasCancelled(u64),
+    UserCantJoinTheirOwnGame(u64),
+    OnlyTheGameCreatorCanCancelIt(u64),
+    PlayerAlreadyInMatchmakingQueue,
+    PlayerIsNotInMatchmakingQueue,
+    InvalidInvitationTtl,
+    InvitationHasNotExpired,
+    InvitationExpired,
+    CantScheduleDelayedMessage,
+    OnlyInvitationParticipantsCanCancelIt,
+    OnlyTheGuestCanDeclineTheInvitation,
+    PlayerHasNoProfile(u64),
+    InvalidFeeConfig,
+    InsufficientTreasuryBalance(u128),
+    InsufficientBalance(u128),
+    CantSendWithdrawal,
+    StakeBelowMinimum(u128),
+    StakeAboveMaximum(u128),
+    InvalidStakeLimits,
+    StakeCurrencyMismatch,
+    TokenStakeCantBeZero,
+    TokenTransferFailed,
+    TokenStakesDontTakeValue,
+    MarketDoesNotExists(u64),
+    MarketIsClosed(u64),
+    MarketIsNotSettled(u64),
+    PlayersCantBetOnTheirGame(u64),
+    SpectatorBetCantBeZero,
+    NothingToClaim,
+    InvalidMarketConfig,
+    InvalidTournamentConfig,
+    TournamentDoesNotExists(u64),
+    TournamentRegistrationIsClosed(u64),
+    TournamentIsFull(u64),
+    PlayerAlreadyInTournament(u64),
+    PlayerIsNotInTournament(u64),
+    EntryFeeIsNotTheSame { entry_fee: u128, sent: u128 },
+    NotEnoughPlayersInTournament(u64),
+    CantIncrementTournamentsIdItOverflow,
+    TournamentGamesCantBeAborted(u64),
+    TournamentIsNotAnArena(u64),
+    ArenaHasNotEnded(u64),
+    TournamentIsNotRunning(u64),
+    NotATournamentGame(u64),
+    InvalidRatingRange,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ChessMove {
+    pub from: u8,
+    pub to: u8,
+    pub promotion: Option<PieceKind>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PieceKind {
+    Pawn,
+    Knight,
+    Bishop,
+    Rook,
+    Queen,
+    King,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FeeConfig {
+    pub fee_basis_points: u16,
+    pub min_fee: u128,
+    pub max_fee: u128,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct StakeLimits {
+    pub min_stake: u128,
+    pub max_stake: u128,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MarketConfig {
+    pub cutoff_moves: u32,
+    pub cutoff_blocks: u32,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum MarketOutcome {
+    Player1,
+    Player2,
+    Draw,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum BoardStatus {
+    InProgress,
+    Check,
+    Checkmate { winner: PieceColor },
+    Stalemate,
+    ThreefoldRepetition,
+    FiftyMoveRule,
+    InsufficientMaterial,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PieceColor {
+    White,
+    Black,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RatingRange {
+    pub min: u32,
+    pub max: u32,
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -818,6 +4350,11 @@ pub struct GameData {
     pub player2_web2_id: u64,
     pub winner: Option<ActorId>,
     pub status: GameStatus,
+    pub time_control: Option<u32>,
+    pub created_at_block: u32,
+    pub token: Option<ActorId>,
+    pub player2_bet: u128,
+    pub final_position_key: Option<u64>,
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -826,6 +4363,63 @@ pub enum GameStatus {
     Waiting,
     Started,
     Ended { winner: Option<ActorId> },
+    Cancelled,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ChessBoard {
+    pub squares: [Option<Piece>; 64],
+    pub turn: PieceColor,
+    pub castling: CastlingRights,
+    pub en_passant: Option<u8>,
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+    pub position_keys: Vec<u64>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Piece {
+    pub kind: PieceKind,
+    pub color: PieceColor,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CastlingRights {
+    pub white_king_side: bool,
+    pub white_queen_side: bool,
+    pub black_king_side: bool,
+    pub black_queen_side: bool,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GamesFilter {
+    pub status: Option<GameStatusKind>,
+    pub player: Option<ActorId>,
+    pub player_web2_id: Option<u64>,
+    pub min_bet: Option<u128>,
+    pub max_bet: Option<u128>,
+    pub created_from_block: Option<u32>,
+    pub created_to_block: Option<u32>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum GameStatusKind {
+    Waiting,
+    Started,
+    Ended,
+    Cancelled,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GamesPage {
+    pub games: Vec<(u64, GameData)>,
+    pub next_cursor: Option<u64>,
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -837,6 +4431,100 @@ pub struct InvitationsState {
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
+pub struct InvitationLifetime {
+    pub inviter_web2_id: u64,
+    pub guest_web2_id: u64,
+    pub bet_amount: u128,
+    pub guest_bet_amount: u128,
+    pub created_at_block: u32,
+    pub expires_at_block: u32,
+    pub remaining_blocks: u32,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum LeaderboardKind {
+    Rating,
+    NetWinnings,
+    GamesWon,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct LeaderboardEntry {
+    pub position: u32,
+    pub web2_id: u64,
+    pub score: i128,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MatchmakingEntry {
+    pub address: ActorId,
+    pub username: String,
+    pub web2_id: u64,
+    pub rating: u32,
+    pub rating_range: Option<RatingRange>,
+    pub joined_at_block: u32,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PlayerRating {
+    pub rating: u32,
+    pub rated_games: u32,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PlayerStats {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub total_wagered: u128,
+    pub total_won: u128,
+    pub current_streak: i32,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct RatingChange {
+    pub game_id: u64,
+    pub opponent_web2_id: u64,
+    pub result: ResultEnd,
+    pub rating_before: u32,
+    pub rating_after: u32,
+    pub block: u32,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ResultEnd {
+    Win,
+    Lose,
+    Draw,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SpectatorMarketInfo {
+    pub status: MarketStatus,
+    pub closes_after_move: u32,
+    pub closes_at_block: u32,
+    pub pools: Vec<(MarketOutcome, u128)>,
+    pub prize_pool: u128,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum MarketStatus {
+    Open,
+    Settled { outcome: MarketOutcome },
+    Refunded,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
 pub enum QueryEvent {
     GameData(GameData),
     SignlessAccountAddress(Option<ActorId>),
@@ -856,6 +4544,125 @@ pub enum SignlessEvent {
     SignlessAccountSet,
     Error(SignlessError),
 }
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TournamentEvents {
+    TournamentCreated(u64),
+    TournamentStarted(u64),
+    TournamentCancelled(u64),
+    RegisteredInTournament(u64),
+    LeftTournament(u64),
+    ArenaClosed(u64),
+    PendingTournamentGamesCreated(u32),
+    TournamentGameForfeited(u64),
+    SignlessError(SignlessError),
+    Error(Bet2ChessErrors),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TournamentConfig {
+    pub name: String,
+    pub format: TournamentFormat,
+    pub entry_fee: u128,
+    pub max_players: u32,
+    pub prize_distribution: Vec<u16>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TournamentFormat {
+    Swiss { rounds: u32 },
+    Knockout,
+    Arena { duration_blocks: u32 },
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Tournament {
+    pub config: TournamentConfig,
+    pub status: TournamentStatus,
+    pub players: BTreeMap<u64, TournamentPlayer>,
+    pub seeds: Vec<u64>,
+    pub rounds: Vec<Vec<TournamentPairing>>,
+    pub prize_pool: u128,
+    pub standings: Vec<u64>,
+    pub ends_at_block: u32,
+    pub arena_queue: Vec<u64>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TournamentStatus {
+    Registration,
+    Running,
+    Finished,
+    Cancelled,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TournamentPlayer {
+    pub address: ActorId,
+    pub username: String,
+    pub score: u32,
+    pub opponents: Vec<u64>,
+    pub had_bye: bool,
+    pub eliminated_in_round: Option<u32>,
+    pub win_streak: u32,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TournamentPairing {
+    pub player1: u64,
+    pub player2: Option<u64>,
+    pub game_id: Option<u64>,
+    pub result: Option<PairingResult>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PairingResult {
+    Winner(u64),
+    Draw,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum UserRegistryEvents {
+    AttesterSet,
+    Web2IdLinked { web2_id: u64, address: ActorId },
+    Web2IdUnlinked(u64),
+    ProfileSet(u64),
+    SignlessError(SignlessError),
+    Error(UserRegistryErrors),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum UserRegistryErrors {
+    OnlyAdminsCanDoThisAction,
+    AttesterIsNotConfigured,
+    InvalidAttesterKey,
+    InvalidSignature,
+    AddressAlreadyLinked(u64),
+    Web2IdIsNotLinked(u64),
+    UserAddressAndWeb2IdAreNotRelated,
+    InvalidUsername,
+    InvalidCountryCode,
+    UsernameAlreadyTaken(String),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PlayerProfile {
+    pub username: String,
+    pub avatar_hash: Option<[u8; 32]>,
+    pub country: Option<String>,
+    pub registered_at_block: u32,
+}
 
 pub mod traits {
     use super::*;
@@ -870,93 +4677,428 @@ pub mod traits {
     #[allow(clippy::type_complexity)]
     pub trait Bet2Chess {
         type Args;
+        fn abort_game(
+            &mut self,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
         fn accept_invitation(
             &mut self,
             web2_user_id: u64,
-            web2_user_id_invitation_owner: u64,
-            web2_match_game_id: u64,
-            username_from_user_who_invite: String,
-            own_username: String,
+            web2_user_id_invitation_owner: u64,
+            web2_match_game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn accept_invitation_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            web2_user_id_invitation_owner: u64,
+            web2_match_game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn accept_invitation_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            web2_user_id_invitation_owner: u64,
+            web2_match_game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn add_admin(
+            &mut self,
+            new_admin: ActorId,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn add_result_oracle(
+            &mut self,
+            oracle: ActorId,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn cancel_invitation(
+            &mut self,
+            first_web2_id: u64,
+            second_web2_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn cancel_invitation_signless(
+            &mut self,
+            user_address: ActorId,
+            first_web2_id: u64,
+            second_web2_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn cancel_invitation_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            first_web2_id: u64,
+            second_web2_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn cancel_open_game(
+            &mut self,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn cancel_open_game_signless(
+            &mut self,
+            user_address: ActorId,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn cancel_open_game_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn claim_spectator_winnings(
+            &mut self,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn claim_spectator_winnings_signless(
+            &mut self,
+            user_address: ActorId,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn claim_spectator_winnings_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn create_open_game(
+            &mut self,
+            web2_user_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn create_open_game_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn create_open_game_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn decline_invitation(
+            &mut self,
+            first_web2_id: u64,
+            second_web2_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn decline_invitation_signless(
+            &mut self,
+            user_address: ActorId,
+            first_web2_id: u64,
+            second_web2_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn decline_invitation_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            first_web2_id: u64,
+            second_web2_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn end_game_by_id(
+            &mut self,
+            game_id: u64,
+            game_winner: Option<ActorId>,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn end_match(
+            &mut self,
+            game_id: u64,
+            game_winner: Option<ActorId>,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn expire_invitation(
+            &mut self,
+            first_web2_id: u64,
+            second_web2_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn join_any_waiting_game(
+            &mut self,
+            web2_user_id: u64,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
-        fn accept_invitation_signless(
+        fn join_any_waiting_game_signless(
             &mut self,
             user_address: ActorId,
             web2_user_id: u64,
-            web2_user_id_invitation_owner: u64,
-            web2_match_game_id: u64,
-            username_from_user_who_invite: String,
-            own_username: String,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
-        fn accept_invitation_signless_no_wallet(
+        fn join_any_waiting_game_signless_no_wallet(
             &mut self,
             no_wallet_name_encoded: String,
             web2_user_id: u64,
-            web2_user_id_invitation_owner: u64,
-            web2_match_game_id: u64,
-            username_from_user_who_invite: String,
-            own_username: String,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
-        fn cancel_invitation(
+        fn join_matchmaking_queue(
             &mut self,
-            first_web2_id: u64,
-            second_web2_id: u64,
+            web2_user_id: u64,
+            time_control: Option<u32>,
+            rating_range: Option<RatingRange>,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
-        fn cancel_invitation_signless(
+        fn join_matchmaking_queue_signless(
             &mut self,
             user_address: ActorId,
-            first_web2_id: u64,
-            second_web2_id: u64,
+            web2_user_id: u64,
+            time_control: Option<u32>,
+            rating_range: Option<RatingRange>,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
-        fn cancel_invitation_signless_no_wallet(
+        fn join_matchmaking_queue_signless_no_wallet(
             &mut self,
             no_wallet_name_encoded: String,
-            first_web2_id: u64,
-            second_web2_id: u64,
+            web2_user_id: u64,
+            time_control: Option<u32>,
+            rating_range: Option<RatingRange>,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
-        fn end_game_by_id(
+        fn join_open_game(
             &mut self,
+            web2_user_id: u64,
             game_id: u64,
-            game_winner: Option<ActorId>,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
-        fn end_match(
+        fn join_open_game_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn join_open_game_signless_no_wallet(
             &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn leave_matchmaking_queue(
+            &mut self,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn leave_matchmaking_queue_signless(
+            &mut self,
+            user_address: ActorId,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn leave_matchmaking_queue_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn make_move(
+            &mut self,
+            game_id: u64,
+            chess_move: ChessMove,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn make_move_signless(
+            &mut self,
+            user_address: ActorId,
+            game_id: u64,
+            chess_move: ChessMove,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn make_move_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            game_id: u64,
+            chess_move: ChessMove,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn place_spectator_bet(
+            &mut self,
+            game_id: u64,
+            outcome: MarketOutcome,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn place_spectator_bet_signless(
+            &mut self,
+            user_address: ActorId,
+            game_id: u64,
+            outcome: MarketOutcome,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn place_spectator_bet_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            game_id: u64,
+            outcome: MarketOutcome,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn remove_admin(
+            &mut self,
+            admin: ActorId,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn remove_result_oracle(
+            &mut self,
+            oracle: ActorId,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn resign(
+            &mut self,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn resign_signless(
+            &mut self,
+            user_address: ActorId,
+            game_id: u64,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn resign_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
             game_id: u64,
-            game_winner: Option<ActorId>,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
         fn send_invitation(
             &mut self,
             web2_user_id: u64,
             web2_guest_id: u64,
+            guest_bet_amount: Option<u128>,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
         fn send_invitation_signless(
             &mut self,
             user_address: ActorId,
             web2_user_id: u64,
             web2_guest_id: u64,
+            guest_bet_amount: Option<u128>,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
         fn send_invitation_signless_no_wallet(
             &mut self,
             no_wallet_name_encoded: String,
             web2_user_id: u64,
             web2_guest_id: u64,
+            guest_bet_amount: Option<u128>,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn set_fee_config(
+            &mut self,
+            fee_config: FeeConfig,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn set_invitation_ttl(
+            &mut self,
+            ttl_blocks: u32,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn set_market_config(
+            &mut self,
+            market_config: MarketConfig,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn set_stake_limits(
+            &mut self,
+            stake_limits: StakeLimits,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn sweep_expired_invitations(
+            &mut self,
+            limit: u32,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn withdraw(
+            &mut self,
+            amount: u128,
         ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn withdraw_signless(
+            &mut self,
+            user_address: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn withdraw_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn withdraw_treasury(
+            &mut self,
+            to: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn admins(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
         fn all_games(&self) -> impl Query<Output = Vec<(u64, GameData)>, Args = Self::Args>;
+        fn balance(&self, address: ActorId) -> impl Query<Output = u128, Args = Self::Args>;
+        fn fee_config(&self) -> impl Query<Output = FeeConfig, Args = Self::Args>;
+        fn game_board(
+            &self,
+            game_id: u64,
+        ) -> impl Query<Output = Option<ChessBoard>, Args = Self::Args>;
         fn game_data(
             &self,
             game_id: u64,
         ) -> impl Query<Output = Option<GameData>, Args = Self::Args>;
+        fn game_id_by_web_2_id(
+            &self,
+            web2_game_id: u64,
+        ) -> impl Query<Output = Option<u64>, Args = Self::Args>;
+        fn games(
+            &self,
+            offset: u32,
+            limit: u32,
+            filter: GamesFilter,
+        ) -> impl Query<Output = GamesPage, Args = Self::Args>;
+        fn games_after(
+            &self,
+            cursor: u64,
+            limit: u32,
+            filter: GamesFilter,
+        ) -> impl Query<Output = GamesPage, Args = Self::Args>;
+        fn games_id_by_player_address(
+            &self,
+            address: ActorId,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
+        fn games_id_by_player_web_2_id(
+            &self,
+            web2_id: u64,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
+        fn games_id_cancelled(&self) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
+        fn games_id_cancelled_page(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
         fn games_id_ended(&self) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
+        fn games_id_ended_page(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
         fn games_id_started(&self) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
+        fn games_id_started_page(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
         fn games_id_waiting(&self) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
+        fn games_id_waiting_page(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<u64>, Args = Self::Args>;
         fn invitation_bet(
             &self,
             first_web2_id: u64,
             second_web2_id: u64,
         ) -> impl Query<Output = Option<u128>, Args = Self::Args>;
+        fn invitation_guest_bet(
+            &self,
+            first_web2_id: u64,
+            second_web2_id: u64,
+        ) -> impl Query<Output = Option<u128>, Args = Self::Args>;
+        fn invitation_ttl_blocks(&self) -> impl Query<Output = u32, Args = Self::Args>;
         fn invitations_from_web_2_id(
             &self,
             web2_id: u64,
         ) -> impl Query<Output = Option<InvitationsState>, Args = Self::Args>;
+        fn invitations_lifetime(
+            &self,
+            web2_id: u64,
+        ) -> impl Query<Output = Vec<InvitationLifetime>, Args = Self::Args>;
+        fn leaderboard(
+            &self,
+            kind: LeaderboardKind,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<LeaderboardEntry>, Args = Self::Args>;
+        fn legal_moves(
+            &self,
+            game_id: u64,
+        ) -> impl Query<Output = Vec<ChessMove>, Args = Self::Args>;
+        fn market_config(&self) -> impl Query<Output = MarketConfig, Args = Self::Args>;
+        fn matchmaking_queue(
+            &self,
+            bet_amount: u128,
+            time_control: Option<u32>,
+        ) -> impl Query<Output = Vec<MatchmakingEntry>, Args = Self::Args>;
+        fn player_rating(
+            &self,
+            web2_id: u64,
+        ) -> impl Query<Output = PlayerRating, Args = Self::Args>;
+        fn player_stats(&self, web2_id: u64)
+            -> impl Query<Output = PlayerStats, Args = Self::Args>;
+        fn rating_history(
+            &self,
+            web2_id: u64,
+        ) -> impl Query<Output = Vec<RatingChange>, Args = Self::Args>;
+        fn result_oracles(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn spectator_bets(
+            &self,
+            game_id: u64,
+            address: ActorId,
+        ) -> impl Query<Output = Vec<(MarketOutcome, u128)>, Args = Self::Args>;
+        fn spectator_market(
+            &self,
+            game_id: u64,
+        ) -> impl Query<Output = Option<SpectatorMarketInfo>, Args = Self::Args>;
+        fn stake_limits(&self) -> impl Query<Output = StakeLimits, Args = Self::Args>;
+        fn total_fees_collected(&self) -> impl Query<Output = u128, Args = Self::Args>;
+        fn treasury_balance(&self) -> impl Query<Output = u128, Args = Self::Args>;
     }
 
     #[allow(clippy::type_complexity)]
@@ -990,6 +5132,275 @@ pub mod traits {
             signless_data: SignlessAccount,
         ) -> impl Call<Output = SignlessEvent, Args = Self::Args>;
     }
+
+    #[allow(clippy::type_complexity)]
+    pub trait TokenStakes {
+        type Args;
+        fn accept_token_invitation(
+            &mut self,
+            web2_user_id: u64,
+            web2_user_id_invitation_owner: u64,
+            web2_match_game_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn accept_token_invitation_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            web2_user_id_invitation_owner: u64,
+            web2_match_game_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn accept_token_invitation_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            web2_user_id_invitation_owner: u64,
+            web2_match_game_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn create_open_token_game(
+            &mut self,
+            web2_user_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn create_open_token_game_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn create_open_token_game_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn join_open_token_game(
+            &mut self,
+            web2_user_id: u64,
+            game_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn join_open_token_game_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            game_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn join_open_token_game_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            game_id: u64,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn send_token_invitation(
+            &mut self,
+            web2_user_id: u64,
+            guest_web2_id: u64,
+            token: ActorId,
+            amount: u128,
+            guest_amount: Option<u128>,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn send_token_invitation_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            guest_web2_id: u64,
+            token: ActorId,
+            amount: u128,
+            guest_amount: Option<u128>,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn send_token_invitation_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            guest_web2_id: u64,
+            token: ActorId,
+            amount: u128,
+            guest_amount: Option<u128>,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn withdraw_token(
+            &mut self,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn withdraw_token_signless(
+            &mut self,
+            user_address: ActorId,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn withdraw_token_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            token: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn withdraw_treasury_token(
+            &mut self,
+            token: ActorId,
+            to: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Bet2ChessEvents, Args = Self::Args>;
+        fn token_balance(
+            &self,
+            token: ActorId,
+            address: ActorId,
+        ) -> impl Query<Output = u128, Args = Self::Args>;
+        fn treasury_token_balance(
+            &self,
+            token: ActorId,
+        ) -> impl Query<Output = u128, Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Tournament {
+        type Args;
+        fn cancel_tournament(
+            &mut self,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn close_arena(
+            &mut self,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn create_pending_tournament_games(
+            &mut self,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn create_tournament(
+            &mut self,
+            config: TournamentConfig,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn forfeit_tournament_game(
+            &mut self,
+            game_id: u64,
+            absent_player: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn leave_tournament(
+            &mut self,
+            web2_user_id: u64,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn leave_tournament_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn leave_tournament_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn register_in_tournament(
+            &mut self,
+            web2_user_id: u64,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn register_in_tournament_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn register_in_tournament_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn start_tournament(
+            &mut self,
+            tournament_id: u64,
+        ) -> impl Call<Output = TournamentEvents, Args = Self::Args>;
+        fn tournament(
+            &self,
+            tournament_id: u64,
+        ) -> impl Query<Output = Option<Tournament>, Args = Self::Args>;
+        fn tournament_round(
+            &self,
+            tournament_id: u64,
+            round: u32,
+        ) -> impl Query<Output = Vec<TournamentPairing>, Args = Self::Args>;
+        fn tournament_standings(
+            &self,
+            tournament_id: u64,
+        ) -> impl Query<Output = Vec<(u64, u32)>, Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait UserRegistry {
+        type Args;
+        fn link_web_2_id(
+            &mut self,
+            web2_id: u64,
+            address: ActorId,
+            signature: [u8; 64],
+        ) -> impl Call<Output = UserRegistryEvents, Args = Self::Args>;
+        fn set_attester(
+            &mut self,
+            attester: [u8; 32],
+        ) -> impl Call<Output = UserRegistryEvents, Args = Self::Args>;
+        fn set_profile(
+            &mut self,
+            web2_user_id: u64,
+            username: String,
+            avatar_hash: Option<[u8; 32]>,
+            country: Option<String>,
+        ) -> impl Call<Output = UserRegistryEvents, Args = Self::Args>;
+        fn set_profile_signless(
+            &mut self,
+            user_address: ActorId,
+            web2_user_id: u64,
+            username: String,
+            avatar_hash: Option<[u8; 32]>,
+            country: Option<String>,
+        ) -> impl Call<Output = UserRegistryEvents, Args = Self::Args>;
+        fn set_profile_signless_no_wallet(
+            &mut self,
+            no_wallet_name_encoded: String,
+            web2_user_id: u64,
+            username: String,
+            avatar_hash: Option<[u8; 32]>,
+            country: Option<String>,
+        ) -> impl Call<Output = UserRegistryEvents, Args = Self::Args>;
+        fn unlink_web_2_id(
+            &mut self,
+            web2_id: u64,
+        ) -> impl Call<Output = UserRegistryEvents, Args = Self::Args>;
+        fn address_by_web_2_id(
+            &self,
+            web2_id: u64,
+        ) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn attester(&self) -> impl Query<Output = Option<[u8; 32]>, Args = Self::Args>;
+        fn link_nonce(&self, web2_id: u64) -> impl Query<Output = u64, Args = Self::Args>;
+        fn profile(
+            &self,
+            web2_id: u64,
+        ) -> impl Query<Output = Option<PlayerProfile>, Args = Self::Args>;
+        fn web_2_id_by_address(
+            &self,
+            address: ActorId,
+        ) -> impl Query<Output = Option<u64>, Args = Self::Args>;
+        fn web_2_id_by_username(
+            &self,
+            username: String,
+        ) -> impl Query<Output = Option<u64>, Args = Self::Args>;
+    }
 }
 
 #[cfg(feature = "with_mocks")]
@@ -1001,7 +5412,10 @@ extern crate std;
 pub mod mockall {
     use super::*;
     use sails_rs::mockall::*;
-    mock! { pub Bet2Chess<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Bet2Chess for Bet2Chess<A> { type Args = A; fn accept_invitation (&mut self, web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,username_from_user_who_invite: String,own_username: String,) -> MockCall<A, Bet2ChessEvents>;fn accept_invitation_signless (&mut self, user_address: ActorId,web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,username_from_user_who_invite: String,own_username: String,) -> MockCall<A, Bet2ChessEvents>;fn accept_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,username_from_user_who_invite: String,own_username: String,) -> MockCall<A, Bet2ChessEvents>;fn cancel_invitation (&mut self, first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn cancel_invitation_signless (&mut self, user_address: ActorId,first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn cancel_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn end_game_by_id (&mut self, game_id: u64,game_winner: Option<ActorId>,) -> MockCall<A, Bet2ChessEvents>;fn end_match (&mut self, game_id: u64,game_winner: Option<ActorId>,) -> MockCall<A, Bet2ChessEvents>;fn send_invitation (&mut self, web2_user_id: u64,web2_guest_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn send_invitation_signless (&mut self, user_address: ActorId,web2_user_id: u64,web2_guest_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn send_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,web2_guest_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn all_games (& self, ) -> MockQuery<A, Vec<(u64,GameData,)>>;fn game_data (& self, game_id: u64,) -> MockQuery<A, Option<GameData>>;fn games_id_ended (& self, ) -> MockQuery<A, Vec<u64>>;fn games_id_started (& self, ) -> MockQuery<A, Vec<u64>>;fn games_id_waiting (& self, ) -> MockQuery<A, Vec<u64>>;fn invitation_bet (& self, first_web2_id: u64,second_web2_id: u64,) -> MockQuery<A, Option<u128>>;fn invitations_from_web_2_id (& self, web2_id: u64,) -> MockQuery<A, Option<InvitationsState>>; } }
+    mock! { pub Bet2Chess<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Bet2Chess for Bet2Chess<A> { type Args = A; fn abort_game (&mut self, game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn accept_invitation (&mut self, web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn accept_invitation_signless (&mut self, user_address: ActorId,web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn accept_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn add_admin (&mut self, new_admin: ActorId,) -> MockCall<A, Bet2ChessEvents>;fn add_result_oracle (&mut self, oracle: ActorId,) -> MockCall<A, Bet2ChessEvents>;fn cancel_invitation (&mut self, first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn cancel_invitation_signless (&mut self, user_address: ActorId,first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn cancel_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn cancel_open_game (&mut self, game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn cancel_open_game_signless (&mut self, user_address: ActorId,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn cancel_open_game_signless_no_wallet (&mut self, no_wallet_name_encoded: String,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn claim_spectator_winnings (&mut self, game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn claim_spectator_winnings_signless (&mut self, user_address: ActorId,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn claim_spectator_winnings_signless_no_wallet (&mut self, no_wallet_name_encoded: String,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn create_open_game (&mut self, web2_user_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn create_open_game_signless (&mut self, user_address: ActorId,web2_user_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn create_open_game_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn decline_invitation (&mut self, first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn decline_invitation_signless (&mut self, user_address: ActorId,first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn decline_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn end_game_by_id (&mut self, game_id: u64,game_winner: Option<ActorId>,) -> MockCall<A, Bet2ChessEvents>;fn end_match (&mut self, game_id: u64,game_winner: Option<ActorId>,) -> MockCall<A, Bet2ChessEvents>;fn expire_invitation (&mut self, first_web2_id: u64,second_web2_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn join_any_waiting_game (&mut self, web2_user_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn join_any_waiting_game_signless (&mut self, user_address: ActorId,web2_user_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn join_any_waiting_game_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn join_matchmaking_queue (&mut self, web2_user_id: u64,time_control: Option<u32>,rating_range: Option<RatingRange>,) -> MockCall<A, Bet2ChessEvents>;fn join_matchmaking_queue_signless (&mut self, user_address: ActorId,web2_user_id: u64,time_control: Option<u32>,rating_range: Option<RatingRange>,) -> MockCall<A, Bet2ChessEvents>;fn join_matchmaking_queue_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,time_control: Option<u32>,rating_range: Option<RatingRange>,) -> MockCall<A, Bet2ChessEvents>;fn join_open_game (&mut self, web2_user_id: u64,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn join_open_game_signless (&mut self, user_address: ActorId,web2_user_id: u64,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn join_open_game_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn leave_matchmaking_queue (&mut self, ) -> MockCall<A, Bet2ChessEvents>;fn leave_matchmaking_queue_signless (&mut self, user_address: ActorId,) -> MockCall<A, Bet2ChessEvents>;fn leave_matchmaking_queue_signless_no_wallet (&mut self, no_wallet_name_encoded: String,) -> MockCall<A, Bet2ChessEvents>;fn make_move (&mut self, game_id: u64,chess_move: ChessMove,) -> MockCall<A, Bet2ChessEvents>;fn make_move_signless (&mut self, user_address: ActorId,game_id: u64,chess_move: ChessMove,) -> MockCall<A, Bet2ChessEvents>;fn make_move_signless_no_wallet (&mut self, no_wallet_name_encoded: String,game_id: u64,chess_move: ChessMove,) -> MockCall<A, Bet2ChessEvents>;fn place_spectator_bet (&mut self, game_id: u64,outcome: MarketOutcome,) -> MockCall<A, Bet2ChessEvents>;fn place_spectator_bet_signless (&mut self, user_address: ActorId,game_id: u64,outcome: MarketOutcome,) -> MockCall<A, Bet2ChessEvents>;fn place_spectator_bet_signless_no_wallet (&mut self, no_wallet_name_encoded: String,game_id: u64,outcome: MarketOutcome,) -> MockCall<A, Bet2ChessEvents>;fn remove_admin (&mut self, admin: ActorId,) -> MockCall<A, Bet2ChessEvents>;fn remove_result_oracle (&mut self, oracle: ActorId,) -> MockCall<A, Bet2ChessEvents>;fn resign (&mut self, game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn resign_signless (&mut self, user_address: ActorId,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn resign_signless_no_wallet (&mut self, no_wallet_name_encoded: String,game_id: u64,) -> MockCall<A, Bet2ChessEvents>;fn send_invitation (&mut self, web2_user_id: u64,web2_guest_id: u64,guest_bet_amount: Option<u128>,) -> MockCall<A, Bet2ChessEvents>;fn send_invitation_signless (&mut self, user_address: ActorId,web2_user_id: u64,web2_guest_id: u64,guest_bet_amount: Option<u128>,) -> MockCall<A, Bet2ChessEvents>;fn send_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,web2_guest_id: u64,guest_bet_amount: Option<u128>,) -> MockCall<A, Bet2ChessEvents>;fn set_fee_config (&mut self, fee_config: FeeConfig,) -> MockCall<A, Bet2ChessEvents>;fn set_invitation_ttl (&mut self, ttl_blocks: u32,) -> MockCall<A, Bet2ChessEvents>;fn set_market_config (&mut self, market_config: MarketConfig,) -> MockCall<A, Bet2ChessEvents>;fn set_stake_limits (&mut self, stake_limits: StakeLimits,) -> MockCall<A, Bet2ChessEvents>;fn sweep_expired_invitations (&mut self, limit: u32,) -> MockCall<A, Bet2ChessEvents>;fn withdraw (&mut self, amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn withdraw_signless (&mut self, user_address: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn withdraw_signless_no_wallet (&mut self, no_wallet_name_encoded: String,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn withdraw_treasury (&mut self, to: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn admins (& self, ) -> MockQuery<A, Vec<ActorId>>;fn all_games (& self, ) -> MockQuery<A, Vec<(u64,GameData,)>>;fn balance (& self, address: ActorId,) -> MockQuery<A, u128>;fn fee_config (& self, ) -> MockQuery<A, FeeConfig>;fn game_board (& self, game_id: u64,) -> MockQuery<A, Option<ChessBoard>>;fn game_data (& self, game_id: u64,) -> MockQuery<A, Option<GameData>>;fn game_id_by_web_2_id (& self, web2_game_id: u64,) -> MockQuery<A, Option<u64>>;fn games (& self, offset: u32,limit: u32,filter: GamesFilter,) -> MockQuery<A, GamesPage>;fn games_after (& self, cursor: u64,limit: u32,filter: GamesFilter,) -> MockQuery<A, GamesPage>;fn games_id_by_player_address (& self, address: ActorId,offset: u32,limit: u32,) -> MockQuery<A, Vec<u64>>;fn games_id_by_player_web_2_id (& self, web2_id: u64,offset: u32,limit: u32,) -> MockQuery<A, Vec<u64>>;fn games_id_cancelled (& self, ) -> MockQuery<A, Vec<u64>>;fn games_id_cancelled_page (& self, offset: u32,limit: u32,) -> MockQuery<A, Vec<u64>>;fn games_id_ended (& self, ) -> MockQuery<A, Vec<u64>>;fn games_id_ended_page (& self, offset: u32,limit: u32,) -> MockQuery<A, Vec<u64>>;fn games_id_started (& self, ) -> MockQuery<A, Vec<u64>>;fn games_id_started_page (& self, offset: u32,limit: u32,) -> MockQuery<A, Vec<u64>>;fn games_id_waiting (& self, ) -> MockQuery<A, Vec<u64>>;fn games_id_waiting_page (& self, offset: u32,limit: u32,) -> MockQuery<A, Vec<u64>>;fn invitation_bet (& self, first_web2_id: u64,second_web2_id: u64,) -> MockQuery<A, Option<u128>>;fn invitation_guest_bet (& self, first_web2_id: u64,second_web2_id: u64,) -> MockQuery<A, Option<u128>>;fn invitation_ttl_blocks (& self, ) -> MockQuery<A, u32>;fn invitations_from_web_2_id (& self, web2_id: u64,) -> MockQuery<A, Option<InvitationsState>>;fn invitations_lifetime (& self, web2_id: u64,) -> MockQuery<A, Vec<InvitationLifetime>>;fn leaderboard (& self, kind: LeaderboardKind,offset: u32,limit: u32,) -> MockQuery<A, Vec<LeaderboardEntry>>;fn legal_moves (& self, game_id: u64,) -> MockQuery<A, Vec<ChessMove>>;fn market_config (& self, ) -> MockQuery<A, MarketConfig>;fn matchmaking_queue (& self, bet_amount: u128,time_control: Option<u32>,) -> MockQuery<A, Vec<MatchmakingEntry>>;fn player_rating (& self, web2_id: u64,) -> MockQuery<A, PlayerRating>;fn player_stats (& self, web2_id: u64,) -> MockQuery<A, PlayerStats>;fn rating_history (& self, web2_id: u64,) -> MockQuery<A, Vec<RatingChange>>;fn result_oracles (& self, ) -> MockQuery<A, Vec<ActorId>>;fn spectator_bets (& self, game_id: u64,address: ActorId,) -> MockQuery<A, Vec<(MarketOutcome,u128,)>>;fn spectator_market (& self, game_id: u64,) -> MockQuery<A, Option<SpectatorMarketInfo>>;fn stake_limits (& self, ) -> MockQuery<A, StakeLimits>;fn total_fees_collected (& self, ) -> MockQuery<A, u128>;fn treasury_balance (& self, ) -> MockQuery<A, u128>; } }
     mock! { pub QueryService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::QueryService for QueryService<A> { type Args = A; fn signless_account_data (& self, signless_address: ActorId,) -> MockQuery<A, QueryEvent>;fn signless_address_from_no_wallet_account (& self, no_wallet_account: String,) -> MockQuery<A, QueryEvent>;fn signless_address_from_user_address (& self, user_address: ActorId,) -> MockQuery<A, QueryEvent>; } }
     mock! { pub Signless<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Signless for Signless<A> { type Args = A; fn bind_signless_data_to_address (&mut self, user_address: ActorId,signless_data: SignlessAccount,) -> MockCall<A, SignlessEvent>;fn bind_signless_data_to_no_wallet_account (&mut self, no_wallet_account: String,signless_data: SignlessAccount,) -> MockCall<A, SignlessEvent>; } }
+    mock! { pub TokenStakes<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::TokenStakes for TokenStakes<A> { type Args = A; fn accept_token_invitation (&mut self, web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn accept_token_invitation_signless (&mut self, user_address: ActorId,web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn accept_token_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,web2_user_id_invitation_owner: u64,web2_match_game_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn create_open_token_game (&mut self, web2_user_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn create_open_token_game_signless (&mut self, user_address: ActorId,web2_user_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn create_open_token_game_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn join_open_token_game (&mut self, web2_user_id: u64,game_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn join_open_token_game_signless (&mut self, user_address: ActorId,web2_user_id: u64,game_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn join_open_token_game_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,game_id: u64,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn send_token_invitation (&mut self, web2_user_id: u64,guest_web2_id: u64,token: ActorId,amount: u128,guest_amount: Option<u128>,) -> MockCall<A, Bet2ChessEvents>;fn send_token_invitation_signless (&mut self, user_address: ActorId,web2_user_id: u64,guest_web2_id: u64,token: ActorId,amount: u128,guest_amount: Option<u128>,) -> MockCall<A, Bet2ChessEvents>;fn send_token_invitation_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,guest_web2_id: u64,token: ActorId,amount: u128,guest_amount: Option<u128>,) -> MockCall<A, Bet2ChessEvents>;fn withdraw_token (&mut self, token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn withdraw_token_signless (&mut self, user_address: ActorId,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn withdraw_token_signless_no_wallet (&mut self, no_wallet_name_encoded: String,token: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn withdraw_treasury_token (&mut self, token: ActorId,to: ActorId,amount: u128,) -> MockCall<A, Bet2ChessEvents>;fn token_balance (& self, token: ActorId,address: ActorId,) -> MockQuery<A, u128>;fn treasury_token_balance (& self, token: ActorId,) -> MockQuery<A, u128>; } }
+    mock! { pub Tournament<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Tournament for Tournament<A> { type Args = A; fn cancel_tournament (&mut self, tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn close_arena (&mut self, tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn create_pending_tournament_games (&mut self, tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn create_tournament (&mut self, config: TournamentConfig,) -> MockCall<A, TournamentEvents>;fn forfeit_tournament_game (&mut self, game_id: u64,absent_player: u64,) -> MockCall<A, TournamentEvents>;fn leave_tournament (&mut self, web2_user_id: u64,tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn leave_tournament_signless (&mut self, user_address: ActorId,web2_user_id: u64,tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn leave_tournament_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn register_in_tournament (&mut self, web2_user_id: u64,tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn register_in_tournament_signless (&mut self, user_address: ActorId,web2_user_id: u64,tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn register_in_tournament_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn start_tournament (&mut self, tournament_id: u64,) -> MockCall<A, TournamentEvents>;fn tournament (& self, tournament_id: u64,) -> MockQuery<A, Option<Tournament>>;fn tournament_round (& self, tournament_id: u64,round: u32,) -> MockQuery<A, Vec<TournamentPairing>>;fn tournament_standings (& self, tournament_id: u64,) -> MockQuery<A, Vec<(u64,u32,)>>; } }
+    mock! { pub UserRegistry<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::UserRegistry for UserRegistry<A> { type Args = A; fn link_web_2_id (&mut self, web2_id: u64,address: ActorId,signature: [u8; 64],) -> MockCall<A, UserRegistryEvents>;fn set_attester (&mut self, attester: [u8; 32],) -> MockCall<A, UserRegistryEvents>;fn set_profile (&mut self, web2_user_id: u64,username: String,avatar_hash: Option<[u8; 32]>,country: Option<String>,) -> MockCall<A, UserRegistryEvents>;fn set_profile_signless (&mut self, user_address: ActorId,web2_user_id: u64,username: String,avatar_hash: Option<[u8; 32]>,country: Option<String>,) -> MockCall<A, UserRegistryEvents>;fn set_profile_signless_no_wallet (&mut self, no_wallet_name_encoded: String,web2_user_id: u64,username: String,avatar_hash: Option<[u8; 32]>,country: Option<String>,) -> MockCall<A, UserRegistryEvents>;fn unlink_web_2_id (&mut self, web2_id: u64,) -> MockCall<A, UserRegistryEvents>;fn address_by_web_2_id (& self, web2_id: u64,) -> MockQuery<A, Option<ActorId>>;fn attester (& self, ) -> MockQuery<A, Option<[u8; 32]>>;fn link_nonce (& self, web2_id: u64,) -> MockQuery<A, u64>;fn profile (& self, web2_id: u64,) -> MockQuery<A, Option<PlayerProfile>>;fn web_2_id_by_address (& self, address: ActorId,) -> MockQuery<A, Option<u64>>;fn web_2_id_by_username (& self, username: String,) -> MockQuery<A, Option<u64>>; } }
 }